@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+use syn::{Error, Lit};
+
+/// Normalized representation of a literal key.
+///
+/// Two keys are considered duplicates if their normalized representations
+/// are equal, i.e. `1` and `0x1` or `"a"` and `"\x61"` are duplicates.
+///
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub(crate) enum Key {
+    Str(String),
+    ByteStr(Vec<u8>),
+    Int { negative: bool, abs: u128 },
+    Char(char),
+    Path(String),
+}
+
+pub fn assert_unique_keys(input: TokenStream) -> TokenStream {
+    let mut tokens = input.into_iter();
+
+    let is_map = match tokens.next() {
+        Some(TokenTree::Ident(kind)) if kind == "map" => true,
+        Some(TokenTree::Ident(kind)) if kind == "set" => false,
+        _ => panic!("expected `map` or `set` as first token"),
+    };
+
+    // skip leading clauses like `hasher: expr;`
+    let mut entries: Vec<TokenTree> = tokens.collect();
+
+    if let Some(end) = entries.iter().rposition(is_semicolon) {
+        entries.drain(..=end);
+    }
+
+    let mut keys = Vec::new();
+
+    for entry in split(entries.into_iter().collect(), is_comma) {
+        // the keys of comprehensions are computed at runtime and guarded
+        // entries may exclude each other
+        if entry.clone().into_iter().any(|t| is_for(&t) || is_if(&t)) {
+            continue;
+        }
+
+        let (key, normalized) = if is_map {
+            if let Some((key, _)) = split_entry(entry.clone()) {
+                let normalized = normalize(key.clone());
+                (key, normalized)
+            } else {
+                let normalized = shorthand(entry.clone()).map(Key::Str);
+                (entry, normalized)
+            }
+        } else {
+            let normalized = normalize(entry.clone());
+            (entry, normalized)
+        };
+
+        if let Some(normalized) = normalized {
+            keys.push((key, normalized));
+        }
+    }
+
+    duplicates(keys)
+        .map(|e| e.to_compile_error())
+        .unwrap_or_default()
+}
+
+/// Returns an error for every key of `keys` whose normalized representation
+/// equals the one of a previous key.
+///
+pub(crate) fn duplicates(keys: Vec<(TokenStream, Key)>) -> Option<Error> {
+    let mut seen: HashMap<Key, (TokenStream, String)> = HashMap::new();
+    let mut errors: Option<Error> = None;
+
+    for (key, normalized) in keys {
+        let shown = match &normalized {
+            Key::Path(path) => path.clone(),
+            _ => key.to_string(),
+        };
+
+        if let Some((first, first_shown)) = seen.get(&normalized) {
+            let mut error = Error::new_spanned(&key, format!("duplicate key `{shown}`"));
+            error.combine(Error::new_spanned(
+                first,
+                format!("key `{first_shown}` first defined here"),
+            ));
+
+            match &mut errors {
+                Some(errors) => errors.combine(error),
+                None => errors = Some(error),
+            }
+        } else {
+            seen.insert(normalized, (key, shown));
+        }
+    }
+
+    errors
+}
+
+/// Splits `tokens` at every top-level token for which `is_separator` returns
+/// `true`, omitting empty parts.
+///
+pub(crate) fn split(tokens: TokenStream, is_separator: fn(&TokenTree) -> bool) -> Vec<TokenStream> {
+    let mut parts = Vec::new();
+    let mut current = Vec::new();
+
+    for token in tokens {
+        if is_separator(&token) {
+            parts.push(current.drain(..).collect());
+        } else {
+            current.push(token);
+        }
+    }
+
+    parts.push(current.into_iter().collect());
+
+    parts.retain(|p: &TokenStream| !p.is_empty());
+    parts
+}
+
+pub(crate) fn is_comma(token: &TokenTree) -> bool {
+    matches!(token, TokenTree::Punct(p) if p.as_char() == ',')
+}
+
+fn is_for(token: &TokenTree) -> bool {
+    matches!(token, TokenTree::Ident(i) if i == "for")
+}
+
+fn is_if(token: &TokenTree) -> bool {
+    matches!(token, TokenTree::Ident(i) if i == "if")
+}
+
+pub(crate) fn is_semicolon(token: &TokenTree) -> bool {
+    matches!(token, TokenTree::Punct(p) if p.as_char() == ';')
+}
+
+/// Splits `entry` at its first top-level `=>` into key and value.
+///
+pub(crate) fn split_entry(entry: TokenStream) -> Option<(TokenStream, TokenStream)> {
+    let tokens: Vec<TokenTree> = entry.into_iter().collect();
+
+    let arrow = tokens.windows(2).position(|w| match (&w[0], &w[1]) {
+        (TokenTree::Punct(eq), TokenTree::Punct(gt)) => {
+            eq.as_char() == '=' && eq.spacing() == Spacing::Joint && gt.as_char() == '>'
+        }
+        _ => false,
+    })?;
+
+    Some((
+        tokens[..arrow].iter().cloned().collect(),
+        tokens[arrow + 2..].iter().cloned().collect(),
+    ))
+}
+
+/// Returns the name of the identifier of a shorthand entry `ident`, which is
+/// keyed by the name.
+///
+fn shorthand(entry: TokenStream) -> Option<String> {
+    let tokens: Vec<TokenTree> = entry.into_iter().collect();
+
+    match tokens.as_slice() {
+        [TokenTree::Group(g)] if g.delimiter() == Delimiter::None => shorthand(g.stream()),
+        [TokenTree::Ident(i)] => Some(i.to_string()),
+        _ => None,
+    }
+}
+
+pub(crate) fn normalize(key: TokenStream) -> Option<Key> {
+    let tokens: Vec<TokenTree> = key.into_iter().collect();
+
+    match tokens.as_slice() {
+        // expressions forwarded by other declarative macros arrive wrapped in
+        // an invisible group
+        [TokenTree::Group(g)] if g.delimiter() == Delimiter::None => normalize(g.stream()),
+        [TokenTree::Literal(l)] => match Lit::new(l.clone()) {
+            Lit::Str(s) => Some(Key::Str(s.value())),
+            Lit::ByteStr(s) => Some(Key::ByteStr(s.value())),
+            Lit::Char(c) => Some(Key::Char(c.value())),
+            Lit::Byte(b) => Some(Key::Int {
+                negative: false,
+                abs: u128::from(b.value()),
+            }),
+            Lit::Int(i) => Some(Key::Int {
+                negative: false,
+                abs: i.base10_parse().ok()?,
+            }),
+            _ => None,
+        },
+        [TokenTree::Punct(minus), TokenTree::Literal(l)] if minus.as_char() == '-' => {
+            match Lit::new(l.clone()) {
+                Lit::Int(i) => {
+                    let abs = i.base10_parse().ok()?;
+
+                    Some(Key::Int {
+                        negative: abs != 0,
+                        abs,
+                    })
+                }
+                _ => None,
+            }
+        }
+        _ => normalize_path(&tokens).map(Key::Path),
+    }
+}
+
+/// Returns the path `tokens` represent without whitespace, if `tokens` is a
+/// simple path like `Foo::Bar`.
+///
+fn normalize_path(tokens: &[TokenTree]) -> Option<String> {
+    let mut path = String::new();
+    let mut expect_ident = true;
+    let mut tokens = tokens.iter().peekable();
+
+    if let Some(TokenTree::Punct(_)) = tokens.peek() {
+        expect_ident = false;
+    }
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(i) if expect_ident => {
+                path.push_str(&i.to_string());
+                expect_ident = false;
+            }
+            TokenTree::Punct(first) if !expect_ident && first.as_char() == ':' => {
+                match tokens.next() {
+                    Some(TokenTree::Punct(second))
+                        if first.spacing() == Spacing::Joint && second.as_char() == ':' =>
+                    {
+                        path.push_str("::");
+                        expect_ident = true;
+                    }
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    if path.is_empty() || expect_ident {
+        None
+    } else {
+        Some(path)
+    }
+}
@@ -0,0 +1,34 @@
+//! Procedural macros backing the compile-time checks and static maps of
+//! [`map-macro`](https://docs.rs/map-macro).
+//!
+//! This crate is an implementation detail of `map-macro`.
+//! Its macros are only meant to be invoked through the declarative macros
+//! of `map-macro` and their input format may change at any time.
+//!
+
+use proc_macro::TokenStream;
+
+mod keys;
+mod table;
+
+/// Emits a [`compile_error!`] for every literal key that occurs more than
+/// once in the entries of a map or set macro.
+///
+/// Expects either `map` or `set`, followed by the input of the map or set
+/// macro.
+///
+#[proc_macro]
+pub fn assert_unique_keys(input: TokenStream) -> TokenStream {
+    keys::assert_unique_keys(input.into()).into()
+}
+
+/// Expands to a `StaticHashMap` or `StaticHashSet` with a perfect hash
+/// function computed from the literal keys of the entries.
+///
+/// Expects the path to `map-macro` followed by `;`, then either `map` or
+/// `set`, followed by the entries of the map or set.
+///
+#[proc_macro]
+pub fn static_hash_table(input: TokenStream) -> TokenStream {
+    table::static_hash_table(input.into()).into()
+}
@@ -0,0 +1,239 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+use syn::Error;
+
+use crate::keys::{duplicates, is_comma, is_semicolon, normalize, split, split_entry, Key};
+
+/// Average number of keys per bucket of the displacement table.
+///
+const LAMBDA: usize = 5;
+
+/// Number of seeds tried before giving up on finding a perfect hash.
+///
+const MAX_SEEDS: u64 = 1024;
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+pub fn static_hash_table(input: TokenStream) -> TokenStream {
+    match expand(input) {
+        Ok(tokens) => tokens,
+        Err(e) => e.to_compile_error(),
+    }
+}
+
+fn expand(input: TokenStream) -> Result<TokenStream, Error> {
+    let mut tokens: Vec<TokenTree> = input.into_iter().collect();
+
+    let krate_end = tokens
+        .iter()
+        .position(is_semicolon)
+        .expect("expected crate path followed by `;`");
+
+    let krate: TokenStream = tokens.drain(..=krate_end).take(krate_end).collect();
+
+    let is_map = match tokens.first() {
+        Some(TokenTree::Ident(kind)) if kind == "map" => true,
+        Some(TokenTree::Ident(kind)) if kind == "set" => false,
+        _ => panic!("expected `map` or `set` after the crate path"),
+    };
+
+    let mut keys = Vec::new();
+    let mut values = Vec::new();
+
+    for entry in split(tokens.drain(1..).collect(), is_comma) {
+        let (key, value) = if is_map {
+            split_entry(entry.clone())
+                .ok_or_else(|| Error::new_spanned(&entry, "expected `key => value`"))?
+        } else {
+            (entry, quote!(()))
+        };
+
+        let normalized = normalize(key.clone())
+            .filter(|k| key_bytes(k).is_some())
+            .ok_or_else(|| Error::new_spanned(&key, "expected a literal key"))?;
+
+        keys.push((key, normalized));
+        values.push(value);
+    }
+
+    if let Some(e) = duplicates(keys.iter().map(|(k, n)| (k.clone(), n.clone())).collect()) {
+        return Err(e);
+    }
+
+    let bytes: Vec<Vec<u8>> = keys.iter().map(|(_, k)| key_bytes(k).unwrap()).collect();
+
+    let mut hashed: HashMap<&[u8], &TokenStream> = HashMap::new();
+
+    for ((key, _), bytes) in keys.iter().zip(&bytes) {
+        if let Some(first) = hashed.insert(bytes, key) {
+            return Err(Error::new_spanned(
+                key,
+                format!("key `{key}` has a different type than key `{first}`"),
+            ));
+        }
+    }
+
+    let table = generate(&bytes).ok_or_else(|| {
+        Error::new(
+            proc_macro2::Span::call_site(),
+            "unable to find a perfect hash function for the provided keys",
+        )
+    })?;
+
+    let seed = table.seed;
+    let d1 = table.disps.iter().map(|(d1, _)| d1);
+    let d2 = table.disps.iter().map(|(_, d2)| d2);
+    let k = table.slots.iter().map(|&i| &keys[i].0);
+    let v = table.slots.iter().map(|&i| &values[i]);
+
+    let (ty, entries) = if is_map {
+        (quote!(StaticHashMap), quote!(&[#((#k, #v)),*]))
+    } else {
+        (quote!(StaticHashSet), quote!(&[#(#k),*]))
+    };
+
+    Ok(quote! {
+        #krate::static_map::#ty::__new(#seed, &[#((#d1, #d2)),*], #entries)
+    })
+}
+
+/// Returns the bytes a key is hashed as.
+///
+/// Must match the implementations of `StaticHash` in `map-macro`.
+///
+fn key_bytes(key: &Key) -> Option<Vec<u8>> {
+    match key {
+        Key::Str(s) => Some(s.as_bytes().to_vec()),
+        Key::ByteStr(b) => Some(b.clone()),
+        Key::Int { negative, abs } => {
+            let bits = if *negative { abs.wrapping_neg() } else { *abs };
+            Some(bits.to_le_bytes().to_vec())
+        }
+        Key::Char(c) => Some(u32::from(*c).to_le_bytes().to_vec()),
+        Key::Path(p) if p == "true" => Some(vec![1]),
+        Key::Path(p) if p == "false" => Some(vec![0]),
+        Key::Path(_) => None,
+    }
+}
+
+/// Perfect hash table computed with the CHD algorithm.
+///
+struct Table {
+    seed: u64,
+    /// Displacements of every bucket.
+    disps: Vec<(u32, u32)>,
+    /// Index of the key stored at every slot of the table.
+    slots: Vec<usize>,
+}
+
+fn generate(keys: &[Vec<u8>]) -> Option<Table> {
+    if keys.is_empty() {
+        return Some(Table {
+            seed: 0,
+            disps: Vec::new(),
+            slots: Vec::new(),
+        });
+    }
+
+    (0..MAX_SEEDS).find_map(|seed| try_generate(keys, seed))
+}
+
+fn try_generate(keys: &[Vec<u8>], seed: u64) -> Option<Table> {
+    let len = keys.len();
+    let len_u32 = u32::try_from(len).expect("too many entries for a static map");
+
+    let hashes: Vec<Hashes> = keys.iter().map(|k| hash(seed, k)).collect();
+
+    let mut buckets = vec![Vec::new(); len.div_ceil(LAMBDA)];
+
+    for (i, h) in hashes.iter().enumerate() {
+        let bucket = h.g as usize % buckets.len();
+        buckets[bucket].push(i);
+    }
+
+    let mut order: Vec<usize> = (0..buckets.len()).collect();
+    order.sort_by_key(|&b| Reverse(buckets[b].len()));
+
+    let mut disps = vec![(0, 0); buckets.len()];
+    let mut slots: Vec<Option<usize>> = vec![None; len];
+    let mut claimed = Vec::new();
+
+    for bucket in order {
+        if buckets[bucket].is_empty() {
+            continue;
+        }
+
+        let found = (0..len_u32)
+            .flat_map(|d1| (0..len_u32).map(move |d2| (d1, d2)))
+            .find(|&(d1, d2)| {
+                claimed.clear();
+
+                buckets[bucket].iter().all(|&k| {
+                    let slot = displace(hashes[k].f1, hashes[k].f2, d1, d2) as usize % len;
+
+                    if slots[slot].is_some() || claimed.contains(&slot) {
+                        false
+                    } else {
+                        claimed.push(slot);
+                        true
+                    }
+                })
+            })?;
+
+        disps[bucket] = found;
+
+        for (&slot, &k) in claimed.iter().zip(&buckets[bucket]) {
+            slots[slot] = Some(k);
+        }
+    }
+
+    Some(Table {
+        seed,
+        disps,
+        slots: slots.into_iter().map(Option::unwrap).collect(),
+    })
+}
+
+/// Must match `Hashes` in `map-macro`.
+///
+struct Hashes {
+    g: u32,
+    f1: u32,
+    f2: u32,
+}
+
+/// Must match `StaticHasher` in `map-macro`.
+///
+#[allow(clippy::cast_possible_truncation)]
+fn hash(seed: u64, bytes: &[u8]) -> Hashes {
+    let mut state = FNV_OFFSET ^ mix(seed);
+
+    for b in bytes {
+        state ^= u64::from(*b);
+        state = state.wrapping_mul(FNV_PRIME);
+    }
+
+    let h1 = mix(state);
+    let h2 = mix(h1 ^ 0x9e37_79b9_7f4a_7c15);
+
+    Hashes {
+        g: (h1 >> 32) as u32,
+        f1: h1 as u32,
+        f2: h2 as u32,
+    }
+}
+
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+fn displace(f1: u32, f2: u32, d1: u32, d2: u32) -> u32 {
+    d2.wrapping_add(f1.wrapping_mul(d1)).wrapping_add(f2)
+}
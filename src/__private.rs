@@ -0,0 +1,279 @@
+//! Implementation details used by the exported macros.
+//! Not part of the public API.
+
+#[cfg(any(
+    feature = "alloc",
+    feature = "hashbrown",
+    feature = "im",
+    feature = "rpds",
+    feature = "serde_json",
+))]
+pub extern crate alloc;
+
+#[cfg(feature = "checked")]
+pub use map_macro_proc::assert_unique_keys;
+
+#[cfg(feature = "static")]
+pub use map_macro_proc::static_hash_table;
+
+/// Extends `map` with `entries`.
+///
+/// Some maps implement [`Extend`] for both owned and borrowed entries, which
+/// leaves the types of the casts in [`extend_map_e!`](crate::extend_map_e)
+/// ambiguous.
+/// The key and value types are therefore taken from what `map` yields when
+/// iterated by reference.
+///
+pub fn extend_map<M, K, V, I>(map: &mut M, entries: I)
+where
+    M: Extend<(K, V)>,
+    for<'a> &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    I: IntoIterator<Item = (K, V)>,
+{
+    map.extend(entries);
+}
+
+/// Extends `set` with `elements`.
+///
+/// Same as [`extend_map`], but for collections of single elements, which are
+/// used by [`extend_set_e!`](crate::extend_set_e).
+///
+pub fn extend_set<S, T, I>(set: &mut S, elements: I)
+where
+    S: Extend<T>,
+    for<'a> &'a S: IntoIterator<Item = &'a T>,
+    I: IntoIterator<Item = T>,
+{
+    set.extend(elements);
+}
+
+/// Fallback for collections without `reserve` and `reserve_exact` methods,
+/// like [`BTreeMap`](::std::collections::BTreeMap) or
+/// [`LinkedList`](::std::collections::LinkedList), which ignores the
+/// reservation.
+///
+/// Used by the spread entries of the macros, which reserve capacity for the
+/// items of the spread source before inserting them, and by the `extend_*`
+/// macros, which reserve capacity for the whole batch.
+/// Inherent methods take precedence over trait methods, so collections that
+/// can reserve capacity still use their own methods.
+///
+pub trait Reserve {
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn reserve_exact(&mut self, _additional: usize) {}
+}
+
+impl<T: ?Sized> Reserve for T {}
+
+/// Collects the entries of a map macro for a collection that is created with
+/// its [`FromIterator`](core::iter::FromIterator) implementation, like the
+/// persistent collections of `im` and `rpds`.
+///
+/// Provides the `insert` and `reserve` methods and the [`Extend`]
+/// implementation used by [`__entries!`](crate::__entries).
+///
+#[cfg(any(feature = "im", feature = "rpds"))]
+pub struct MapEntries<K, V>(alloc::vec::Vec<(K, V)>);
+
+#[cfg(any(feature = "im", feature = "rpds"))]
+impl<K, V> MapEntries<K, V> {
+    pub fn insert(&mut self, key: K, value: V) {
+        self.0.push((key, value));
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+}
+
+#[cfg(any(feature = "im", feature = "rpds"))]
+impl<K, V> Default for MapEntries<K, V> {
+    fn default() -> Self {
+        Self(alloc::vec::Vec::new())
+    }
+}
+
+#[cfg(any(feature = "im", feature = "rpds"))]
+impl<K, V> Extend<(K, V)> for MapEntries<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+#[cfg(any(feature = "im", feature = "rpds"))]
+impl<K, V> IntoIterator for MapEntries<K, V> {
+    type Item = (K, V);
+    type IntoIter = alloc::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Collects the entries of a map macro into a collection of type `C`.
+///
+/// The item type of the iterator of `&C` ties the key and value types of
+/// the collection to the types of the entries, which the
+/// [`FromIterator`](core::iter::FromIterator) implementations of `im`
+/// don't, because they accept every entry that can be converted into the key
+/// and value types.
+///
+#[cfg(any(feature = "im", feature = "rpds"))]
+#[must_use]
+pub fn collect_map<C, K, V, I>(entries: I) -> C
+where
+    I: IntoIterator<Item = (K, V)>,
+    C: core::iter::FromIterator<(K, V)>,
+    for<'a> &'a C: IntoIterator<Item = (&'a K, &'a V)>,
+{
+    C::from_iter(entries)
+}
+
+/// Same as [`collect_map`], but for the elements of a set or sequence
+/// macro.
+///
+#[cfg(any(feature = "im", feature = "rpds"))]
+#[must_use]
+pub fn collect_set<C, T, I>(elements: I) -> C
+where
+    I: IntoIterator<Item = T>,
+    C: core::iter::FromIterator<T>,
+    for<'a> &'a C: IntoIterator<Item = &'a T>,
+{
+    C::from_iter(elements)
+}
+
+/// Same as [`MapEntries`], but for the elements of a set macro.
+///
+#[cfg(any(feature = "im", feature = "rpds"))]
+pub struct SetEntries<T>(alloc::vec::Vec<T>);
+
+#[cfg(any(feature = "im", feature = "rpds"))]
+impl<T> SetEntries<T> {
+    pub fn insert(&mut self, value: T) {
+        self.0.push(value);
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+}
+
+#[cfg(any(feature = "im", feature = "rpds"))]
+impl<T> Default for SetEntries<T> {
+    fn default() -> Self {
+        Self(alloc::vec::Vec::new())
+    }
+}
+
+#[cfg(any(feature = "im", feature = "rpds"))]
+impl<T> Extend<T> for SetEntries<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+#[cfg(any(feature = "im", feature = "rpds"))]
+impl<T> IntoIterator for SetEntries<T> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Collects the entries of [`json_map!`](crate::serde_json::json_map) into
+/// a JSON object, converting the keys with [`Into<String>`] and the values
+/// with [`serde_json::to_value`](::serde_json::to_value).
+///
+/// Provides the `insert` method and the [`Extend`] implementation used by
+/// [`__entries!`](crate::__entries).
+/// The first value that can't be converted is kept as the error of the
+/// object, which is returned by [`JsonMap::into_result`].
+///
+#[cfg(feature = "serde_json")]
+#[derive(Default)]
+pub struct JsonMap {
+    map: ::serde_json::Map<alloc::string::String, ::serde_json::Value>,
+    error: Option<::serde_json::Error>,
+}
+
+#[cfg(feature = "serde_json")]
+impl JsonMap {
+    pub fn insert<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<alloc::string::String>,
+        V: ::serde::Serialize,
+    {
+        match ::serde_json::to_value(value) {
+            Ok(value) => {
+                self.map.insert(key.into(), value);
+            }
+            Err(e) => {
+                self.error.get_or_insert(e);
+            }
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if a value couldn't be converted to JSON, e.g. because it is a
+    /// map with keys that aren't strings.
+    ///
+    #[must_use]
+    pub fn into_inner(self) -> ::serde_json::Map<alloc::string::String, ::serde_json::Value> {
+        match self.into_result() {
+            Ok(map) => map,
+            Err(e) => panic!("failed to convert value of `json_map!` to JSON: {}", e),
+        }
+    }
+
+    /// # Errors
+    ///
+    /// Returns the error of the first value that couldn't be converted to
+    /// JSON.
+    ///
+    pub fn into_result(
+        self,
+    ) -> Result<::serde_json::Map<alloc::string::String, ::serde_json::Value>, ::serde_json::Error>
+    {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.map),
+        }
+    }
+}
+
+/// A nested object of [`json_map!`](crate::serde_json::json_map), which is
+/// serialized like the object or fails with its error, so that the error is
+/// passed on to the object containing it.
+///
+#[cfg(feature = "serde_json")]
+pub struct JsonObject(
+    pub Result<::serde_json::Map<alloc::string::String, ::serde_json::Value>, ::serde_json::Error>,
+);
+
+#[cfg(feature = "serde_json")]
+impl ::serde::Serialize for JsonObject {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.0 {
+            Ok(map) => map.serialize(serializer),
+            Err(e) => Err(<S::Error as ::serde::ser::Error>::custom(e)),
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<K, V> Extend<(K, V)> for JsonMap
+where
+    K: Into<alloc::string::String>,
+    V: ::serde::Serialize,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
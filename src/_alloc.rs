@@ -0,0 +1,1341 @@
+/// Macro for creating a [`BTreeMap`](::std::collections::BTreeMap).
+///
+/// Syntactic sugar for [`BTreeMap::from`](::std::collections::BTreeMap::from).
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::btree_map;
+///
+/// let goodbye = btree_map! {
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+///     "fr" => "Au revoir",
+///     "es" => "Adios",
+///     "cat" => "Adéu",
+/// };
+/// ```
+///
+/// # Comprehensions
+///
+/// Supports the same [comprehensions](crate::hash_map#comprehensions),
+/// [spread](crate::hash_map#spread),
+/// [conditional](crate::hash_map#conditional-entries) and
+/// [shorthand](crate::hash_map#shorthand-entries) entries as
+/// [`hash_map!`](crate::hash_map):
+///
+/// ```rust
+/// use map_macro::btree_map;
+///
+/// let squares = btree_map! { x => x * x for x in 0..10 if x % 2 == 0 };
+///
+/// assert_eq!(squares[&4], 16);
+///
+/// let more_squares = btree_map! { ..squares, 10 => 100 };
+///
+/// assert_eq!(more_squares.len(), 6);
+/// ```
+///
+#[macro_export]
+macro_rules! btree_map {
+    {@list $($k: expr => $v: expr),* $(,)?} => {
+        <$crate::__private::alloc::collections::BTreeMap<_, _> as ::core::convert::From<_>>::from([$(($k, $v),)*])
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut map = $crate::__private::alloc::collections::BTreeMap::new();
+            $crate::__entries!(@map map []; $($t)*);
+            map
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(btree_map map []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`btree_map!`].
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use std::fmt::Debug;
+///
+/// use map_macro::btree_map_e;
+///
+/// let goodbye: BTreeMap<&str, &dyn Debug> = btree_map_e! {
+///     "en" => &"Goodbye",
+///     "de" => &"Auf Wiedersehen",
+///     "fr" => &"Au revoir",
+///     "es" => &"Adios",
+///     "cat" => &"Adéu",
+/// };
+/// ```
+///
+/// Supports the same [comprehensions](crate::hash_map#comprehensions),
+/// [spread](crate::hash_map#spread),
+/// [conditional](crate::hash_map#conditional-entries) and
+/// [shorthand](crate::hash_map#shorthand-entries) entries as
+/// [`btree_map!`].
+///
+#[macro_export]
+macro_rules! btree_map_e {
+    {@list $($k: expr => $v: expr),* $(,)?} => {
+        <$crate::__private::alloc::collections::BTreeMap<_, _> as ::core::convert::From<_>>::from([$(($k as _, $v as _),)*])
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut map = $crate::__private::alloc::collections::BTreeMap::new();
+            $crate::__entries!(@map map [as _]; $($t)*);
+            map
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(btree_map_e map []; $($t)*)
+    };
+}
+
+/// Macro for creating a [`BTreeSet`](::std::collections::BTreeSet).
+///
+/// Syntactic sugar for [`BTreeSet::from`](::std::collections::BTreeSet::from).
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::btree_set;
+///
+/// let x = btree_set! { 1, 2, 3, 3, 4 };
+///
+/// assert_eq!(x.len(), 4);
+/// ```
+///
+/// # Comprehensions
+///
+/// Supports the same [comprehensions](crate::hash_set#comprehensions),
+/// [spread](crate::hash_set#spread),
+/// [conditional](crate::hash_set#conditional-elements) and
+/// [range](crate::hash_set#ranges) elements as [`hash_set!`](crate::hash_set):
+///
+/// ```rust
+/// use map_macro::btree_set;
+///
+/// let remainders = btree_set! { 42, x % 3 for x in 0..10 };
+///
+/// assert_eq!(remainders.len(), 4);
+///
+/// let more_remainders = btree_set! { ..remainders, 43 };
+///
+/// assert_eq!(more_remainders.len(), 5);
+///
+/// let letters = btree_set! { 'a'..='z' };
+///
+/// assert_eq!(letters.len(), 26);
+/// ```
+///
+#[macro_export]
+macro_rules! btree_set {
+    {@list $($v: expr),* $(,)?} => {
+        <$crate::__private::alloc::collections::BTreeSet<_> as ::core::convert::From<_>>::from([$($v,)*])
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut set = $crate::__private::alloc::collections::BTreeSet::new();
+            $crate::__entries!(@set set []; $($t)*);
+            set
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(btree_set set []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`btree_set!`].
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeSet;
+///
+/// use map_macro::btree_set_e;
+///
+/// enum Foo { A, B, C, D }
+///
+/// let x: BTreeSet<u8> = btree_set_e! { Foo::A, Foo::B, Foo::C, Foo::C, Foo::D };
+///
+/// assert_eq!(x.len(), 4);
+/// ```
+///
+/// Supports the same [comprehensions](crate::hash_set#comprehensions),
+/// [spread](crate::hash_set#spread),
+/// [conditional](crate::hash_set#conditional-elements) and
+/// [range](crate::hash_set#ranges) elements as [`btree_set!`].
+/// The items of spread sources and ranges are not cast.
+///
+#[macro_export]
+macro_rules! btree_set_e {
+    {@list $($v: expr),* $(,)?} => {
+        <$crate::__private::alloc::collections::BTreeSet<_> as ::core::convert::From<_>>::from([$($v as _,)*])
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut set = $crate::__private::alloc::collections::BTreeSet::new();
+            $crate::__entries!(@set set [as _]; $($t)*);
+            set
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(btree_set_e set []; $($t)*)
+    };
+}
+
+/// Macro for creating a [`VecDeque`](::std::collections::VecDeque).
+///
+/// Follows the same syntax as the [`vec!`](::std::vec!) macro.
+///
+/// # Examples
+///
+/// ```
+/// use map_macro::vec_deque;
+///
+/// let v = vec_deque![0, 1, 2, 3];
+/// let v = vec_deque![0; 4];
+/// ```
+///
+/// # Capacity
+///
+/// The deque is created with just enough capacity for the provided
+/// elements.
+/// If you intend to add more elements to it later on, you can reserve
+/// additional capacity up front with a leading `capacity: expr;` clause.
+/// The deque is then created with a capacity of at least `expr`:
+///
+/// ```rust
+/// use map_macro::vec_deque;
+///
+/// let v = vec_deque![capacity: 10; 0, 1, 2, 3];
+///
+/// assert_eq!(v.len(), 4);
+/// assert!(v.capacity() >= 10);
+/// ```
+///
+/// # Index-Aware Repetition
+///
+/// Like [`vec_no_clone!`](crate::vec_no_clone), the repeat form accepts an
+/// index-aware element written as `|i| expr; n`, which evaluates `expr` for
+/// every index `i` in `0..n`:
+///
+/// ```rust
+/// use map_macro::vec_deque;
+///
+/// let v = vec_deque![|i| i * 2; 4];
+///
+/// assert_eq!(v, [0, 2, 4, 6]);
+/// ```
+///
+/// # Spread
+///
+/// An element written as `..iterable` appends all items of `iterable`,
+/// which can be anything that implements [`IntoIterator`] with items of the
+/// element type, in place of the spread:
+///
+/// ```rust
+/// use map_macro::vec_deque;
+///
+/// let tail = vec![2, 3];
+///
+/// let v = vec_deque![0, 1, ..tail, 4];
+///
+/// assert_eq!(v, [0, 1, 2, 3, 4]);
+/// ```
+///
+/// Before the items of the spread source are appended, capacity is
+/// reserved for the lower bound of its [size hint](Iterator::size_hint),
+/// which is exact if the iterator of the source is an
+/// [`ExactSizeIterator`].
+///
+/// # Ranges
+///
+/// A range whose start is a literal, like `1..=10` or `'a'..n`, appends all
+/// items of the range, as if it were [spread](crate::vec_deque#spread):
+///
+/// ```rust
+/// use map_macro::vec_deque;
+///
+/// let v = vec_deque![0, 1..=3, 10..12];
+///
+/// assert_eq!(v, [0, 1, 2, 3, 10, 11]);
+/// ```
+///
+/// Other ranges, like `start..end` or `(1..3)`, are appended as elements.
+///
+#[macro_export]
+macro_rules! vec_deque {
+    {@list capacity: $c: expr; $($v: expr),* $(,)?} => {
+        {
+            let mut vec = $crate::__private::alloc::collections::VecDeque::with_capacity(
+                ::core::cmp::max($c, $crate::__count!($($v)*)),
+            );
+            $(vec.push_back($v);)*
+            vec
+        }
+    };
+    {@list $($v: expr),* $(,)?} => {
+        <$crate::__private::alloc::collections::VecDeque<_> as ::core::convert::From<_>>::from([$($v,)*])
+    };
+    {@entries capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut vec = $crate::__private::alloc::collections::VecDeque::with_capacity($c);
+            $crate::__entries!(@seq vec []; $($t)*);
+            vec
+        }
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut vec = $crate::__private::alloc::collections::VecDeque::new();
+            $crate::__entries!(@seq vec []; $($t)*);
+            vec
+        }
+    };
+    {|$i: pat| $v: expr; $c: expr} => {
+        {
+            let count = $c;
+            let mut vec = $crate::__private::alloc::collections::VecDeque::with_capacity(count);
+
+            for $i in 0..count {
+                vec.push_back($v);
+            }
+
+            vec
+        }
+    };
+    {$v: expr; $c: expr} => {
+        {
+            let count = $c;
+            let mut vec = $crate::__private::alloc::collections::VecDeque::with_capacity(count);
+
+            for _ in 0..count {
+                vec.push_back($v);
+            }
+
+            vec
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(vec_deque set []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`vec_deque!`].
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use std::fmt::Debug;
+///
+/// use map_macro::vec_deque_e;
+///
+/// let v: VecDeque<&dyn Debug> = vec_deque_e![&0, &1, &2, &3];
+/// let v: VecDeque<&dyn Debug> = vec_deque_e![&0; 4];
+/// ```
+///
+/// Supports the same [`capacity`](vec_deque#capacity) clause and
+/// [spread](vec_deque#spread) elements as [`vec_deque!`].
+/// The items of spread sources and ranges are not cast.
+///
+#[macro_export]
+macro_rules! vec_deque_e {
+    {@list capacity: $c: expr; $($v: expr),* $(,)?} => {
+        {
+            let mut vec = $crate::__private::alloc::collections::VecDeque::with_capacity(
+                ::core::cmp::max($c, $crate::__count!($($v)*)),
+            );
+            $(vec.push_back($v as _);)*
+            vec
+        }
+    };
+    {@list $($v: expr),* $(,)?} => {
+        <$crate::__private::alloc::collections::VecDeque<_> as ::core::convert::From<_>>::from([$($v as _,)*])
+    };
+    {@entries capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut vec = $crate::__private::alloc::collections::VecDeque::with_capacity($c);
+            $crate::__entries!(@seq vec [as _]; $($t)*);
+            vec
+        }
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut vec = $crate::__private::alloc::collections::VecDeque::new();
+            $crate::__entries!(@seq vec [as _]; $($t)*);
+            vec
+        }
+    };
+    {|$i: pat| $v: expr; $c: expr} => {
+        {
+            let count = $c;
+            let mut vec = $crate::__private::alloc::collections::VecDeque::with_capacity(count);
+
+            for $i in 0..count {
+                vec.push_back($v as _);
+            }
+
+            vec
+        }
+    };
+    {$v: expr; $c: expr} => {
+        {
+            let count = $c;
+            let mut vec = $crate::__private::alloc::collections::VecDeque::with_capacity(count);
+
+            for _ in 0..count {
+                vec.push_back($v as _);
+            }
+
+            vec
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(vec_deque_e set []; $($t)*)
+    };
+}
+
+/// Macro for creating a [`LinkedList`](::std::collections::LinkedList).
+///
+/// Follows the same syntax as the [`vec!`](::std::vec!) macro.
+///
+/// # Examples
+///
+/// ```
+/// use map_macro::linked_list;
+///
+/// let v = linked_list![0, 1, 2, 3];
+/// let v = linked_list![0; 4];
+/// ```
+///
+/// # Index-Aware Repetition
+///
+/// Like [`vec_no_clone!`](crate::vec_no_clone), the repeat form accepts an
+/// index-aware element written as `|i| expr; n`, which evaluates `expr` for
+/// every index `i` in `0..n`:
+///
+/// ```rust
+/// use map_macro::linked_list;
+///
+/// let l = linked_list![|i| i * 2; 4];
+///
+/// assert!(l.into_iter().eq([0, 2, 4, 6]));
+/// ```
+///
+/// # Spread
+///
+/// Like [`vec_deque!`](crate::vec_deque#spread), an element written as
+/// `..iterable` appends all items of `iterable`:
+///
+/// ```rust
+/// use map_macro::linked_list;
+///
+/// let l = linked_list![0, ..[1, 2], 3];
+///
+/// assert!(l.into_iter().eq([0, 1, 2, 3]));
+/// ```
+///
+/// [Ranges](crate::vec_deque#ranges) whose start is a literal are spread,
+/// too.
+///
+#[macro_export]
+macro_rules! linked_list {
+    {@list $($v: expr),* $(,)?} => {
+        <$crate::__private::alloc::collections::LinkedList<_> as ::core::convert::From<_>>::from([$($v,)*])
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut ll = $crate::__private::alloc::collections::LinkedList::new();
+            $crate::__entries!(@seq ll []; $($t)*);
+            ll
+        }
+    };
+    {|$i: pat| $v: expr; $c: expr} => {
+        {
+            let count = $c;
+            let mut ll = $crate::__private::alloc::collections::LinkedList::new();
+
+            for $i in 0..count {
+                ll.push_back($v);
+            }
+
+            ll
+        }
+    };
+    {$v: expr; $c: expr} => {
+        {
+            let count = $c;
+            let mut ll = $crate::__private::alloc::collections::LinkedList::new();
+
+            for _ in 0..count {
+                ll.push_back($v);
+            }
+
+            ll
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(linked_list set []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`linked_list!`].
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::LinkedList;
+/// use std::fmt::Debug;
+///
+/// use map_macro::linked_list_e;
+///
+/// let v: LinkedList<&dyn Debug> = linked_list_e![&0, &1, &2, &3];
+/// let v: LinkedList<&dyn Debug> = linked_list_e![&0; 4];
+/// ```
+///
+/// Supports the same [spread](linked_list#spread) elements as
+/// [`linked_list!`].
+/// The items of spread sources and ranges are not cast.
+///
+#[macro_export]
+macro_rules! linked_list_e {
+    {@list $($v: expr),* $(,)?} => {
+        <$crate::__private::alloc::collections::LinkedList<_> as ::core::convert::From<_>>::from([$($v as _,)*])
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut ll = $crate::__private::alloc::collections::LinkedList::new();
+            $crate::__entries!(@seq ll [as _]; $($t)*);
+            ll
+        }
+    };
+    {|$i: pat| $v: expr; $c: expr} => {
+        {
+            let count = $c;
+            let mut ll = $crate::__private::alloc::collections::LinkedList::new();
+
+            for $i in 0..count {
+                ll.push_back($v as _);
+            }
+
+            ll
+        }
+    };
+    {$v: expr; $c: expr} => {
+        {
+            let count = $c;
+            let mut ll = $crate::__private::alloc::collections::LinkedList::new();
+
+            for _ in 0..count {
+                ll.push_back($v as _);
+            }
+
+            ll
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(linked_list_e set []; $($t)*)
+    };
+}
+
+/// Macro for creating a [`BinaryHeap`](::std::collections::BinaryHeap).
+///
+/// Follows the same syntax as the [`vec!`](::std::vec!) macro.
+///
+/// # Examples
+///
+/// ```
+/// use map_macro::binary_heap;
+///
+/// let v = binary_heap![0, 1, 2, 3];
+/// let v = binary_heap![0; 4];
+/// ```
+///
+/// # Capacity
+///
+/// The heap is created with just enough capacity for the provided
+/// elements.
+/// If you intend to add more elements to it later on, you can reserve
+/// additional capacity up front with a leading `capacity: expr;` clause.
+/// The heap is then created with a capacity of at least `expr`:
+///
+/// ```rust
+/// use map_macro::binary_heap;
+///
+/// let v = binary_heap![capacity: 10; 0, 1, 2, 3];
+///
+/// assert_eq!(v.len(), 4);
+/// assert!(v.capacity() >= 10);
+/// ```
+///
+/// # Index-Aware Repetition
+///
+/// Like [`vec_no_clone!`](crate::vec_no_clone), the repeat form accepts an
+/// index-aware element written as `|i| expr; n`, which evaluates `expr` for
+/// every index `i` in `0..n`:
+///
+/// ```rust
+/// use map_macro::binary_heap;
+///
+/// let h = binary_heap![|i| i * 2; 4];
+///
+/// assert_eq!(h.into_sorted_vec(), vec![0, 2, 4, 6]);
+/// ```
+///
+/// # Spread
+///
+/// Like [`vec_deque!`](crate::vec_deque#spread), an element written as
+/// `..iterable` appends all items of `iterable`:
+///
+/// ```rust
+/// use map_macro::binary_heap;
+///
+/// let h = binary_heap![..vec![1, 5], 3];
+///
+/// assert_eq!(h.into_sorted_vec(), vec![1, 3, 5]);
+/// ```
+///
+/// [Ranges](crate::vec_deque#ranges) whose start is a literal are spread,
+/// too.
+///
+#[macro_export]
+macro_rules! binary_heap {
+    {@list capacity: $c: expr; $($v: expr),* $(,)?} => {
+        {
+            let mut bh = $crate::__private::alloc::collections::BinaryHeap::with_capacity(
+                ::core::cmp::max($c, $crate::__count!($($v)*)),
+            );
+            $(bh.push($v);)*
+            bh
+        }
+    };
+    {@list $($v: expr),* $(,)?} => {
+        <$crate::__private::alloc::collections::BinaryHeap<_> as ::core::convert::From<_>>::from([$($v,)*])
+    };
+    {@entries capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut bh = $crate::__private::alloc::collections::BinaryHeap::with_capacity($c);
+            $crate::__entries!(@seq bh []; $($t)*);
+            bh
+        }
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut bh = $crate::__private::alloc::collections::BinaryHeap::new();
+            $crate::__entries!(@seq bh []; $($t)*);
+            bh
+        }
+    };
+    {|$i: pat| $v: expr; $c: expr} => {
+        {
+            let count = $c;
+            let mut bh = $crate::__private::alloc::collections::BinaryHeap::with_capacity(count);
+
+            for $i in 0..count {
+                bh.push($v);
+            }
+
+            bh
+        }
+    };
+    {$v: expr; $c: expr} => {
+        {
+            let count = $c;
+            let mut bh = $crate::__private::alloc::collections::BinaryHeap::with_capacity(count);
+
+            for _ in 0..count {
+                bh.push($v);
+            }
+
+            bh
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(binary_heap set []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`binary_heap!`].
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BinaryHeap;
+///
+/// use map_macro::binary_heap_e;
+///
+/// enum Foo { A, B, C, D }
+///
+/// let v: BinaryHeap<u8> = binary_heap_e![Foo::A, Foo::B, Foo::C, Foo::D];
+/// let v: BinaryHeap<u8> = binary_heap_e![Foo::A; 4];
+/// ```
+///
+/// Supports the same [`capacity`](binary_heap#capacity) clause and
+/// [spread](binary_heap#spread) elements as [`binary_heap!`].
+/// The items of spread sources and ranges are not cast.
+///
+#[macro_export]
+macro_rules! binary_heap_e {
+    {@list capacity: $c: expr; $($v: expr),* $(,)?} => {
+        {
+            let mut bh = $crate::__private::alloc::collections::BinaryHeap::with_capacity(
+                ::core::cmp::max($c, $crate::__count!($($v)*)),
+            );
+            $(bh.push($v as _);)*
+            bh
+        }
+    };
+    {@list $($v: expr),* $(,)?} => {
+        <$crate::__private::alloc::collections::BinaryHeap<_> as ::core::convert::From<_>>::from([$($v as _,)*])
+    };
+    {@entries capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut bh = $crate::__private::alloc::collections::BinaryHeap::with_capacity($c);
+            $crate::__entries!(@seq bh [as _]; $($t)*);
+            bh
+        }
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut bh = $crate::__private::alloc::collections::BinaryHeap::new();
+            $crate::__entries!(@seq bh [as _]; $($t)*);
+            bh
+        }
+    };
+    {|$i: pat| $v: expr; $c: expr} => {
+        {
+            let count = $c;
+            let mut bh = $crate::__private::alloc::collections::BinaryHeap::with_capacity(count);
+
+            for $i in 0..count {
+                bh.push($v as _);
+            }
+
+            bh
+        }
+    };
+    {$v: expr; $c: expr} => {
+        {
+            let count = $c;
+            let mut bh = $crate::__private::alloc::collections::BinaryHeap::with_capacity(count);
+
+            for _ in 0..count {
+                bh.push($v as _);
+            }
+
+            bh
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(binary_heap_e set []; $($t)*)
+    };
+}
+
+/// Macro for creating a [`BinaryHeap`](::std::collections::BinaryHeap) that
+/// pops its smallest element first.
+///
+/// Wraps every element in [`Reverse`](::core::cmp::Reverse), which turns
+/// the max-heap of the standard library into a min-heap.
+/// Otherwise follows the same syntax as the [`binary_heap!`](crate::binary_heap)
+/// macro, including the `capacity: expr;` clause and the index-aware
+/// repeat form.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Reverse;
+///
+/// use map_macro::min_heap;
+///
+/// let mut h = min_heap![3, 1, 2];
+///
+/// assert_eq!(h.pop(), Some(Reverse(1)));
+///
+/// let h = min_heap![0; 4];
+/// let h = min_heap![|i| 10 - i; 4];
+///
+/// assert_eq!(h.peek(), Some(&Reverse(7)));
+/// ```
+///
+#[macro_export]
+macro_rules! min_heap {
+    {capacity: $c: expr; $($v: expr),* $(,)?} => {
+        $crate::binary_heap!{capacity: $c; $(::core::cmp::Reverse($v)),*}
+    };
+    {|$i: pat| $v: expr; $c: expr} => {
+        $crate::binary_heap!{|$i| ::core::cmp::Reverse($v); $c}
+    };
+    {$v: expr; $c: expr} => {
+        $crate::binary_heap!{::core::cmp::Reverse($v); $c}
+    };
+    {$($v: expr),* $(,)?} => {
+        $crate::binary_heap!{$(::core::cmp::Reverse($v)),*}
+    };
+}
+
+/// Explicitly typed equivalent of [`min_heap!`].
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Reverse;
+/// use std::collections::BinaryHeap;
+///
+/// use map_macro::min_heap_e;
+///
+/// enum Foo { A, B, C, D }
+///
+/// let mut v: BinaryHeap<Reverse<u8>> = min_heap_e![Foo::D, Foo::B, Foo::C, Foo::A];
+///
+/// assert_eq!(v.pop(), Some(Reverse(0)));
+/// ```
+///
+#[macro_export]
+macro_rules! min_heap_e {
+    {capacity: $c: expr; $($v: expr),* $(,)?} => {
+        $crate::binary_heap!{capacity: $c; $(::core::cmp::Reverse($v as _)),*}
+    };
+    {|$i: pat| $v: expr; $c: expr} => {
+        $crate::binary_heap!{|$i| ::core::cmp::Reverse($v as _); $c}
+    };
+    {$v: expr; $c: expr} => {
+        $crate::binary_heap!{::core::cmp::Reverse($v as _); $c}
+    };
+    {$($v: expr),* $(,)?} => {
+        $crate::binary_heap!{$(::core::cmp::Reverse($v as _)),*}
+    };
+}
+
+/// Macro for creating a [`BinaryHeap`](::std::collections::BinaryHeap) of
+/// items ordered by a priority.
+///
+/// Every `item => priority` entry is stored as a
+/// [`Prioritized`](crate::Prioritized), which compares only the priorities.
+/// The item with the highest priority is popped first, so the item itself
+/// does not need to implement [`Ord`].
+/// Otherwise follows the same syntax as the [`binary_heap!`](crate::binary_heap)
+/// macro, including the `capacity: expr;` clause and the index-aware
+/// repeat form.
+///
+/// # Examples
+///
+/// ```
+/// use map_macro::priority_queue;
+///
+/// let mut tasks = priority_queue! {
+///     "write docs" => 1,
+///     "fix bug" => 10,
+///     "review" => 5,
+/// };
+///
+/// assert_eq!(tasks.pop().unwrap().item, "fix bug");
+/// assert_eq!(tasks.pop().unwrap().into_item(), "review");
+///
+/// let workers = priority_queue![|i| format!("worker {}", i) => i % 2; 4];
+///
+/// assert_eq!(workers.peek().unwrap().priority, 1);
+/// ```
+///
+/// Wrap the priority in [`Reverse`](::core::cmp::Reverse) to pop the item
+/// with the lowest priority first, like in Dijkstra's algorithm:
+///
+/// ```
+/// use std::cmp::Reverse;
+///
+/// use map_macro::priority_queue;
+///
+/// let mut frontier = priority_queue! {
+///     'b' => Reverse(7),
+///     'c' => Reverse(2),
+/// };
+///
+/// assert_eq!(frontier.pop().unwrap().item, 'c');
+/// ```
+///
+#[macro_export]
+macro_rules! priority_queue {
+    {capacity: $c: expr; $($v: expr => $p: expr),* $(,)?} => {
+        $crate::binary_heap!{capacity: $c; $($crate::Prioritized::new($v, $p)),*}
+    };
+    {|$i: pat| $v: expr => $p: expr; $c: expr} => {
+        $crate::binary_heap!{|$i| $crate::Prioritized::new($v, $p); $c}
+    };
+    {$v: expr => $p: expr; $c: expr} => {
+        $crate::binary_heap!{$crate::Prioritized::new($v, $p); $c}
+    };
+    {$($v: expr => $p: expr),* $(,)?} => {
+        $crate::binary_heap!{$($crate::Prioritized::new($v, $p)),*}
+    };
+}
+
+/// Explicitly typed equivalent of [`priority_queue!`].
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// Casts both the items and their priorities.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BinaryHeap;
+/// use std::fmt::Debug;
+///
+/// use map_macro::{priority_queue_e, Prioritized};
+///
+/// let mut queue: BinaryHeap<Prioritized<&dyn Debug, u64>> = priority_queue_e! {
+///     &"Hello" => 1u8,
+///     &2.5 => 2u32,
+/// };
+///
+/// assert_eq!(queue.pop().unwrap().priority, 2);
+/// ```
+///
+#[macro_export]
+macro_rules! priority_queue_e {
+    {capacity: $c: expr; $($v: expr => $p: expr),* $(,)?} => {
+        $crate::binary_heap!{capacity: $c; $($crate::Prioritized::new($v as _, $p as _)),*}
+    };
+    {|$i: pat| $v: expr => $p: expr; $c: expr} => {
+        $crate::binary_heap!{|$i| $crate::Prioritized::new($v as _, $p as _); $c}
+    };
+    {$v: expr => $p: expr; $c: expr} => {
+        $crate::binary_heap!{$crate::Prioritized::new($v as _, $p as _); $c}
+    };
+    {$($v: expr => $p: expr),* $(,)?} => {
+        $crate::binary_heap!{$($crate::Prioritized::new($v as _, $p as _)),*}
+    };
+}
+
+/// Version of the [`vec!`](::std::vec!) macro where the value does not have to implement [`Clone`].
+///
+/// Useful for unclonable types or where `Clone` is exerting undesired behaviour.
+///
+/// # Uncloneable Types
+///
+/// When using `vec![x; count]`, the type of `x` has to implement `Clone`, because
+/// `x` is cloned `count - 1` times into all the vector elements except the first one.
+/// For example, calling `vec!` will result in a panic during compile time here,
+/// because `UnclonableWrapper` is not cloneable:
+///
+/// ```compile_fail
+/// struct UnclonableWrapper(u8);
+///
+/// let x = vec![UnclonableWrapper(0); 5];
+/// ```
+///
+/// The `vec_no_clone!` macro takes a different approach.
+/// Instead of cloning `UnclonableWrapper(0)`, it treats it as an
+/// [expression](https://doc.rust-lang.org/reference/expressions.html) which is
+/// called 5 times in this case.
+/// So 5 independent `UnclonableWrapper` objects, each with its own location in
+/// memory, are created:
+///
+/// ```rust
+/// use map_macro::vec_no_clone;
+///
+/// struct UnclonableWrapper(u8);
+///
+/// let x = vec_no_clone![UnclonableWrapper(0); 5];
+///
+/// assert_eq!(x.len(), 5);
+/// ```
+///
+/// A real-world example where `vec_no_clone!` is a useful drop-in replacement
+/// for `vec!` are [atomic types](::std::sync::atomic), which are not clonable:
+///
+/// ```rust
+/// use std::sync::atomic::AtomicU8;
+///
+/// use map_macro::vec_no_clone;
+///
+/// let x = vec_no_clone![AtomicU8::new(0); 5];
+///
+/// assert_eq!(x.len(), 5);
+/// ```
+///
+/// # Types where `Clone` exerts the wrong Behaviour
+///
+/// `vec_no_clone!` is not only useful for unclonable types, but also for types
+/// where cloning them is not what you want.
+/// The best example would be a reference counted pointer [`Rc`](::std::rc::Rc).
+/// When you clone an `Rc`, a new instance referencing the same location in memory
+/// is created.
+/// If you'd rather have multiple independent reference counted pointers to
+/// different memory locations, you can use `vec_no_clone!` as well:
+///
+/// ```rust
+/// use map_macro::vec_no_clone;
+///
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// // simply clones the reference counted pointer for each element that
+/// // is not the first
+/// let shared_vec = vec![Rc::new(RefCell::new(0)); 2];
+/// {
+///     let mut first = shared_vec[0].borrow_mut();
+///     *first += 1;
+/// }
+///
+/// assert_eq!(*shared_vec[0].borrow(), 1);
+///
+/// // the second element is a clone of the reference counted pointer at
+/// // the first element of the vector, referencing the same address in
+/// // memory, therefore being mutated as well
+/// assert_eq!(*shared_vec[1].borrow(), 1);
+///
+/// // the `vec_no_clone!` macro does not clone the object created by the
+/// // first expression but instead calls the expression for each element
+/// // in the vector, creating two independent objects, each with their
+/// // own address in memory
+/// let unshared_vec = vec_no_clone![Rc::new(RefCell::new(0)); 2];
+///
+/// {
+///     let mut first = unshared_vec[0].borrow_mut();
+///     *first += 1;
+/// }
+///
+/// assert_eq!(*unshared_vec[0].borrow(), 1);
+///
+/// // the second element is not the same cloned reference counted
+/// // pointer as it would be if it were constructed with the `vec!` macro
+/// // from the standard library like it was above, therefore it is not
+/// // mutated
+/// assert_eq!(*unshared_vec[1].borrow(), 0);
+/// ```
+///
+/// # Drawbacks of using Expressions
+///
+/// Since `vec_no_clone!` treats the value as an expression, you must provide the
+/// initialization as input directly.
+/// This, for example, won't work:
+///
+/// ```compile_fail
+/// use map_macro::vec_no_clone;
+///
+/// struct UnclonableWrapper(u8);
+///
+/// let a = UnclonableWrapper(0);
+///
+/// // a will have moved into the first element of x, raising a compile
+/// // time error for the second element.
+/// let x = vec_no_clone![a; 5];
+/// ```
+///
+/// # Processing Lists of Elements
+///
+/// You can also use the macro with a list of elements, like `vec!`.
+/// In fact, `vec_no_clone!` falls back to `vec!` in this case:
+///
+/// ```rust
+/// use map_macro::vec_no_clone;
+///
+/// let v1 = vec_no_clone![0, 1, 2, 3];
+/// let v2 = vec![0, 1, 2, 3];
+///
+/// assert_eq!(v1, v2);
+///
+/// let v1: Vec<u8> = vec_no_clone![];
+/// let v2: Vec<u8> = vec![];
+///
+/// assert_eq!(v1, v2);
+/// ```
+///
+/// # Capacity
+///
+/// The vector is created with just enough capacity for the provided
+/// elements.
+/// If you intend to add more elements to it later on, you can reserve
+/// additional capacity up front with a leading `capacity: expr;` clause.
+/// The vector is then created with a capacity of at least `expr`:
+///
+/// ```rust
+/// use map_macro::vec_no_clone;
+///
+/// let v = vec_no_clone![capacity: 10; 0, 1, 2, 3];
+///
+/// assert_eq!(v, vec![0, 1, 2, 3]);
+/// assert!(v.capacity() >= 10);
+/// ```
+///
+/// # Index-Aware Repetition
+///
+/// If the element needs to know its position in the vector, you can write
+/// it as `|i| expr; n`.
+/// `expr` is then evaluated once for every index `i` in `0..n`.
+/// `n` itself is evaluated only once:
+///
+/// ```rust
+/// use map_macro::vec_no_clone;
+///
+/// let v = vec_no_clone![|i| format!("item {}", i); 3];
+///
+/// assert_eq!(v, vec!["item 0", "item 1", "item 2"]);
+/// ```
+///
+/// Instead of an identifier you can use any irrefutable pattern that
+/// matches a `usize`, like `_`.
+///
+/// Because of this, an element that is a closure must be wrapped in
+/// parentheses to be repeated:
+///
+/// ```rust
+/// use map_macro::vec_no_clone_e;
+///
+/// let v: Vec<fn(usize) -> usize> = vec_no_clone_e![(|x: usize| x + 1); 3];
+///
+/// assert_eq!(v[2](1), 2);
+/// ```
+///
+/// # Spread
+///
+/// Like [`vec_deque!`](crate::vec_deque#spread), an element written as
+/// `..iterable` appends all items of `iterable`:
+///
+/// ```rust
+/// use map_macro::vec_no_clone;
+///
+/// let v = vec_no_clone![0, ..1..3, 3];
+///
+/// assert_eq!(v, vec![0, 1, 2, 3]);
+/// ```
+///
+/// [Ranges](crate::vec_deque#ranges) whose start is a literal are spread,
+/// too, which reserves exactly the length of the range:
+///
+/// ```rust
+/// use map_macro::vec_no_clone;
+///
+/// let v = vec_no_clone![1..=100];
+///
+/// assert_eq!(v.capacity(), 100);
+/// ```
+///
+#[macro_export]
+macro_rules! vec_no_clone {
+    {@list capacity: $c: expr; $($v: expr),* $(,)?} => {
+        {
+            let mut vec = $crate::__private::alloc::vec::Vec::with_capacity(
+                ::core::cmp::max($c, $crate::__count!($($v)*)),
+            );
+            $(vec.push($v);)*
+            vec
+        }
+    };
+    {@list $($v: expr),* $(,)?} => {
+        {
+            $crate::__private::alloc::vec![$($v),*]
+        }
+    };
+    {@entries capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut vec = $crate::__private::alloc::vec::Vec::with_capacity($c);
+            $crate::__entries!(@seq vec []; $($t)*);
+            vec
+        }
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut vec = $crate::__private::alloc::vec::Vec::new();
+            $crate::__entries!(@seq vec []; $($t)*);
+            vec
+        }
+    };
+    {|$i: pat| $v: expr; $c: expr} => {
+        {
+            let count = $c;
+            let mut vec = $crate::__private::alloc::vec::Vec::with_capacity(count);
+
+            for $i in 0..count {
+                vec.push($v);
+            }
+
+            vec
+        }
+    };
+    {$v: expr; $c: expr} => {
+        {
+            let count = $c;
+            let mut vec = $crate::__private::alloc::vec::Vec::with_capacity(count);
+
+            for _ in 0..count {
+                vec.push($v);
+            }
+
+            vec
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(vec_no_clone set []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`vec_no_clone!`].
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::Display;
+///
+/// use map_macro::vec_no_clone_e;
+///
+/// let v: Vec<&dyn Display> = vec_no_clone_e![&0; 4];
+/// ```
+///
+/// Supports the same [`capacity`](vec_no_clone#capacity) clause and
+/// [spread](vec_no_clone#spread) elements as [`vec_no_clone!`].
+/// The items of spread sources and ranges are not cast.
+///
+#[macro_export]
+macro_rules! vec_no_clone_e {
+    {@list capacity: $c: expr; $($v: expr),* $(,)?} => {
+        {
+            let mut vec = $crate::__private::alloc::vec::Vec::with_capacity(
+                ::core::cmp::max($c, $crate::__count!($($v)*)),
+            );
+            $(vec.push($v as _);)*
+            vec
+        }
+    };
+    {@list $($v: expr),* $(,)?} => {
+        {
+            $crate::__private::alloc::vec![$($v as _),*]
+        }
+    };
+    {@entries capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut vec = $crate::__private::alloc::vec::Vec::with_capacity($c);
+            $crate::__entries!(@seq vec [as _]; $($t)*);
+            vec
+        }
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut vec = $crate::__private::alloc::vec::Vec::new();
+            $crate::__entries!(@seq vec [as _]; $($t)*);
+            vec
+        }
+    };
+    {|$i: pat| $v: expr; $c: expr} => {
+        {
+            let count = $c;
+            let mut vec = $crate::__private::alloc::vec::Vec::with_capacity(count);
+
+            for $i in 0..count {
+                vec.push($v as _);
+            }
+
+            vec
+        }
+    };
+    {$v: expr; $c: expr} => {
+        {
+            let count = $c;
+            let mut vec = $crate::__private::alloc::vec::Vec::with_capacity(count);
+
+            for _ in 0..count {
+                vec.push($v as _);
+            }
+
+            vec
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(vec_no_clone_e set []; $($t)*)
+    };
+}
+
+/// Macro for creating a [`BTreeMap`](::std::collections::BTreeMap) that
+/// maps each key to a collection of values.
+///
+/// Entries with the same key don't overwrite each other, their values are
+/// collected into an inner collection instead, which is a
+/// [`Vec`](::std::vec::Vec) by default.
+/// Supports the same `k => [v1, v2]` entries and
+/// [`inner`](crate::hash_multimap#inner-collection) clause as
+/// [`hash_multimap!`](crate::hash_multimap).
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeSet;
+///
+/// use map_macro::btree_multimap;
+///
+/// let routes = btree_multimap! {
+///     inner: BTreeSet<_>;
+///     "/" => "index",
+///     "/users" => ["list_users", "create_user"],
+///     "/" => ["health", "index"],
+/// };
+///
+/// assert_eq!(routes.keys().next(), Some(&"/"));
+/// assert_eq!(routes["/"].len(), 2);
+/// ```
+///
+#[macro_export]
+macro_rules! btree_multimap {
+    {inner: $inner: ty; $($t: tt)*} => {
+        {
+            let mut map = $crate::__private::alloc::collections::BTreeMap::new();
+            $crate::__multimap!(map $inner, []; $($t)*);
+            map
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::btree_multimap!{inner: $crate::__private::alloc::vec::Vec<_>; $($t)*}
+    };
+}
+
+/// Explicitly typed equivalent of [`btree_multimap!`].
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use std::fmt::Debug;
+///
+/// use map_macro::btree_multimap_e;
+///
+/// let values: BTreeMap<&str, Vec<&dyn Debug>> = btree_multimap_e! {
+///     "numbers" => [&1, &2.5],
+///     "strings" => &"Hello",
+/// };
+///
+/// assert_eq!(values["numbers"].len(), 2);
+/// ```
+///
+#[macro_export]
+macro_rules! btree_multimap_e {
+    {inner: $inner: ty; $($t: tt)*} => {
+        {
+            let mut map = $crate::__private::alloc::collections::BTreeMap::new();
+            $crate::__multimap!(map $inner, [as _]; $($t)*);
+            map
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::btree_multimap_e!{inner: $crate::__private::alloc::vec::Vec<_>; $($t)*}
+    };
+}
@@ -0,0 +1,155 @@
+use core::borrow::Borrow;
+use core::hash::Hash;
+
+use std::collections::HashMap;
+
+/// Map of one-to-one relations between left and right values, which can be
+/// looked up from either side.
+///
+/// Created by the [`bi_map!`](crate::bi_map) macro.
+/// Both sides are stored in a [`HashMap`], so the values of both sides are
+/// kept twice and must implement [`Clone`].
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::bi_map;
+///
+/// let codecs = bi_map! {
+///     "h264" => 27,
+///     "hevc" => 173,
+/// };
+///
+/// assert_eq!(codecs.get_by_left("hevc"), Some(&173));
+/// assert_eq!(codecs.get_by_right(&27), Some(&"h264"));
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct BiHashMap<L, R> {
+    left: HashMap<L, R>,
+    right: HashMap<R, L>,
+}
+
+impl<L, R> BiHashMap<L, R> {
+    /// Creates an empty map.
+    ///
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            left: HashMap::new(),
+            right: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of pairs in the map.
+    ///
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    /// Returns `true` if the map contains no pairs.
+    ///
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty()
+    }
+
+    /// Returns an iterator over the `(left, right)` pairs of the map in
+    /// arbitrary order.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (&L, &R)> {
+        self.left.iter()
+    }
+}
+
+impl<L: Eq + Hash, R: Eq + Hash> BiHashMap<L, R> {
+    /// Returns the right value that is paired with `left`.
+    ///
+    pub fn get_by_left<Q>(&self, left: &Q) -> Option<&R>
+    where
+        L: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.left.get(left)
+    }
+
+    /// Returns the left value that is paired with `right`.
+    ///
+    pub fn get_by_right<Q>(&self, right: &Q) -> Option<&L>
+    where
+        R: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.right.get(right)
+    }
+
+    /// Returns `true` if `left` is paired with a right value.
+    ///
+    pub fn contains_left<Q>(&self, left: &Q) -> bool
+    where
+        L: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.left.contains_key(left)
+    }
+
+    /// Returns `true` if `right` is paired with a left value.
+    ///
+    pub fn contains_right<Q>(&self, right: &Q) -> bool
+    where
+        R: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.right.contains_key(right)
+    }
+}
+
+impl<L: Eq + Hash + Clone, R: Eq + Hash + Clone> BiHashMap<L, R> {
+    /// Inserts the pair `(left, right)` into the map.
+    ///
+    /// # Errors
+    ///
+    /// Returns the pair without inserting it if either `left` or `right` is
+    /// already part of another pair.
+    ///
+    pub fn try_insert(&mut self, left: L, right: R) -> Result<(), (L, R)> {
+        if self.left.contains_key(&left) || self.right.contains_key(&right) {
+            return Err((left, right));
+        }
+
+        self.left.insert(left.clone(), right.clone());
+        self.right.insert(right, left);
+
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    pub fn __insert(&mut self, left: L, right: R) {
+        assert!(
+            !self.contains_left(&left),
+            "duplicate left value in `bi_map!`",
+        );
+        assert!(
+            !self.contains_right(&right),
+            "duplicate right value in `bi_map!`",
+        );
+
+        self.left.insert(left.clone(), right.clone());
+        self.right.insert(right, left);
+    }
+}
+
+impl<L, R> Default for BiHashMap<L, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L: Eq + Hash, R: Eq + Hash> PartialEq for BiHashMap<L, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.left == other.left
+    }
+}
+
+impl<L: Eq + Hash, R: Eq + Hash> Eq for BiHashMap<L, R> {}
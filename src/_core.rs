@@ -0,0 +1,265 @@
+/// Macro for adding entries to an existing map.
+///
+/// Syntactic sugar for [`Extend::extend`](::core::iter::Extend::extend)
+/// with an array of the provided entries, after reserving capacity for the
+/// whole batch once.
+/// Maps without a `reserve` method, like
+/// [`BTreeMap`](::std::collections::BTreeMap), are extended without
+/// reserving capacity.
+/// Works with any collection that implements
+/// [`Extend<(K, V)>`](::core::iter::Extend), like the maps from the standard
+/// library or [`hashbrown`](https://docs.rs/hashbrown).
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::{extend_map, hash_map};
+///
+/// let mut goodbye = hash_map! {
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+/// };
+///
+/// extend_map!(goodbye, "fr" => "Au revoir", "es" => "Adios", "cat" => "Adéu");
+///
+/// assert_eq!(goodbye.len(), 5);
+/// assert_eq!(goodbye["fr"], "Au revoir");
+/// ```
+///
+/// The first argument must be the collection itself.
+/// If you only hold a mutable reference to it, dereference it:
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use map_macro::extend_map;
+///
+/// fn add_greetings(greetings: &mut BTreeMap<&str, &str>) {
+///     extend_map!(*greetings, "en" => "Hello", "de" => "Hallo");
+/// }
+///
+/// let mut hello = BTreeMap::new();
+/// add_greetings(&mut hello);
+///
+/// assert_eq!(hello["de"], "Hallo");
+/// ```
+///
+#[macro_export]
+macro_rules! extend_map {
+    ($m: expr, $($k: expr => $v: expr),+ $(,)?) => {
+        {
+            #[allow(unused_imports)]
+            use $crate::__private::Reserve as _;
+
+            let map = &mut $m;
+            map.reserve($crate::__count!($($k)*));
+            ::core::iter::Extend::extend(map, [$(($k, $v),)*])
+        }
+    };
+}
+
+/// Explicitly typed equivalent of [`extend_map!`].
+///
+/// See the [Explicitly Typed Macros](crate#explicitly-typed-macros) section.
+///
+/// The types of the casts are taken from the entries the map yields when
+/// iterated by reference, so the map's reference must implement
+/// [`IntoIterator<Item = (&K, &V)>`](::core::iter::IntoIterator), which is the
+/// case for the maps from the standard library and
+/// [`hashbrown`](https://docs.rs/hashbrown).
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use std::fmt::Debug;
+///
+/// use map_macro::{extend_map_e, hash_map_e};
+///
+/// let mut goodbye: HashMap<&str, &dyn Debug> = hash_map_e! {
+///     "en" => &"Goodbye",
+///     "de" => &"Auf Wiedersehen",
+/// };
+///
+/// extend_map_e!(goodbye, "fr" => &"Au revoir", "es" => &"Adios");
+///
+/// assert_eq!(goodbye.len(), 4);
+/// ```
+///
+#[macro_export]
+macro_rules! extend_map_e {
+    ($m: expr, $($k: expr => $v: expr),+ $(,)?) => {
+        {
+            #[allow(unused_imports)]
+            use $crate::__private::Reserve as _;
+
+            let map = &mut $m;
+            map.reserve($crate::__count!($($k)*));
+            $crate::__private::extend_map(map, [$(($k as _, $v as _),)*])
+        }
+    };
+}
+
+/// Macro for adding elements to an existing set or sequence.
+///
+/// Syntactic sugar for [`Extend::extend`](::core::iter::Extend::extend)
+/// with an array of the provided elements, after reserving capacity for the
+/// whole batch once.
+/// Collections without a `reserve` method, like
+/// [`BTreeSet`](::std::collections::BTreeSet), are extended without
+/// reserving capacity.
+/// Works with any collection that implements
+/// [`Extend<T>`](::core::iter::Extend), like the sets and sequences from the
+/// standard library or [`hashbrown`](https://docs.rs/hashbrown).
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::VecDeque;
+///
+/// use map_macro::{btree_set, extend_set, vec_deque};
+///
+/// let mut languages = btree_set! { "en", "de" };
+///
+/// extend_set!(languages, "fr", "es", "cat");
+///
+/// assert_eq!(languages.len(), 5);
+///
+/// let mut queue = vec_deque![1, 2];
+///
+/// extend_set!(queue, 3, 4);
+///
+/// assert_eq!(queue, VecDeque::from([1, 2, 3, 4]));
+/// ```
+///
+#[macro_export]
+macro_rules! extend_set {
+    ($s: expr, $($v: expr),+ $(,)?) => {
+        {
+            #[allow(unused_imports)]
+            use $crate::__private::Reserve as _;
+
+            let set = &mut $s;
+            set.reserve($crate::__count!($($v)*));
+            ::core::iter::Extend::extend(set, [$($v,)*])
+        }
+    };
+}
+
+/// Explicitly typed equivalent of [`extend_set!`].
+///
+/// See the [Explicitly Typed Macros](crate#explicitly-typed-macros) section.
+///
+/// The types of the casts are taken from the elements the collection yields
+/// when iterated by reference, so the collection's reference must implement
+/// [`IntoIterator<Item = &T>`](::core::iter::IntoIterator), which is the case
+/// for the sets and sequences from the standard library and
+/// [`hashbrown`](https://docs.rs/hashbrown).
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::VecDeque;
+/// use std::fmt::Debug;
+///
+/// use map_macro::{extend_set_e, vec_deque_e};
+///
+/// let mut queue: VecDeque<&dyn Debug> = vec_deque_e![&"Hello", &1];
+///
+/// extend_set_e!(queue, &2.0, &'c');
+///
+/// assert_eq!(queue.len(), 4);
+/// ```
+///
+#[macro_export]
+macro_rules! extend_set_e {
+    ($s: expr, $($v: expr),+ $(,)?) => {
+        {
+            #[allow(unused_imports)]
+            use $crate::__private::Reserve as _;
+
+            let set = &mut $s;
+            set.reserve($crate::__count!($($v)*));
+            $crate::__private::extend_set(set, [$($v as _,)*])
+        }
+    };
+}
+
+/// Macro for creating an array of `N` elements by evaluating an
+/// expression `N` times.
+///
+/// The array repeat expression `[expr; N]` only works if `expr` is
+/// [`Copy`] or a constant.
+/// `array_no_clone!` lifts this restriction, much like
+/// [`vec_no_clone!`](crate::vec_no_clone) does for vectors, but without
+/// allocating:
+///
+/// ```rust
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// use map_macro::array_no_clone;
+///
+/// let counters: [AtomicUsize; 4] = array_no_clone![AtomicUsize::new(0); 4];
+///
+/// counters[1].fetch_add(1, Ordering::Relaxed);
+///
+/// assert_eq!(counters[0].load(Ordering::Relaxed), 0);
+/// assert_eq!(counters[1].load(Ordering::Relaxed), 1);
+/// ```
+///
+/// `N` must be a constant expression of type `usize`.
+/// Like [`vec_no_clone!`](crate::vec_no_clone), the element can be written
+/// as `|i| expr; N` to make the index of the element available to `expr`:
+///
+/// ```rust
+/// use map_macro::array_no_clone;
+///
+/// let squares = array_no_clone![|i| i * i; 4];
+///
+/// assert_eq!(squares, [0, 1, 4, 9]);
+/// ```
+///
+/// The array is built with [`core::array::from_fn`], so no
+/// partially initialized array is left behind if the evaluation of `expr`
+/// panics.
+/// The elements that were already created are dropped instead.
+///
+/// Since `expr` is evaluated inside a closure, control flow like `return`
+/// or `?` applies to the closure and not to the surrounding function.
+///
+#[macro_export]
+macro_rules! array_no_clone {
+    [|$i: pat| $v: expr; $n: expr] => {
+        ::core::array::from_fn::<_, { $n }, _>(|$i| $v)
+    };
+    [$v: expr; $n: expr] => {
+        ::core::array::from_fn::<_, { $n }, _>(|_| $v)
+    };
+}
+
+/// Explicitly typed equivalent of [`array_no_clone!`].
+///
+/// See the [Explicitly Typed Macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Debug;
+/// use std::sync::atomic::AtomicUsize;
+///
+/// use map_macro::array_no_clone_e;
+///
+/// let counters: [Box<dyn Debug>; 2] = array_no_clone_e![Box::new(AtomicUsize::new(0)); 2];
+///
+/// assert_eq!(format!("{:?}", counters[1]), "0");
+/// ```
+///
+#[macro_export]
+macro_rules! array_no_clone_e {
+    [|$i: pat| $v: expr; $n: expr] => {
+        ::core::array::from_fn::<_, { $n }, _>(|$i| $v as _)
+    };
+    [$v: expr; $n: expr] => {
+        ::core::array::from_fn::<_, { $n }, _>(|_| $v as _)
+    };
+}
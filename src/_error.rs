@@ -0,0 +1,86 @@
+use core::fmt;
+
+/// Error returned by the `try_*` macros, like
+/// [`try_hash_map!`](crate::try_hash_map), if the same key occurs more than
+/// once.
+///
+/// Holds the indices of the first and second entry with the key, as well as
+/// the key of the second entry.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::try_hash_map;
+///
+/// let en = "en";
+///
+/// let err = try_hash_map! {
+///     en => "Hello",
+///     "de" => "Hallo",
+///     "en" => "Hi",
+/// }
+/// .unwrap_err();
+///
+/// assert_eq!(err.first_index(), 0);
+/// assert_eq!(err.second_index(), 2);
+/// assert_eq!(err.key(), &"en");
+/// assert_eq!(
+///     err.to_string(),
+///     "duplicate key at index 2, first defined at index 0",
+/// );
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateKeyError<K> {
+    first: usize,
+    second: usize,
+    key: K,
+}
+
+impl<K> DuplicateKeyError<K> {
+    #[doc(hidden)]
+    pub fn __new(first: usize, second: usize, key: K) -> Self {
+        Self { first, second, key }
+    }
+
+    /// Returns the index of the first entry with the duplicate key.
+    ///
+    #[must_use]
+    pub fn first_index(&self) -> usize {
+        self.first
+    }
+
+    /// Returns the index of the second entry with the duplicate key.
+    ///
+    #[must_use]
+    pub fn second_index(&self) -> usize {
+        self.second
+    }
+
+    /// Returns the duplicate key.
+    ///
+    #[must_use]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Consumes the error, returning the duplicate key.
+    ///
+    #[must_use]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+}
+
+impl<K> fmt::Display for DuplicateKeyError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "duplicate key at index {}, first defined at index {}",
+            self.second, self.first,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: fmt::Debug> std::error::Error for DuplicateKeyError<K> {}
@@ -0,0 +1,1008 @@
+/// Expands to the number of token trees passed to it as a constant
+/// expression of type `usize`.
+///
+/// Used to determine the capacity of a collection from the entries passed to
+/// a macro, which are forwarded as one token tree per entry.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __count {
+    (@unit $t: tt) => {
+        ()
+    };
+    ($($t: tt)*) => {
+        <[()]>::len(&[$($crate::__count!(@unit $t)),*])
+    };
+}
+
+/// Expands to statements inserting the entries of a map or set macro into
+/// the collection `$c` with its `insert` method, or the elements of a
+/// sequence macro (`@seq`) with its [`Extend`] implementation.
+///
+/// Plain entries `k => v` of maps and `v` of sets and sequences, whose keys
+/// and elements are token trees, are matched by a single repetition if they
+/// make up the rest of the input, and eight at a time in front of other
+/// entries, which keeps the recursion depth low for long lists.
+/// The same goes for the rest of the input if the keys and elements are
+/// literals with attributes.
+/// Other entries are matched one at a time, and their values and conditions
+/// are matched as expressions where possible, that is if they end at a `,`.
+/// A value in front of a `for` or `if` clause can't be matched as an
+/// expression and is collected token by token, unless it's a single token
+/// tree.
+/// Comprehension clauses like `k => v for x in iter if cond` expand to nested
+/// `for` loops, guards like `k => v if cond` to an `if` expression and
+/// optional values `k =>? opt` to an `if let`.
+/// Shorthand entries `ident` of maps are keyed by the name of the
+/// identifier.
+/// Entries with attributes are wrapped in a block that the attributes are
+/// applied to.
+/// Spread entries `..src` extend the collection with the items of `src`,
+/// after reserving capacity for the lower bound of their size hint, which
+/// is exact for an [`ExactSizeIterator`].
+/// The tokens in brackets are appended to every key and value, which the
+/// explicitly typed macros use to cast them with `as _`.
+/// Range entries with a literal start like `1..=n` of sets and sequences
+/// are spread as well.
+/// Items of spread entries are not cast.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __entries {
+    // plain entries whose keys and elements are token trees, which make up
+    // the rest of the input
+    (@map $c: ident $cast: tt; $($k: tt => $v: expr),+ $(,)?) => {
+        $($crate::__entries!(@insert map $c $cast $k $v);)+
+    };
+    (@set $c: ident $cast: tt; $($v: tt),+ $(,)?) => {
+        $($crate::__entries!(@insert set $c $cast $v);)+
+    };
+    (@seq $c: ident $cast: tt; $($v: tt),+ $(,)?) => {
+        $($crate::__entries!(@insert seq $c $cast $v);)+
+    };
+    (@insert map $c: ident [$($cast: tt)*] $k: tt $v: tt) => {
+        $c.insert($k $($cast)*, $v $($cast)*);
+    };
+    (@insert set $c: ident [$($cast: tt)*] $v: tt) => {
+        $c.insert($v $($cast)*);
+    };
+    (@insert seq $c: ident [$($cast: tt)*] $v: tt) => {
+        $crate::__private::extend_set(&mut $c, ::core::iter::once($v $($cast)*));
+    };
+    // entry with outer attributes like `#[cfg(...)]`, which ends at the next
+    // `,` or the end of the input
+    (@$mode: ident $c: ident $cast: tt; #[$m: meta] $($rest: tt)*) => {
+        $crate::__entries!(@attr $mode $c $cast [#[$m]] []; $($rest)*);
+    };
+    (@attr $mode: ident $c: ident $cast: tt [$($a: tt)*] []; #[$m: meta] $($rest: tt)*) => {
+        $crate::__entries!(@attr $mode $c $cast [$($a)* #[$m]] []; $($rest)*);
+    };
+    (@attr $mode: ident $c: ident $cast: tt [$($a: tt)*] [$($e: tt)*]; $(, $($rest: tt)*)?) => {
+        $($a)* {
+            $crate::__entries!(@$mode $c $cast; $($e)*);
+        }
+        $crate::__entries!(@$mode $c $cast; $($($rest)*)?);
+    };
+    (@attr $mode: ident $c: ident $cast: tt [$($a: tt)*] []; $e: tt $(, $($rest: tt)*)?) => {
+        $($a)* {
+            $crate::__entries!(@$mode $c $cast; $e);
+        }
+        $crate::__entries!(@$mode $c $cast; $($($rest)*)?);
+    };
+    (@attr map $c: ident [$($cast: tt)*] [$($a: tt)*] []; $k: expr => $v: expr $(, $($rest: tt)*)?) => {
+        $($a)* {
+            $c.insert($k $($cast)*, $v $($cast)*);
+        }
+        $crate::__entries!(@map $c [$($cast)*]; $($($rest)*)?);
+    };
+    (@attr $mode: ident $c: ident $cast: tt $a: tt [$($e: tt)*]; $t: tt $($rest: tt)*) => {
+        $crate::__entries!(@attr $mode $c $cast $a [$($e)* $t]; $($rest)*);
+    };
+    // range entry with a literal start like `1..=n` of a set or sequence,
+    // which is spread
+    // the start is matched as a token tree and checked for a literal on its
+    // own, since a `literal` fragment fails with an error on `-x`
+    (@set $c: ident $cast: tt; $s: tt .. $($rest: tt)*) => {
+        $crate::__entries!(@range_start set $c $cast [] [$s] [..]; $($rest)*);
+    };
+    (@set $c: ident $cast: tt; - $s: tt .. $($rest: tt)*) => {
+        $crate::__entries!(@range_start set $c $cast [-] [$s] [..]; $($rest)*);
+    };
+    (@set $c: ident $cast: tt; $s: tt ..= $($rest: tt)*) => {
+        $crate::__entries!(@range_start set $c $cast [] [$s] [..=]; $($rest)*);
+    };
+    (@set $c: ident $cast: tt; - $s: tt ..= $($rest: tt)*) => {
+        $crate::__entries!(@range_start set $c $cast [-] [$s] [..=]; $($rest)*);
+    };
+    (@seq $c: ident $cast: tt; $s: tt .. $($rest: tt)*) => {
+        $crate::__entries!(@range_start seq $c $cast [] [$s] [..]; $($rest)*);
+    };
+    (@seq $c: ident $cast: tt; - $s: tt .. $($rest: tt)*) => {
+        $crate::__entries!(@range_start seq $c $cast [-] [$s] [..]; $($rest)*);
+    };
+    (@seq $c: ident $cast: tt; $s: tt ..= $($rest: tt)*) => {
+        $crate::__entries!(@range_start seq $c $cast [] [$s] [..=]; $($rest)*);
+    };
+    (@seq $c: ident $cast: tt; - $s: tt ..= $($rest: tt)*) => {
+        $crate::__entries!(@range_start seq $c $cast [-] [$s] [..=]; $($rest)*);
+    };
+    (@range_start $mode: ident $c: ident $cast: tt [$($n: tt)?] [$s: literal] [$r: tt]; $($rest: tt)*) => {
+        $crate::__entries!(@range $mode $c $cast [$($n)? $s $r] []; $($rest)*);
+    };
+    (@range_start $mode: ident $c: ident $cast: tt [$($n: tt)?] [$s: tt] [$r: tt]; $($rest: tt)*) => {
+        $crate::__entries!(@plain $mode $c $cast; $($n)? $s $r $($rest)*);
+    };
+    (@range $mode: ident $c: ident $cast: tt [$($r: tt)*] []; $(, $($rest: tt)*)?) => {
+        $crate::__entries!(@$mode $c $cast; ($($r)*) $(, $($rest)*)?);
+    };
+    (@range $mode: ident $c: ident $cast: tt [$($r: tt)*] [$($e: tt)+]; $(, $($rest: tt)*)?) => {
+        $crate::__entries!(@$mode $c $cast; ..($($r)* $($e)+) $(, $($rest)*)?);
+    };
+    (@range $mode: ident $c: ident $cast: tt [$($r: tt)*] [$($e: tt)+]; for $($rest: tt)*) => {
+        $crate::__entries!(
+            @for $mode $c $cast {$crate::__entries!(@$mode $c $cast; ..($($r)* $($e)+));} [];
+            for $($rest)*
+        );
+    };
+    (@range $mode: ident $c: ident $cast: tt [$($r: tt)*] [$($e: tt)+]; if $($rest: tt)*) => {
+        $crate::__entries!(
+            @guard $mode $c $cast {$crate::__entries!(@$mode $c $cast; ..($($r)* $($e)+));};
+            $($rest)*
+        );
+    };
+    (@range $mode: ident $c: ident $cast: tt $r: tt [$($e: tt)*]; $t: tt $($rest: tt)*) => {
+        $crate::__entries!(@range $mode $c $cast $r [$($e)* $t]; $($rest)*);
+    };
+    // range entry whose start isn't a literal, which is a single element
+    (@plain $mode: ident $c: ident $cast: tt; $v: expr $(, $($rest: tt)*)?) => {
+        $crate::__entries!(@insert $mode $c $cast $v);
+        $crate::__entries!(@$mode $c $cast; $($($rest)*)?);
+    };
+    (@plain set $c: ident $cast: tt; $($rest: tt)*) => {
+        $crate::__entries!(@set_value $c $cast []; $($rest)*);
+    };
+    (@seq $c: ident $cast: tt; .. $src: expr $(, $($rest: tt)*)?) => {
+        {
+            #[allow(unused_imports)]
+            use $crate::__private::Reserve as _;
+
+            let iter = ::core::iter::IntoIterator::into_iter($src);
+            $c.reserve_exact(::core::iter::Iterator::size_hint(&iter).0);
+            ::core::iter::Extend::extend(&mut $c, iter);
+        }
+        $crate::__entries!(@seq $c $cast; $($($rest)*)?);
+    };
+    // spread entry of a map or set
+    (@$mode: ident $c: ident $cast: tt; .. $src: expr $(, $($rest: tt)*)?) => {
+        {
+            #[allow(unused_imports)]
+            use $crate::__private::Reserve as _;
+
+            let iter = ::core::iter::IntoIterator::into_iter($src);
+            $c.reserve(::core::iter::Iterator::size_hint(&iter).0);
+            ::core::iter::Extend::extend(&mut $c, iter);
+        }
+        $crate::__entries!(@$mode $c $cast; $($($rest)*)?);
+    };
+    // plain entries of maps in front of other entries, eight at a time, and
+    // the ones in front of an entry with attributes, which `$k: expr` would
+    // match as well
+    (@map $c: ident $cast: tt; $ak: expr => $av: expr, # $($rest: tt)*) => {
+        $crate::__entries!(@map $c $cast; $ak => $av);
+        $crate::__entries!(@map $c $cast; # $($rest)*);
+    };
+    (@map $c: ident $cast: tt; $ak: expr => $av: expr, $bk: expr => $bv: expr, # $($rest: tt)*) => {
+        $crate::__entries!(@map $c $cast; $ak => $av, $bk => $bv);
+        $crate::__entries!(@map $c $cast; # $($rest)*);
+    };
+    (@map $c: ident $cast: tt; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, # $($rest: tt)*) => {
+        $crate::__entries!(@map $c $cast; $ak => $av, $bk => $bv, $ck => $cv);
+        $crate::__entries!(@map $c $cast; # $($rest)*);
+    };
+    (@map $c: ident $cast: tt; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, # $($rest: tt)*) => {
+        $crate::__entries!(@map $c $cast; $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv);
+        $crate::__entries!(@map $c $cast; # $($rest)*);
+    };
+    (@map $c: ident $cast: tt; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, # $($rest: tt)*) => {
+        $crate::__entries!(@map $c $cast; $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv, $ek => $ev);
+        $crate::__entries!(@map $c $cast; # $($rest)*);
+    };
+    (@map $c: ident $cast: tt; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, # $($rest: tt)*) => {
+        $crate::__entries!(@map $c $cast; $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv, $ek => $ev, $fk => $fv);
+        $crate::__entries!(@map $c $cast; # $($rest)*);
+    };
+    (@map $c: ident $cast: tt; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, $gk: expr => $gv: expr, # $($rest: tt)*) => {
+        $crate::__entries!(@map $c $cast; $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv, $ek => $ev, $fk => $fv, $gk => $gv);
+        $crate::__entries!(@map $c $cast; # $($rest)*);
+    };
+    (@map $c: ident $cast: tt; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, $gk: expr => $gv: expr, $hk: expr => $hv: expr, $($rest: tt)+) => {
+        $crate::__entries!(@map $c $cast; $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv, $ek => $ev, $fk => $fv, $gk => $gv, $hk => $hv);
+        $crate::__entries!(@map $c $cast; $($rest)+);
+    };
+    (@map $c: ident [$($cast: tt)*];) => {};
+    // shorthand entry `ident`, which is keyed by the name of the identifier
+    (@map $c: ident [$($cast: tt)*]; $k: ident $(, $($rest: tt)*)?) => {
+        $c.insert(::core::stringify!($k) $($cast)*, $k $($cast)*);
+        $crate::__entries!(@map $c [$($cast)*]; $($($rest)*)?);
+    };
+    (@map $c: ident [$($cast: tt)*]; $k: ident if $($rest: tt)*) => {
+        $crate::__entries!(
+            @guard map $c [$($cast)*] {$c.insert(::core::stringify!($k) $($cast)*, $k $($cast)*);};
+            $($rest)*
+        );
+    };
+    (@map $c: ident [$($cast: tt)*]; $k: expr =>? $v: expr $(, $($rest: tt)*)?) => {
+        if let ::core::option::Option::Some(value) = $v {
+            $c.insert($k $($cast)*, value $($cast)*);
+        }
+        $crate::__entries!(@map $c [$($cast)*]; $($($rest)*)?);
+    };
+    (@map $c: ident [$($cast: tt)*]; $k: expr =>? $v: tt $($rest: tt)*) => {
+        $crate::__entries!(@map_opt $c [$($cast)*] $k [$v]; $($rest)*);
+    };
+    (@map $c: ident [$($cast: tt)*]; $k: expr =>? $($rest: tt)*) => {
+        $crate::__entries!(@map_opt $c [$($cast)*] $k []; $($rest)*);
+    };
+    (@map $c: ident [$($cast: tt)*]; $k: expr => $v: expr $(, $($rest: tt)*)?) => {
+        $c.insert($k $($cast)*, $v $($cast)*);
+        $crate::__entries!(@map $c [$($cast)*]; $($($rest)*)?);
+    };
+    (@map $c: ident [$($cast: tt)*]; $k: expr => $v: tt for $($rest: tt)*) => {
+        $crate::__entries!(@map_value $c [$($cast)*] $k [$v]; for $($rest)*);
+    };
+    (@map $c: ident [$($cast: tt)*]; $k: expr => $v: tt if $($rest: tt)*) => {
+        $crate::__entries!(@map_value $c [$($cast)*] $k [$v]; if $($rest)*);
+    };
+    (@map $c: ident [$($cast: tt)*]; $k: expr => $($rest: tt)*) => {
+        $crate::__entries!(@map_value $c [$($cast)*] $k []; $($rest)*);
+    };
+    (@map_value $c: ident [$($cast: tt)*] $k: tt [$($v: tt)*]; for $($rest: tt)*) => {
+        $crate::__entries!(
+            @for map $c [$($cast)*] {$c.insert($k $($cast)*, ($($v)*) $($cast)*);} [];
+            for $($rest)*
+        );
+    };
+    (@map_value $c: ident [$($cast: tt)*] $k: tt [$($v: tt)+]; if $($rest: tt)*) => {
+        $crate::__entries!(
+            @guard map $c [$($cast)*] {$c.insert($k $($cast)*, ($($v)*) $($cast)*);};
+            $($rest)*
+        );
+    };
+    (@map_value $c: ident [$($cast: tt)*] $k: tt [$($v: tt)*]; $t: tt $($rest: tt)*) => {
+        $crate::__entries!(@map_value $c [$($cast)*] $k [$($v)* $t]; $($rest)*);
+    };
+    (@map_opt $c: ident [$($cast: tt)*] $k: tt [$($v: tt)+]; for $($rest: tt)*) => {
+        $crate::__entries!(
+            @for map $c [$($cast)*] {
+                if let ::core::option::Option::Some(value) = ($($v)*) {
+                    $c.insert($k $($cast)*, value $($cast)*);
+                }
+            } [];
+            for $($rest)*
+        );
+    };
+    (@map_opt $c: ident [$($cast: tt)*] $k: tt [$($v: tt)+]; if $($rest: tt)*) => {
+        $crate::__entries!(
+            @guard map $c [$($cast)*] {
+                if let ::core::option::Option::Some(value) = ($($v)*) {
+                    $c.insert($k $($cast)*, value $($cast)*);
+                }
+            };
+            $($rest)*
+        );
+    };
+    (@map_opt $c: ident [$($cast: tt)*] $k: tt [$($v: tt)*]; $t: tt $($rest: tt)*) => {
+        $crate::__entries!(@map_opt $c [$($cast)*] $k [$($v)* $t]; $($rest)*);
+    };
+    // plain elements of sets and sequences in front of other entries, four
+    // at a time, and the ones in front of an entry that `$v: expr` would
+    // match as well, that is an entry with attributes, a spread entry or a
+    // range entry that may have a literal start
+    (@$mode: ident $c: ident $cast: tt; $av: expr, # $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av);
+        $crate::__entries!(@$mode $c $cast; # $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, .. $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av);
+        $crate::__entries!(@$mode $c $cast; .. $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, $s: tt .. $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av);
+        $crate::__entries!(@$mode $c $cast; $s .. $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, $s: tt ..= $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av);
+        $crate::__entries!(@$mode $c $cast; $s ..= $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, - $s: tt .. $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av);
+        $crate::__entries!(@$mode $c $cast; - $s .. $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, - $s: tt ..= $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av);
+        $crate::__entries!(@$mode $c $cast; - $s ..= $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, $bv: expr, # $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av, $bv);
+        $crate::__entries!(@$mode $c $cast; # $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, $bv: expr, .. $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av, $bv);
+        $crate::__entries!(@$mode $c $cast; .. $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, $bv: expr, $s: tt .. $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av, $bv);
+        $crate::__entries!(@$mode $c $cast; $s .. $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, $bv: expr, $s: tt ..= $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av, $bv);
+        $crate::__entries!(@$mode $c $cast; $s ..= $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, $bv: expr, - $s: tt .. $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av, $bv);
+        $crate::__entries!(@$mode $c $cast; - $s .. $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, $bv: expr, - $s: tt ..= $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av, $bv);
+        $crate::__entries!(@$mode $c $cast; - $s ..= $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, $bv: expr, $cv: expr, # $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av, $bv, $cv);
+        $crate::__entries!(@$mode $c $cast; # $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, $bv: expr, $cv: expr, .. $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av, $bv, $cv);
+        $crate::__entries!(@$mode $c $cast; .. $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, $bv: expr, $cv: expr, $s: tt .. $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av, $bv, $cv);
+        $crate::__entries!(@$mode $c $cast; $s .. $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, $bv: expr, $cv: expr, $s: tt ..= $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av, $bv, $cv);
+        $crate::__entries!(@$mode $c $cast; $s ..= $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, $bv: expr, $cv: expr, - $s: tt .. $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av, $bv, $cv);
+        $crate::__entries!(@$mode $c $cast; - $s .. $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, $bv: expr, $cv: expr, - $s: tt ..= $($rest: tt)*) => {
+        $crate::__entries!(@$mode $c $cast; $av, $bv, $cv);
+        $crate::__entries!(@$mode $c $cast; - $s ..= $($rest)*);
+    };
+    (@$mode: ident $c: ident $cast: tt; $av: expr, $bv: expr, $cv: expr, $dv: expr, $($rest: tt)+) => {
+        $crate::__entries!(@$mode $c $cast; $av, $bv, $cv, $dv);
+        $crate::__entries!(@$mode $c $cast; $($rest)+);
+    };
+    (@seq $c: ident [$($cast: tt)*];) => {};
+    (@seq $c: ident [$($cast: tt)*]; $v: expr $(, $($rest: tt)*)?) => {
+        $crate::__private::extend_set(&mut $c, ::core::iter::once($v $($cast)*));
+        $crate::__entries!(@seq $c [$($cast)*]; $($($rest)*)?);
+    };
+    (@set $c: ident [$($cast: tt)*];) => {};
+    (@set $c: ident [$($cast: tt)*]; $v: expr $(, $($rest: tt)*)?) => {
+        $c.insert($v $($cast)*);
+        $crate::__entries!(@set $c [$($cast)*]; $($($rest)*)?);
+    };
+    (@set $c: ident [$($cast: tt)*]; $v: tt for $($rest: tt)*) => {
+        $crate::__entries!(@set_value $c [$($cast)*] [$v]; for $($rest)*);
+    };
+    (@set $c: ident [$($cast: tt)*]; $v: tt if $($rest: tt)*) => {
+        $crate::__entries!(@set_value $c [$($cast)*] [$v]; if $($rest)*);
+    };
+    (@set $c: ident [$($cast: tt)*]; $($rest: tt)*) => {
+        $crate::__entries!(@set_value $c [$($cast)*] []; $($rest)*);
+    };
+    (@set_value $c: ident [$($cast: tt)*] [$($v: tt)*]; for $($rest: tt)*) => {
+        $crate::__entries!(
+            @for set $c [$($cast)*] {$c.insert(($($v)*) $($cast)*);} [];
+            for $($rest)*
+        );
+    };
+    (@set_value $c: ident [$($cast: tt)*] [$($v: tt)+]; if $($rest: tt)*) => {
+        $crate::__entries!(@guard set $c [$($cast)*] {$c.insert(($($v)*) $($cast)*);}; $($rest)*);
+    };
+    (@set_value $c: ident [$($cast: tt)*] [$($v: tt)*]; $t: tt $($rest: tt)*) => {
+        $crate::__entries!(@set_value $c [$($cast)*] [$($v)* $t]; $($rest)*);
+    };
+    // `if cond` guard of a single entry
+    (@guard $mode: ident $c: ident $cast: tt {$($body: tt)*}; $e: expr $(, $($rest: tt)*)?) => {
+        if $e {
+            $($body)*
+        }
+        $crate::__entries!(@$mode $c $cast; $($($rest)*)?);
+    };
+    // `for pat in iter` clause, the iterator ends at `if`, `for`, `,` or the
+    // end of the input
+    (@for $mode: ident $c: ident $cast: tt $body: tt [$($cl: tt)*]; for $p: pat in $($rest: tt)*) => {
+        $crate::__entries!(@iter $mode $c $cast $body [$($cl)*] ($p) []; $($rest)*);
+    };
+    (@iter $mode: ident $c: ident $cast: tt $body: tt [$($cl: tt)*] $p: tt []; $it: expr $(, $($rest: tt)*)?) => {
+        $crate::__entries!(@nest $body $($cl)* [$p [$it] []]);
+        $crate::__entries!(@$mode $c $cast; $($($rest)*)?);
+    };
+    (@iter $mode: ident $c: ident $cast: tt $body: tt [$($cl: tt)*] $p: tt [$($it: tt)*]; if $($rest: tt)*) => {
+        $crate::__entries!(@cond $mode $c $cast $body [$($cl)*] $p [$($it)*] []; $($rest)*);
+    };
+    (@iter $mode: ident $c: ident $cast: tt $body: tt [$($cl: tt)*] $p: tt [$($it: tt)*]; for $($rest: tt)*) => {
+        $crate::__entries!(@for $mode $c $cast $body [$($cl)* [$p [$($it)*] []]]; for $($rest)*);
+    };
+    (@iter $mode: ident $c: ident $cast: tt $body: tt [$($cl: tt)*] $p: tt [$($it: tt)*]; $(, $($rest: tt)*)?) => {
+        $crate::__entries!(@nest $body $($cl)* [$p [$($it)*] []]);
+        $crate::__entries!(@$mode $c $cast; $($($rest)*)?);
+    };
+    (@iter $mode: ident $c: ident $cast: tt $body: tt [$($cl: tt)*] $p: tt [$($it: tt)*]; $t: tt $($rest: tt)*) => {
+        $crate::__entries!(@iter $mode $c $cast $body [$($cl)*] $p [$($it)* $t]; $($rest)*);
+    };
+    // `if cond` clause, the condition ends at `for`, `,` or the end of the
+    // input
+    (@cond $mode: ident $c: ident $cast: tt $body: tt [$($cl: tt)*] $p: tt $it: tt []; $e: expr $(, $($rest: tt)*)?) => {
+        $crate::__entries!(@nest $body $($cl)* [$p $it [$e]]);
+        $crate::__entries!(@$mode $c $cast; $($($rest)*)?);
+    };
+    (@cond $mode: ident $c: ident $cast: tt $body: tt [$($cl: tt)*] $p: tt $it: tt [$($e: tt)*]; for $($rest: tt)*) => {
+        $crate::__entries!(@for $mode $c $cast $body [$($cl)* [$p $it [$($e)*]]]; for $($rest)*);
+    };
+    (@cond $mode: ident $c: ident $cast: tt $body: tt [$($cl: tt)*] $p: tt $it: tt [$($e: tt)*]; $(, $($rest: tt)*)?) => {
+        $crate::__entries!(@nest $body $($cl)* [$p $it [$($e)*]]);
+        $crate::__entries!(@$mode $c $cast; $($($rest)*)?);
+    };
+    (@cond $mode: ident $c: ident $cast: tt $body: tt [$($cl: tt)*] $p: tt $it: tt [$($e: tt)*]; $t: tt $($rest: tt)*) => {
+        $crate::__entries!(@cond $mode $c $cast $body [$($cl)*] $p $it [$($e)* $t]; $($rest)*);
+    };
+    // nests the collected clauses, innermost last
+    (@nest {$($body: tt)*}) => {
+        $($body)*
+    };
+    (@nest $body: tt [($p: pat) [$($it: tt)*] []] $($cl: tt)*) => {
+        for $p in $($it)* {
+            $crate::__entries!(@nest $body $($cl)*);
+        }
+    };
+    (@nest $body: tt [($p: pat) [$($it: tt)*] [$($e: tt)+]] $($cl: tt)*) => {
+        for $p in $($it)* {
+            if $($e)+ {
+                $crate::__entries!(@nest $body $($cl)*);
+            }
+        }
+    };
+}
+
+/// Creates a collection of type `$ty` with its
+/// [`FromIterator`](core::iter::FromIterator) implementation from the
+/// entries of a map (`map`), set (`set`) or sequence (`seq`) macro.
+///
+/// Plain lists (`@list`) are collected from an array.
+/// Other entries (`@entries`) are inserted with
+/// [`__entries!`](crate::__entries) into a vector first, wrapped in
+/// [`MapEntries`](crate::__private::MapEntries) or
+/// [`SetEntries`](crate::__private::SetEntries) for maps and sets.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __from_iter {
+    (@list map [] $ty: ty; $($k: expr => $v: expr),* $(,)?) => {
+        $crate::__private::collect_map::<$ty, _, _, _>([$(($k, $v),)*])
+    };
+    (@list map [as _] $ty: ty; $($k: expr => $v: expr),* $(,)?) => {
+        $crate::__private::collect_map::<$ty, _, _, _>([$(($k as _, $v as _),)*])
+    };
+    (@list $mode: ident [] $ty: ty; $($v: expr),* $(,)?) => {
+        $crate::__private::collect_set::<$ty, _, _>([$($v,)*])
+    };
+    (@list $mode: ident [as _] $ty: ty; $($v: expr),* $(,)?) => {
+        $crate::__private::collect_set::<$ty, _, _>([$($v as _,)*])
+    };
+    (@entries map $cast: tt $ty: ty; $($t: tt)*) => {
+        {
+            let mut entries =
+                <$crate::__private::MapEntries<_, _> as ::core::default::Default>::default();
+            $crate::__entries!(@map entries $cast; $($t)*);
+            $crate::__private::collect_map::<$ty, _, _, _>(entries)
+        }
+    };
+    (@entries set $cast: tt $ty: ty; $($t: tt)*) => {
+        {
+            let mut entries =
+                <$crate::__private::SetEntries<_> as ::core::default::Default>::default();
+            $crate::__entries!(@set entries $cast; $($t)*);
+            $crate::__private::collect_set::<$ty, _, _>(entries)
+        }
+    };
+    (@entries seq $cast: tt $ty: ty; $($t: tt)*) => {
+        {
+            let mut entries = $crate::__private::alloc::vec::Vec::new();
+            $crate::__entries!(@seq entries $cast; $($t)*);
+            $crate::__private::collect_set::<$ty, _, _>(entries)
+        }
+    };
+}
+
+/// Invokes `$m!{@list ...}` if the entries of a map or set macro are a plain
+/// list of `key => value` pairs (`map`) or expressions (`set`), or
+/// `$m!{@entries ...}` otherwise.
+///
+/// A plain list is matched by a single arm without recursion, so it may be
+/// arbitrarily long. An `expr` key or element would also match spread
+/// entries like `..src`, which are range expressions, as well as entries
+/// with attributes like `#[cfg(...)]`, so the keys of maps and the elements
+/// of sets are matched as token trees instead, which don't match the other
+/// kinds of entries.
+/// Other fragments like `literal` or `path` aren't used, because a failure
+/// to parse them, like a `literal` on `-x`, is an error instead of a
+/// mismatch.
+/// Lists with other keys or elements are passed on to `@entries`, where
+/// `__entries!` inserts them in batches.
+/// Leading `hasher` and `capacity` clauses are passed on to `$m` in front of
+/// the entries.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __list {
+    ($m: ident $shape: ident [$($cl: tt)*]; hasher: $h: expr; $($t: tt)*) => {
+        $crate::__list!($m $shape [$($cl)* hasher: $h;]; $($t)*)
+    };
+    ($m: ident $shape: ident [$($cl: tt)*]; capacity: $c: expr; $($t: tt)*) => {
+        $crate::__list!($m $shape [$($cl)* capacity: $c;]; $($t)*)
+    };
+    ($m: ident map [$($cl: tt)*]; $($k: tt => $v: expr),* $(,)?) => {
+        $crate::$m!{@list $($cl)* $($k => $v),*}
+    };
+    ($m: ident set [$($cl: tt)*]; $($v: tt),* $(,)?) => {
+        $crate::$m!{@list $($cl)* $($v),*}
+    };
+    ($m: ident $shape: ident [$($cl: tt)*]; $($t: tt)*) => {
+        $crate::$m!{@entries $($cl)* $($t)*}
+    };
+}
+
+/// Creates a map of the given kind from entries whose values can be nested
+/// maps (`{ ... }`) or vectors (`[ ... ]`).
+///
+/// Nested maps and vectors are built recursively, then the entries are
+/// passed to the respective map macro.
+/// Entries and elements are rewritten up to eight at a time, so long lists
+/// don't run into the recursion limit.
+/// The tokens in brackets are appended to every key and to every value that
+/// is neither a map nor a vector.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __nested {
+    (@map $kind: ident $cast: tt [$($acc: tt)*];) => {
+        $crate::__nested!(@finish $kind $($acc)*)
+    };
+    // entries whose values are single token trees, like nested maps and
+    // vectors, are rewritten up to eight at a time
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt, $dk: expr => $dv: tt, $ek: expr => $ev: tt, $fk: expr => $fv: tt, $gk: expr => $gv: tt, $hk: expr => $hv: tt $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $av), $bk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $bv), $ck $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $cv), $dk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $dv), $ek $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $ev), $fk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $fv), $gk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $gv), $hk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $hv),]; $($($rest)*)?)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt, $dk: expr => $dv: tt, $ek: expr => $ev: tt, $fk: expr => $fv: tt, $gk: expr => $gv: tt $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $av), $bk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $bv), $ck $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $cv), $dk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $dv), $ek $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $ev), $fk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $fv), $gk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $gv),]; $($($rest)*)?)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt, $dk: expr => $dv: tt, $ek: expr => $ev: tt, $fk: expr => $fv: tt $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $av), $bk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $bv), $ck $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $cv), $dk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $dv), $ek $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $ev), $fk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $fv),]; $($($rest)*)?)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt, $dk: expr => $dv: tt, $ek: expr => $ev: tt $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $av), $bk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $bv), $ck $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $cv), $dk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $dv), $ek $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $ev),]; $($($rest)*)?)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt, $dk: expr => $dv: tt $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $av), $bk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $bv), $ck $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $cv), $dk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $dv),]; $($($rest)*)?)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $av), $bk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $bv), $ck $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $cv),]; $($($rest)*)?)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $av), $bk $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $bv),]; $($($rest)*)?)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: tt $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $crate::__nested!(@value $kind [$($cast)*] $av),]; $($($rest)*)?)
+    };
+    // the values of other entries are expressions, which are rewritten up to
+    // eight at a time as well, stopping in front of a nested map or vector,
+    // which would be parsed as an expression otherwise
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: expr, $k: expr => {$($m: tt)*} $($rest: tt)*) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $av $($cast)*,]; $k => {$($m)*} $($rest)*)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: expr, $k: expr => [$($m: tt)*] $($rest: tt)*) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $av $($cast)*,]; $k => [$($m)*] $($rest)*)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $k: expr => {$($m: tt)*} $($rest: tt)*) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $av $($cast)*, $bk $($cast)* => $bv $($cast)*,]; $k => {$($m)*} $($rest)*)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $k: expr => [$($m: tt)*] $($rest: tt)*) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $av $($cast)*, $bk $($cast)* => $bv $($cast)*,]; $k => [$($m)*] $($rest)*)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $k: expr => {$($m: tt)*} $($rest: tt)*) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $av $($cast)*, $bk $($cast)* => $bv $($cast)*, $ck $($cast)* => $cv $($cast)*,]; $k => {$($m)*} $($rest)*)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $k: expr => [$($m: tt)*] $($rest: tt)*) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $av $($cast)*, $bk $($cast)* => $bv $($cast)*, $ck $($cast)* => $cv $($cast)*,]; $k => [$($m)*] $($rest)*)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $k: expr => {$($m: tt)*} $($rest: tt)*) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $av $($cast)*, $bk $($cast)* => $bv $($cast)*, $ck $($cast)* => $cv $($cast)*, $dk $($cast)* => $dv $($cast)*,]; $k => {$($m)*} $($rest)*)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $k: expr => [$($m: tt)*] $($rest: tt)*) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $av $($cast)*, $bk $($cast)* => $bv $($cast)*, $ck $($cast)* => $cv $($cast)*, $dk $($cast)* => $dv $($cast)*,]; $k => [$($m)*] $($rest)*)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $k: expr => {$($m: tt)*} $($rest: tt)*) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $av $($cast)*, $bk $($cast)* => $bv $($cast)*, $ck $($cast)* => $cv $($cast)*, $dk $($cast)* => $dv $($cast)*, $ek $($cast)* => $ev $($cast)*,]; $k => {$($m)*} $($rest)*)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $k: expr => [$($m: tt)*] $($rest: tt)*) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $av $($cast)*, $bk $($cast)* => $bv $($cast)*, $ck $($cast)* => $cv $($cast)*, $dk $($cast)* => $dv $($cast)*, $ek $($cast)* => $ev $($cast)*,]; $k => [$($m)*] $($rest)*)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, $k: expr => {$($m: tt)*} $($rest: tt)*) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $av $($cast)*, $bk $($cast)* => $bv $($cast)*, $ck $($cast)* => $cv $($cast)*, $dk $($cast)* => $dv $($cast)*, $ek $($cast)* => $ev $($cast)*, $fk $($cast)* => $fv $($cast)*,]; $k => {$($m)*} $($rest)*)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, $k: expr => [$($m: tt)*] $($rest: tt)*) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $av $($cast)*, $bk $($cast)* => $bv $($cast)*, $ck $($cast)* => $cv $($cast)*, $dk $($cast)* => $dv $($cast)*, $ek $($cast)* => $ev $($cast)*, $fk $($cast)* => $fv $($cast)*,]; $k => [$($m)*] $($rest)*)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, $gk: expr => $gv: expr, $k: expr => {$($m: tt)*} $($rest: tt)*) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $av $($cast)*, $bk $($cast)* => $bv $($cast)*, $ck $($cast)* => $cv $($cast)*, $dk $($cast)* => $dv $($cast)*, $ek $($cast)* => $ev $($cast)*, $fk $($cast)* => $fv $($cast)*, $gk $($cast)* => $gv $($cast)*,]; $k => {$($m)*} $($rest)*)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, $gk: expr => $gv: expr, $k: expr => [$($m: tt)*] $($rest: tt)*) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $av $($cast)*, $bk $($cast)* => $bv $($cast)*, $ck $($cast)* => $cv $($cast)*, $dk $($cast)* => $dv $($cast)*, $ek $($cast)* => $ev $($cast)*, $fk $($cast)* => $fv $($cast)*, $gk $($cast)* => $gv $($cast)*,]; $k => [$($m)*] $($rest)*)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, $gk: expr => $gv: expr, $hk: expr => $hv: expr $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $ak $($cast)* => $av $($cast)*, $bk $($cast)* => $bv $($cast)*, $ck $($cast)* => $cv $($cast)*, $dk $($cast)* => $dv $($cast)*, $ek $($cast)* => $ev $($cast)*, $fk $($cast)* => $fv $($cast)*, $gk $($cast)* => $gv $($cast)*, $hk $($cast)* => $hv $($cast)*,]; $($($rest)*)?)
+    };
+    (@map $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $k: expr => $v: expr $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@map $kind [$($cast)*] [$($acc)* $k $($cast)* => $v $($cast)*,]; $($($rest)*)?)
+    };
+    // the elements of vectors are rewritten like the values of maps
+    (@vec $kind: ident $cast: tt [$($acc: tt)*];) => {
+        $crate::__private::alloc::vec![$($acc)*]
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: tt, $b: tt, $c: tt, $d: tt, $e: tt, $f: tt, $g: tt, $h: tt $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $crate::__nested!(@value $kind [$($cast)*] $a), $crate::__nested!(@value $kind [$($cast)*] $b), $crate::__nested!(@value $kind [$($cast)*] $c), $crate::__nested!(@value $kind [$($cast)*] $d), $crate::__nested!(@value $kind [$($cast)*] $e), $crate::__nested!(@value $kind [$($cast)*] $f), $crate::__nested!(@value $kind [$($cast)*] $g), $crate::__nested!(@value $kind [$($cast)*] $h),]; $($($rest)*)?)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: tt, $b: tt, $c: tt, $d: tt, $e: tt, $f: tt, $g: tt $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $crate::__nested!(@value $kind [$($cast)*] $a), $crate::__nested!(@value $kind [$($cast)*] $b), $crate::__nested!(@value $kind [$($cast)*] $c), $crate::__nested!(@value $kind [$($cast)*] $d), $crate::__nested!(@value $kind [$($cast)*] $e), $crate::__nested!(@value $kind [$($cast)*] $f), $crate::__nested!(@value $kind [$($cast)*] $g),]; $($($rest)*)?)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: tt, $b: tt, $c: tt, $d: tt, $e: tt, $f: tt $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $crate::__nested!(@value $kind [$($cast)*] $a), $crate::__nested!(@value $kind [$($cast)*] $b), $crate::__nested!(@value $kind [$($cast)*] $c), $crate::__nested!(@value $kind [$($cast)*] $d), $crate::__nested!(@value $kind [$($cast)*] $e), $crate::__nested!(@value $kind [$($cast)*] $f),]; $($($rest)*)?)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: tt, $b: tt, $c: tt, $d: tt, $e: tt $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $crate::__nested!(@value $kind [$($cast)*] $a), $crate::__nested!(@value $kind [$($cast)*] $b), $crate::__nested!(@value $kind [$($cast)*] $c), $crate::__nested!(@value $kind [$($cast)*] $d), $crate::__nested!(@value $kind [$($cast)*] $e),]; $($($rest)*)?)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: tt, $b: tt, $c: tt, $d: tt $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $crate::__nested!(@value $kind [$($cast)*] $a), $crate::__nested!(@value $kind [$($cast)*] $b), $crate::__nested!(@value $kind [$($cast)*] $c), $crate::__nested!(@value $kind [$($cast)*] $d),]; $($($rest)*)?)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: tt, $b: tt, $c: tt $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $crate::__nested!(@value $kind [$($cast)*] $a), $crate::__nested!(@value $kind [$($cast)*] $b), $crate::__nested!(@value $kind [$($cast)*] $c),]; $($($rest)*)?)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: tt, $b: tt $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $crate::__nested!(@value $kind [$($cast)*] $a), $crate::__nested!(@value $kind [$($cast)*] $b),]; $($($rest)*)?)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: tt $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $crate::__nested!(@value $kind [$($cast)*] $a),]; $($($rest)*)?)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: expr, {$($m: tt)*} $($rest: tt)*) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $a $($cast)*,]; {$($m)*} $($rest)*)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: expr, [$($m: tt)*] $($rest: tt)*) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $a $($cast)*,]; [$($m)*] $($rest)*)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: expr, $b: expr, {$($m: tt)*} $($rest: tt)*) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $a $($cast)*, $b $($cast)*,]; {$($m)*} $($rest)*)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: expr, $b: expr, [$($m: tt)*] $($rest: tt)*) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $a $($cast)*, $b $($cast)*,]; [$($m)*] $($rest)*)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: expr, $b: expr, $c: expr, {$($m: tt)*} $($rest: tt)*) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $a $($cast)*, $b $($cast)*, $c $($cast)*,]; {$($m)*} $($rest)*)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: expr, $b: expr, $c: expr, [$($m: tt)*] $($rest: tt)*) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $a $($cast)*, $b $($cast)*, $c $($cast)*,]; [$($m)*] $($rest)*)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: expr, $b: expr, $c: expr, $d: expr, {$($m: tt)*} $($rest: tt)*) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $a $($cast)*, $b $($cast)*, $c $($cast)*, $d $($cast)*,]; {$($m)*} $($rest)*)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: expr, $b: expr, $c: expr, $d: expr, [$($m: tt)*] $($rest: tt)*) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $a $($cast)*, $b $($cast)*, $c $($cast)*, $d $($cast)*,]; [$($m)*] $($rest)*)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: expr, $b: expr, $c: expr, $d: expr, $e: expr, {$($m: tt)*} $($rest: tt)*) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $a $($cast)*, $b $($cast)*, $c $($cast)*, $d $($cast)*, $e $($cast)*,]; {$($m)*} $($rest)*)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: expr, $b: expr, $c: expr, $d: expr, $e: expr, [$($m: tt)*] $($rest: tt)*) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $a $($cast)*, $b $($cast)*, $c $($cast)*, $d $($cast)*, $e $($cast)*,]; [$($m)*] $($rest)*)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: expr, $b: expr, $c: expr, $d: expr, $e: expr, $f: expr, {$($m: tt)*} $($rest: tt)*) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $a $($cast)*, $b $($cast)*, $c $($cast)*, $d $($cast)*, $e $($cast)*, $f $($cast)*,]; {$($m)*} $($rest)*)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: expr, $b: expr, $c: expr, $d: expr, $e: expr, $f: expr, [$($m: tt)*] $($rest: tt)*) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $a $($cast)*, $b $($cast)*, $c $($cast)*, $d $($cast)*, $e $($cast)*, $f $($cast)*,]; [$($m)*] $($rest)*)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: expr, $b: expr, $c: expr, $d: expr, $e: expr, $f: expr, $g: expr, {$($m: tt)*} $($rest: tt)*) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $a $($cast)*, $b $($cast)*, $c $($cast)*, $d $($cast)*, $e $($cast)*, $f $($cast)*, $g $($cast)*,]; {$($m)*} $($rest)*)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: expr, $b: expr, $c: expr, $d: expr, $e: expr, $f: expr, $g: expr, [$($m: tt)*] $($rest: tt)*) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $a $($cast)*, $b $($cast)*, $c $($cast)*, $d $($cast)*, $e $($cast)*, $f $($cast)*, $g $($cast)*,]; [$($m)*] $($rest)*)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $a: expr, $b: expr, $c: expr, $d: expr, $e: expr, $f: expr, $g: expr, $h: expr $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $a $($cast)*, $b $($cast)*, $c $($cast)*, $d $($cast)*, $e $($cast)*, $f $($cast)*, $g $($cast)*, $h $($cast)*,]; $($($rest)*)?)
+    };
+    (@vec $kind: ident [$($cast: tt)*] [$($acc: tt)*]; $v: expr $(, $($rest: tt)*)?) => {
+        $crate::__nested!(@vec $kind [$($cast)*] [$($acc)* $v $($cast)*,]; $($($rest)*)?)
+    };
+    (@value $kind: ident $cast: tt {$($m: tt)*}) => {
+        $crate::__nested!(@map $kind $cast []; $($m)*)
+    };
+    (@value $kind: ident $cast: tt [$($v: tt)*]) => {
+        $crate::__nested!(@vec $kind $cast []; $($v)*)
+    };
+    (@value $kind: ident [$($cast: tt)*] $v: expr) => {
+        $v $($cast)*
+    };
+    (@finish hash_map $($t: tt)*) => {
+        $crate::hash_map!{$($t)*}
+    };
+    (@finish btree_map $($t: tt)*) => {
+        $crate::btree_map!{$($t)*}
+    };
+    (@finish hashbrown $($t: tt)*) => {
+        $crate::hashbrown::hash_map!{$($t)*}
+    };
+}
+
+/// Creates a collection of the given type from the entries of a `try_*`
+/// macro, or returns a [`DuplicateKeyError`](crate::DuplicateKeyError) for
+/// the first key that occurs twice.
+///
+/// Duplicates are detected with a map of the given type from references to
+/// the keys to their indices, before the entries are moved into the
+/// collection.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __try_collect {
+    (@map [$($seen: tt)*] [$($c: tt)*]; $($entry: expr),*) => {
+        $crate::__try_collect!(@collect [$($seen)*] [$($c)*] [.0]; $($entry),*)
+    };
+    (@set [$($seen: tt)*] [$($c: tt)*]; $($entry: expr),*) => {
+        $crate::__try_collect!(@collect [$($seen)*] [$($c)*] []; $($entry),*)
+    };
+    (@collect $seen: tt [$($c: tt)*] $key: tt;) => {
+        ::core::result::Result::<_, $crate::DuplicateKeyError<_>>::Ok(
+            <$($c)* as ::core::default::Default>::default(),
+        )
+    };
+    (@collect [$($seen: tt)*] [$($c: tt)*] [$($key: tt)*]; $($entry: expr),*) => {
+        {
+            let entries = [$($entry,)*];
+
+            let duplicate = {
+                let mut seen = <$($seen)* as ::core::default::Default>::default();
+
+                entries.iter().enumerate().find_map(|(i, entry)| {
+                    seen.insert(&(*entry) $($key)*, i).map(|first| (first, i))
+                })
+            };
+
+            match duplicate {
+                ::core::option::Option::None => ::core::result::Result::Ok(
+                    <$($c)* as ::core::iter::FromIterator<_>>::from_iter(entries),
+                ),
+                ::core::option::Option::Some((first, second)) => {
+                    let entry = ::core::iter::IntoIterator::into_iter(entries)
+                        .nth(second)
+                        .unwrap();
+
+                    ::core::result::Result::Err(
+                        $crate::DuplicateKeyError::__new(first, second, entry $($key)*),
+                    )
+                }
+            }
+        }
+    };
+}
+
+/// Expands to statements adding the entries of a multimap macro to the map
+/// `$c`.
+///
+/// The values of every key are collected into an inner collection of type
+/// `$inner`, which is created with [`Default`] the first time the key
+/// occurs.
+/// Entries written as `k => [v1, v2]` add all listed values at once.
+/// The tokens in brackets are appended to every key and value, which the
+/// explicitly typed macros use to cast them with `as _`.
+/// Entries are inserted up to eight at a time, so long lists don't run into
+/// the recursion limit.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __multimap {
+    (@cast [$($cast: tt)*] $e: expr) => {
+        $e $($cast)*
+    };
+    (@insert $c: ident $inner: ty, $cast: tt; $k: expr => [$($v: expr),* $(,)?]) => {
+        $crate::__private::extend_set(
+            $c.entry($crate::__multimap!(@cast $cast $k))
+                .or_insert_with(<$inner as ::core::default::Default>::default),
+            [$($crate::__multimap!(@cast $cast $v)),*],
+        );
+    };
+    (@insert $c: ident $inner: ty, $cast: tt; $k: expr => $v: expr) => {
+        $crate::__private::extend_set(
+            $c.entry($crate::__multimap!(@cast $cast $k))
+                .or_insert_with(<$inner as ::core::default::Default>::default),
+            [$crate::__multimap!(@cast $cast $v)],
+        );
+    };
+    ($c: ident $inner: ty, $cast: tt;) => {};
+    // entries with lists of values are inserted up to eight at a time
+    ($c: ident $inner: ty, $cast: tt; $ak: expr => [$($av: tt)*], $bk: expr => [$($bv: tt)*], $ck: expr => [$($cv: tt)*], $dk: expr => [$($dv: tt)*], $ek: expr => [$($ev: tt)*], $fk: expr => [$($fv: tt)*], $gk: expr => [$($gv: tt)*], $hk: expr => [$($hv: tt)*] $(, $($rest: tt)*)?) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $ak => [$($av)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $bk => [$($bv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $ck => [$($cv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $dk => [$($dv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $ek => [$($ev)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $fk => [$($fv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $gk => [$($gv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $hk => [$($hv)*]);
+        $crate::__multimap!($c $inner, $cast; $($($rest)*)?);
+    };
+    ($c: ident $inner: ty, $cast: tt; $ak: expr => [$($av: tt)*], $bk: expr => [$($bv: tt)*], $ck: expr => [$($cv: tt)*], $dk: expr => [$($dv: tt)*], $ek: expr => [$($ev: tt)*], $fk: expr => [$($fv: tt)*], $gk: expr => [$($gv: tt)*] $(, $($rest: tt)*)?) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $ak => [$($av)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $bk => [$($bv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $ck => [$($cv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $dk => [$($dv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $ek => [$($ev)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $fk => [$($fv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $gk => [$($gv)*]);
+        $crate::__multimap!($c $inner, $cast; $($($rest)*)?);
+    };
+    ($c: ident $inner: ty, $cast: tt; $ak: expr => [$($av: tt)*], $bk: expr => [$($bv: tt)*], $ck: expr => [$($cv: tt)*], $dk: expr => [$($dv: tt)*], $ek: expr => [$($ev: tt)*], $fk: expr => [$($fv: tt)*] $(, $($rest: tt)*)?) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $ak => [$($av)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $bk => [$($bv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $ck => [$($cv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $dk => [$($dv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $ek => [$($ev)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $fk => [$($fv)*]);
+        $crate::__multimap!($c $inner, $cast; $($($rest)*)?);
+    };
+    ($c: ident $inner: ty, $cast: tt; $ak: expr => [$($av: tt)*], $bk: expr => [$($bv: tt)*], $ck: expr => [$($cv: tt)*], $dk: expr => [$($dv: tt)*], $ek: expr => [$($ev: tt)*] $(, $($rest: tt)*)?) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $ak => [$($av)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $bk => [$($bv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $ck => [$($cv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $dk => [$($dv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $ek => [$($ev)*]);
+        $crate::__multimap!($c $inner, $cast; $($($rest)*)?);
+    };
+    ($c: ident $inner: ty, $cast: tt; $ak: expr => [$($av: tt)*], $bk: expr => [$($bv: tt)*], $ck: expr => [$($cv: tt)*], $dk: expr => [$($dv: tt)*] $(, $($rest: tt)*)?) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $ak => [$($av)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $bk => [$($bv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $ck => [$($cv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $dk => [$($dv)*]);
+        $crate::__multimap!($c $inner, $cast; $($($rest)*)?);
+    };
+    ($c: ident $inner: ty, $cast: tt; $ak: expr => [$($av: tt)*], $bk: expr => [$($bv: tt)*], $ck: expr => [$($cv: tt)*] $(, $($rest: tt)*)?) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $ak => [$($av)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $bk => [$($bv)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $ck => [$($cv)*]);
+        $crate::__multimap!($c $inner, $cast; $($($rest)*)?);
+    };
+    ($c: ident $inner: ty, $cast: tt; $ak: expr => [$($av: tt)*], $bk: expr => [$($bv: tt)*] $(, $($rest: tt)*)?) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $ak => [$($av)*]);
+        $crate::__multimap!(@insert $c $inner, $cast; $bk => [$($bv)*]);
+        $crate::__multimap!($c $inner, $cast; $($($rest)*)?);
+    };
+    ($c: ident $inner: ty, $cast: tt; $ak: expr => [$($av: tt)*] $(, $($rest: tt)*)?) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $ak => [$($av)*]);
+        $crate::__multimap!($c $inner, $cast; $($($rest)*)?);
+    };
+    // entries with single values are inserted up to eight at a time as well,
+    // stopping in front of a list, which would be parsed as an array otherwise
+    ($c: ident $inner: ty, $cast: tt; $ak: expr => $av: expr, $k: expr => [$($v: tt)*] $($rest: tt)*) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $ak => $av);
+        $crate::__multimap!($c $inner, $cast; $k => [$($v)*] $($rest)*);
+    };
+    ($c: ident $inner: ty, $cast: tt; $ak: expr => $av: expr, $bk: expr => $bv: expr, $k: expr => [$($v: tt)*] $($rest: tt)*) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $ak => $av);
+        $crate::__multimap!(@insert $c $inner, $cast; $bk => $bv);
+        $crate::__multimap!($c $inner, $cast; $k => [$($v)*] $($rest)*);
+    };
+    ($c: ident $inner: ty, $cast: tt; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $k: expr => [$($v: tt)*] $($rest: tt)*) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $ak => $av);
+        $crate::__multimap!(@insert $c $inner, $cast; $bk => $bv);
+        $crate::__multimap!(@insert $c $inner, $cast; $ck => $cv);
+        $crate::__multimap!($c $inner, $cast; $k => [$($v)*] $($rest)*);
+    };
+    ($c: ident $inner: ty, $cast: tt; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $k: expr => [$($v: tt)*] $($rest: tt)*) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $ak => $av);
+        $crate::__multimap!(@insert $c $inner, $cast; $bk => $bv);
+        $crate::__multimap!(@insert $c $inner, $cast; $ck => $cv);
+        $crate::__multimap!(@insert $c $inner, $cast; $dk => $dv);
+        $crate::__multimap!($c $inner, $cast; $k => [$($v)*] $($rest)*);
+    };
+    ($c: ident $inner: ty, $cast: tt; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $k: expr => [$($v: tt)*] $($rest: tt)*) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $ak => $av);
+        $crate::__multimap!(@insert $c $inner, $cast; $bk => $bv);
+        $crate::__multimap!(@insert $c $inner, $cast; $ck => $cv);
+        $crate::__multimap!(@insert $c $inner, $cast; $dk => $dv);
+        $crate::__multimap!(@insert $c $inner, $cast; $ek => $ev);
+        $crate::__multimap!($c $inner, $cast; $k => [$($v)*] $($rest)*);
+    };
+    ($c: ident $inner: ty, $cast: tt; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, $k: expr => [$($v: tt)*] $($rest: tt)*) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $ak => $av);
+        $crate::__multimap!(@insert $c $inner, $cast; $bk => $bv);
+        $crate::__multimap!(@insert $c $inner, $cast; $ck => $cv);
+        $crate::__multimap!(@insert $c $inner, $cast; $dk => $dv);
+        $crate::__multimap!(@insert $c $inner, $cast; $ek => $ev);
+        $crate::__multimap!(@insert $c $inner, $cast; $fk => $fv);
+        $crate::__multimap!($c $inner, $cast; $k => [$($v)*] $($rest)*);
+    };
+    ($c: ident $inner: ty, $cast: tt; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, $gk: expr => $gv: expr, $k: expr => [$($v: tt)*] $($rest: tt)*) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $ak => $av);
+        $crate::__multimap!(@insert $c $inner, $cast; $bk => $bv);
+        $crate::__multimap!(@insert $c $inner, $cast; $ck => $cv);
+        $crate::__multimap!(@insert $c $inner, $cast; $dk => $dv);
+        $crate::__multimap!(@insert $c $inner, $cast; $ek => $ev);
+        $crate::__multimap!(@insert $c $inner, $cast; $fk => $fv);
+        $crate::__multimap!(@insert $c $inner, $cast; $gk => $gv);
+        $crate::__multimap!($c $inner, $cast; $k => [$($v)*] $($rest)*);
+    };
+    ($c: ident $inner: ty, $cast: tt; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, $gk: expr => $gv: expr, $hk: expr => $hv: expr $(, $($rest: tt)*)?) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $ak => $av);
+        $crate::__multimap!(@insert $c $inner, $cast; $bk => $bv);
+        $crate::__multimap!(@insert $c $inner, $cast; $ck => $cv);
+        $crate::__multimap!(@insert $c $inner, $cast; $dk => $dv);
+        $crate::__multimap!(@insert $c $inner, $cast; $ek => $ev);
+        $crate::__multimap!(@insert $c $inner, $cast; $fk => $fv);
+        $crate::__multimap!(@insert $c $inner, $cast; $gk => $gv);
+        $crate::__multimap!(@insert $c $inner, $cast; $hk => $hv);
+        $crate::__multimap!($c $inner, $cast; $($($rest)*)?);
+    };
+    ($c: ident $inner: ty, $cast: tt; $k: expr => $v: expr $(, $($rest: tt)*)?) => {
+        $crate::__multimap!(@insert $c $inner, $cast; $k => $v);
+        $crate::__multimap!($c $inner, $cast; $($($rest)*)?);
+    };
+}
+
+/// Usually fails to compile if the same literal occurs more than once.
+///
+/// Expands to a `match` on the first literal with every literal as a
+/// pattern, in which a repeated literal is an unreachable pattern.
+/// This is only a lint, which `--cap-lints` can downgrade to a warning, so
+/// callers must still reject duplicates at runtime.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __unique_literals {
+    () => {};
+    ($first: literal $(, $l: literal)*) => {
+        #[deny(unreachable_patterns)]
+        let () = match $first {
+            $first => {}
+            $($l => {})*
+            #[allow(unreachable_patterns)]
+            _ => {}
+        };
+    };
+}
+
+/// Creates a [`BiHashMap`](crate::BiHashMap) from the entries of a
+/// [`bi_map!`](crate::bi_map) macro, panicking if a value occurs twice on
+/// the same side.
+///
+/// The values are cast with `as _` if the first argument is `[as _]`, which
+/// the explicitly typed macro uses.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bi_map {
+    ([] $($l: expr => $r: expr),*) => {
+        {
+            let mut map = $crate::BiHashMap::new();
+            $(map.__insert($l, $r);)*
+            map
+        }
+    };
+    ([as _] $($l: expr => $r: expr),*) => {
+        {
+            let mut map = $crate::BiHashMap::new();
+            $(map.__insert($l as _, $r as _);)*
+            map
+        }
+    };
+}
@@ -0,0 +1,282 @@
+/// Macro for declaring lazily initialized [`HashMap`](::std::collections::HashMap) statics.
+///
+/// Declares a [`LazyLock`](::std::sync::LazyLock) that is initialized with
+/// [`hash_map_e!`](crate::hash_map_e) when it is accessed for the first time.
+/// This lets you declare `static` maps, even though [`hash_map!`](crate::hash_map) can't be
+/// evaluated in a `const` context.
+///
+/// The `lazy_*` macros require Rust 1.80 or newer, which stabilized
+/// `LazyLock`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use map_macro::lazy_hash_map;
+///
+/// lazy_hash_map! {
+///     pub static GOODBYE: HashMap<&str, &str> = {
+///         "en" => "Goodbye",
+///         "de" => "Auf Wiedersehen",
+///         "fr" => "Au revoir",
+///     };
+/// }
+///
+/// assert_eq!(GOODBYE["de"], "Auf Wiedersehen");
+/// ```
+///
+/// Multiple statics can be declared in a single invocation, each with its
+/// own attributes and visibility.
+/// The body of every declaration accepts the same input as [`hash_map!`](crate::hash_map),
+/// including the [`hasher`](crate::hash_map#custom-hashers) and
+/// [`capacity`](crate::hash_map#capacity) clauses.
+///
+/// # Explicit Types
+///
+/// Because the type of a `static` must always be written out, it is used to
+/// cast the keys and values just like the
+/// [explicitly typed macros](crate#explicitly-typed-macros) do.
+/// Trait objects therefore work without further annotations.
+/// Note that the values of a `static` must be [`Sync`]:
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use std::fmt::Debug;
+///
+/// use map_macro::lazy_hash_map;
+///
+/// lazy_hash_map! {
+///     /// Greetings in different languages.
+///     static HELLO: HashMap<&str, &(dyn Debug + Sync)> = {
+///         "en" => &"Hello",
+///         "de" => &"Hallo",
+///     };
+///
+///     static ANSWERS: HashMap<u8, &(dyn Debug + Sync)> = {
+///         0 => &42,
+///         1 => &"forty-two",
+///     };
+/// }
+///
+/// assert_eq!(HELLO.len(), 2);
+/// assert_eq!(format!("{:?}", ANSWERS[&0]), "42");
+/// ```
+///
+#[macro_export]
+macro_rules! lazy_hash_map {
+    {$($t: tt)*} => {
+        $crate::__lazy!($crate::hash_map_e; $($t)*);
+    };
+}
+
+/// Macro for declaring lazily initialized [`BTreeMap`](::std::collections::BTreeMap) statics.
+///
+/// Declares a [`LazyLock`](::std::sync::LazyLock) that is initialized with
+/// [`btree_map_e!`](crate::btree_map_e) when it is accessed for the first time.
+/// See [`lazy_hash_map!`] for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use map_macro::lazy_btree_map;
+///
+/// lazy_btree_map! {
+///     static GOODBYE: BTreeMap<u8, &str> = {
+///         0 => "Goodbye",
+///         1 => "Auf Wiedersehen",
+///     };
+/// }
+///
+/// assert_eq!(GOODBYE[&1], "Auf Wiedersehen");
+/// ```
+///
+#[macro_export]
+macro_rules! lazy_btree_map {
+    {$($t: tt)*} => {
+        $crate::__lazy!($crate::btree_map_e; $($t)*);
+    };
+}
+
+/// Macro for declaring lazily initialized [`HashSet`](::std::collections::HashSet) statics.
+///
+/// Declares a [`LazyLock`](::std::sync::LazyLock) that is initialized with
+/// [`hash_set_e!`](crate::hash_set_e) when it is accessed for the first time.
+/// See [`lazy_hash_map!`] for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashSet;
+///
+/// use map_macro::lazy_hash_set;
+///
+/// lazy_hash_set! {
+///     static LANGUAGES: HashSet<&str> = { "en", "de", "fr" };
+/// }
+///
+/// assert!(LANGUAGES.contains("de"));
+/// ```
+///
+#[macro_export]
+macro_rules! lazy_hash_set {
+    {$($t: tt)*} => {
+        $crate::__lazy!($crate::hash_set_e; $($t)*);
+    };
+}
+
+/// Macro for declaring lazily initialized [`BTreeSet`](::std::collections::BTreeSet) statics.
+///
+/// Declares a [`LazyLock`](::std::sync::LazyLock) that is initialized with
+/// [`btree_set_e!`](crate::btree_set_e) when it is accessed for the first time.
+/// See [`lazy_hash_map!`] for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeSet;
+///
+/// use map_macro::lazy_btree_set;
+///
+/// lazy_btree_set! {
+///     static LANGUAGES: BTreeSet<&str> = { "en", "de", "fr" };
+/// }
+///
+/// assert_eq!(LANGUAGES.first(), Some(&"de"));
+/// ```
+///
+#[macro_export]
+macro_rules! lazy_btree_set {
+    {$($t: tt)*} => {
+        $crate::__lazy!($crate::btree_set_e; $($t)*);
+    };
+}
+
+/// Macro for declaring lazily initialized [`VecDeque`](::std::collections::VecDeque) statics.
+///
+/// Declares a [`LazyLock`](::std::sync::LazyLock) that is initialized with
+/// [`vec_deque_e!`](crate::vec_deque_e) when it is accessed for the first time.
+/// See [`lazy_hash_map!`] for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::VecDeque;
+///
+/// use map_macro::lazy_vec_deque;
+///
+/// lazy_vec_deque! {
+///     static NUMBERS: VecDeque<u8> = [1, 2, 3];
+/// }
+///
+/// assert_eq!(NUMBERS[1], 2);
+/// ```
+///
+#[macro_export]
+macro_rules! lazy_vec_deque {
+    {$($t: tt)*} => {
+        $crate::__lazy!($crate::vec_deque_e; $($t)*);
+    };
+}
+
+/// Macro for declaring lazily initialized [`LinkedList`](::std::collections::LinkedList) statics.
+///
+/// Declares a [`LazyLock`](::std::sync::LazyLock) that is initialized with
+/// [`linked_list_e!`](crate::linked_list_e) when it is accessed for the first time.
+/// See [`lazy_hash_map!`] for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::LinkedList;
+///
+/// use map_macro::lazy_linked_list;
+///
+/// lazy_linked_list! {
+///     static NUMBERS: LinkedList<u8> = [1, 2, 3];
+/// }
+///
+/// assert_eq!(NUMBERS.back(), Some(&3));
+/// ```
+///
+#[macro_export]
+macro_rules! lazy_linked_list {
+    {$($t: tt)*} => {
+        $crate::__lazy!($crate::linked_list_e; $($t)*);
+    };
+}
+
+/// Macro for declaring lazily initialized [`BinaryHeap`](::std::collections::BinaryHeap) statics.
+///
+/// Declares a [`LazyLock`](::std::sync::LazyLock) that is initialized with
+/// [`binary_heap_e!`](crate::binary_heap_e) when it is accessed for the first time.
+/// See [`lazy_hash_map!`] for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BinaryHeap;
+///
+/// use map_macro::lazy_binary_heap;
+///
+/// lazy_binary_heap! {
+///     static NUMBERS: BinaryHeap<u8> = [1, 3, 2];
+/// }
+///
+/// assert_eq!(NUMBERS.peek(), Some(&3));
+/// ```
+///
+#[macro_export]
+macro_rules! lazy_binary_heap {
+    {$($t: tt)*} => {
+        $crate::__lazy!($crate::binary_heap_e; $($t)*);
+    };
+}
+
+/// Macro for declaring lazily initialized [`Vec`](::std::vec::Vec) statics.
+///
+/// Declares a [`LazyLock`](::std::sync::LazyLock) that is initialized with
+/// [`vec_no_clone_e!`](crate::vec_no_clone_e) when it is accessed for the first time.
+/// See [`lazy_hash_map!`] for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::lazy_vec_no_clone;
+///
+/// lazy_vec_no_clone! {
+///     static NUMBERS: Vec<Vec<u8>> = [Vec::new(); 4];
+/// }
+///
+/// assert_eq!(NUMBERS.len(), 4);
+/// ```
+///
+#[macro_export]
+macro_rules! lazy_vec_no_clone {
+    {$($t: tt)*} => {
+        $crate::__lazy!($crate::vec_no_clone_e; $($t)*);
+    };
+}
+
+/// Declares the statics of a `lazy_*` macro one at a time, each a
+/// [`LazyLock`](::std::sync::LazyLock) that is initialized with the macro at
+/// the given path, e.g. `$crate::hash_map_e`.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lazy {
+    {$($m: ident)::+;} => {};
+    {
+        $($m: ident)::+;
+        $(#[$attr: meta])* $vis: vis static $name: ident: $ty: ty = $body: tt;
+        $($rest: tt)*
+    } => {
+        $(#[$attr])*
+        $vis static $name: ::std::sync::LazyLock<$ty> =
+            ::std::sync::LazyLock::new(|| -> $ty { $($m)::+! $body });
+
+        $crate::__lazy!($($m)::+; $($rest)*);
+    };
+}
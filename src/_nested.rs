@@ -0,0 +1,148 @@
+/// Macro for creating nested [`HashMap`](::std::collections::HashMap)s.
+///
+/// Accepts the same `key => value` entries as [`hash_map!`](crate::hash_map),
+/// but values written as `{ ... }` become inner `HashMap`s and values written
+/// as `[ ... ]` become [`Vec`](::std::vec::Vec)s.
+/// Both can be nested arbitrarily deep, which lets you write JSON-like
+/// structures with a single macro invocation.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::nested_hash_map;
+///
+/// let config = nested_hash_map! {
+///     "server" => {
+///         "host" => ["localhost"],
+///         "ports" => ["8080", "8081"],
+///     },
+///     "client" => {
+///         "retries" => ["3"],
+///     },
+/// };
+///
+/// assert_eq!(config["server"]["ports"], vec!["8080", "8081"]);
+/// assert_eq!(config["client"]["retries"][0], "3");
+/// ```
+///
+/// Elements of a vector can be maps or vectors themselves:
+///
+/// ```rust
+/// use map_macro::nested_hash_map;
+///
+/// let users = nested_hash_map! {
+///     "users" => [
+///         { "name" => "Alice", "role" => "admin" },
+///         { "name" => "Bob", "role" => "user" },
+///     ],
+/// };
+///
+/// assert_eq!(users["users"][1]["name"], "Bob");
+/// ```
+///
+/// Every value that is not written as `{ ... }` or `[ ... ]` is used as is.
+/// Wrap block expressions in parentheses to keep them from being interpreted
+/// as maps:
+///
+/// ```rust
+/// use map_macro::nested_hash_map;
+///
+/// let m = nested_hash_map! {
+///     "a" => { "b" => ({ let x = 1; x + 1 }) },
+/// };
+///
+/// assert_eq!(m["a"]["b"], 2);
+/// ```
+///
+#[macro_export]
+macro_rules! nested_hash_map {
+    {$($t: tt)*} => {
+        $crate::__nested!(@map hash_map [] []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`nested_hash_map!`].
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// Casts every key and every value that is neither a map nor a vector.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use std::fmt::Debug;
+///
+/// use map_macro::nested_hash_map_e;
+///
+/// let settings: HashMap<&str, HashMap<&str, &dyn Debug>> = nested_hash_map_e! {
+///     "window" => {
+///         "title" => &"map-macro",
+///         "width" => &800,
+///     },
+/// };
+///
+/// assert_eq!(format!("{:?}", settings["window"]["width"]), "800");
+/// ```
+///
+#[macro_export]
+macro_rules! nested_hash_map_e {
+    {$($t: tt)*} => {
+        $crate::__nested!(@map hash_map [as _] []; $($t)*)
+    };
+}
+
+/// Macro for creating nested [`BTreeMap`](::std::collections::BTreeMap)s.
+///
+/// Values written as `{ ... }` become inner `BTreeMap`s and values written as
+/// `[ ... ]` become [`Vec`](::std::vec::Vec)s.
+/// See [`nested_hash_map!`] for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::nested_btree_map;
+///
+/// let config = nested_btree_map! {
+///     "server" => {
+///         "host" => ["localhost"],
+///         "ports" => ["8080", "8081"],
+///     },
+/// };
+///
+/// assert_eq!(config["server"]["ports"], vec!["8080", "8081"]);
+/// ```
+///
+#[macro_export]
+macro_rules! nested_btree_map {
+    {$($t: tt)*} => {
+        $crate::__nested!(@map btree_map [] []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`nested_btree_map!`].
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use std::fmt::Debug;
+///
+/// use map_macro::nested_btree_map_e;
+///
+/// let settings: BTreeMap<&str, BTreeMap<&str, &dyn Debug>> = nested_btree_map_e! {
+///     "window" => {
+///         "title" => &"map-macro",
+///         "width" => &800,
+///     },
+/// };
+/// ```
+///
+#[macro_export]
+macro_rules! nested_btree_map_e {
+    {$($t: tt)*} => {
+        $crate::__nested!(@map btree_map [as _] []; $($t)*)
+    };
+}
@@ -0,0 +1,69 @@
+use core::cmp::Ordering;
+
+/// Item of a priority queue, ordered only by its priority.
+///
+/// Elements of the [`BinaryHeap`](::std::collections::BinaryHeap)s created
+/// by [`priority_queue!`](crate::priority_queue).
+/// Unlike a `(priority, item)` tuple, the item does not need to implement
+/// [`Ord`] and does not break ties between equal priorities.
+/// Items with equal priorities are therefore popped from the heap in an
+/// unspecified order.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BinaryHeap;
+///
+/// use map_macro::Prioritized;
+///
+/// let mut queue = BinaryHeap::new();
+///
+/// queue.push(Prioritized::new("low", 1));
+/// queue.push(Prioritized::new("high", 10));
+///
+/// assert_eq!(queue.pop().unwrap().item, "high");
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Prioritized<T, P> {
+    /// The item.
+    pub item: T,
+    /// The priority of the item.
+    pub priority: P,
+}
+
+impl<T, P> Prioritized<T, P> {
+    /// Creates a new item with the given priority.
+    ///
+    #[must_use]
+    pub fn new(item: T, priority: P) -> Self {
+        Self { item, priority }
+    }
+
+    /// Consumes the wrapper, returning the item.
+    ///
+    #[must_use]
+    pub fn into_item(self) -> T {
+        self.item
+    }
+}
+
+impl<T, P: PartialEq> PartialEq for Prioritized<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<T, P: Eq> Eq for Prioritized<T, P> {}
+
+impl<T, P: PartialOrd> PartialOrd for Prioritized<T, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.priority.partial_cmp(&other.priority)
+    }
+}
+
+impl<T, P: Ord> Ord for Prioritized<T, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
@@ -16,93 +16,346 @@
 /// };
 /// ```
 ///
-#[macro_export]
-macro_rules! hash_map {
-    {$($k: expr => $v: expr),* $(,)?} => {
-        ::std::collections::HashMap::from([$(($k, $v),)*])
-    };
-}
-
-/// Explicitly typed equivalent of [`hash_map!`].
+/// # Custom Hashers
 ///
-/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+/// If the first clause of the macro is `hasher: expr;`, the map is created
+/// with [`HashMap::with_capacity_and_hasher`](::std::collections::HashMap::with_capacity_and_hasher),
+/// using the provided [`BuildHasher`](::std::hash::BuildHasher):
 ///
-/// # Examples
+/// ```rust
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::BuildHasherDefault;
+///
+/// use map_macro::hash_map;
+///
+/// let goodbye = hash_map! {
+///     hasher: BuildHasherDefault::<DefaultHasher>::default();
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+///     "fr" => "Au revoir",
+///     "es" => "Adios",
+///     "cat" => "Adéu",
+/// };
+/// ```
+///
+/// # Capacity
+///
+/// The map is created with just enough capacity for the provided
+/// elements.
+/// If you intend to add more elements to it later on, you can reserve
+/// additional capacity up front with a leading `capacity: expr;` clause.
+/// The map is then created with a capacity of at least `expr`:
 ///
 /// ```rust
-/// use std::collections::HashMap;
-/// use std::fmt::Debug;
+/// use map_macro::hash_map;
 ///
-/// use map_macro::hash_map_e;
+/// let mut goodbye = hash_map! {
+///     capacity: 10;
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+/// };
 ///
-/// let goodbye: HashMap<&str, &dyn Debug> = hash_map_e! {
-///     "en" => &"Goodbye",
-///     "de" => &"Auf Wiedersehen",
-///     "fr" => &"Au revoir",
-///     "es" => &"Adios",
-///     "cat" => &"Adéu",
+/// assert!(goodbye.capacity() >= 10);
+///
+/// goodbye.insert("fr", "Au revoir");
+/// ```
+///
+/// The `capacity` and `hasher` clauses can be combined in any order.
+///
+/// # Comprehensions
+///
+/// Entries can also be computed from iterators with comprehension clauses,
+/// similar to Python's dict comprehensions.
+/// A `key => value for pattern in iterator` entry inserts an entry for
+/// every element of the iterator.
+/// It can be followed by an `if condition` filtering the elements, as well as
+/// by further `for` clauses, which are nested in the order they appear in:
+///
+/// ```rust
+/// use map_macro::hash_map;
+///
+/// let squares = hash_map! { x => x * x for x in 0..10 if x % 2 == 0 };
+///
+/// assert_eq!(squares[&4], 16);
+///
+/// let products = hash_map! { (a, b) => a * b for a in 1..4 for b in 1..4 if a < b };
+///
+/// assert_eq!(products.len(), 3);
+/// assert_eq!(products[&(2, 3)], 6);
+/// ```
+///
+/// Comprehensions can be mixed with literal entries.
+/// Entries are inserted in the order they appear in, so later entries
+/// overwrite earlier ones with the same key:
+///
+/// ```rust
+/// use map_macro::hash_map;
+///
+/// let lengths = hash_map! {
+///     "default" => 0,
+///     word => word.len() for word in ["hello", "world", "default"],
+///     "override" => 42,
 /// };
 ///
-/// println!("{:?}", goodbye);
+/// assert_eq!(lengths["default"], 7);
+/// assert_eq!(lengths["override"], 42);
 /// ```
 ///
-#[macro_export]
-macro_rules! hash_map_e {
-    {$($k: expr => $v: expr),* $(,)?} => {
-        ::std::collections::HashMap::from([$(($k as _, $v as _),)*])
-    };
-}
-
-/// Macro for creating a [`BTreeMap`](::std::collections::BTreeMap).
+/// # Spread
 ///
-/// Syntactic sugar for [`BTreeMap::from`](::std::collections::BTreeMap::from).
+/// An entry written as `..iterable` inserts all key-value pairs of
+/// `iterable`, which can be anything that implements [`IntoIterator`] with
+/// `(key, value)` items, like another map.
+/// Because entries are inserted in the order they appear in, this makes
+/// creating a map from defaults and a few overrides easy:
 ///
-/// # Examples
+/// ```rust
+/// use map_macro::hash_map;
+///
+/// let defaults = hash_map! { "timeout" => 10, "retries" => 3 };
+///
+/// let config = hash_map! {
+///     ..defaults,
+///     "timeout" => 30,
+/// };
+///
+/// assert_eq!(config["timeout"], 30);
+/// assert_eq!(config["retries"], 3);
+/// ```
+///
+/// Before the pairs of the spread source are inserted, capacity is reserved
+/// for the lower bound of its [size hint](Iterator::size_hint), which is
+/// exact if the iterator of the source is an [`ExactSizeIterator`].
+///
+/// # Conditional Entries
+///
+/// An entry followed by an `if` guard is only inserted if the condition is
+/// `true`.
+/// Entries can also be annotated with attributes like `#[cfg(...)]`, and an
+/// entry written as `key =>? option` is only inserted if `option` is `Some`,
+/// in which case the value of the `Some` is inserted:
 ///
 /// ```rust
-/// use map_macro::btree_map;
+/// use map_macro::hash_map;
 ///
-/// let goodbye = btree_map! {
-///     "en" => "Goodbye",
-///     "de" => "Auf Wiedersehen",
-///     "fr" => "Au revoir",
-///     "es" => "Adios",
-///     "cat" => "Adéu",
+/// let proxy: Option<&str> = None;
+///
+/// let config = hash_map! {
+///     "host" => "localhost",
+///     "log" => "debug" if cfg!(debug_assertions),
+///     #[cfg(unix)]
+///     "socket" => "/tmp/app.sock",
+///     "proxy" =>? proxy,
 /// };
+///
+/// assert!(!config.contains_key("proxy"));
+/// ```
+///
+/// The key and value of a guarded entry are only evaluated if the condition
+/// is `true`.
+///
+/// # Shorthand Entries
+///
+/// Like the field init shorthand of structs, a bare identifier is an entry
+/// with the name of the identifier as key and its value as value.
+/// Shorthand entries can be mixed with other entries:
+///
+/// ```rust
+/// use map_macro::hash_map;
+///
+/// let width = 16;
+/// let height = 9;
+///
+/// let dimensions = hash_map! { width, height, "depth" => 1 };
+///
+/// assert_eq!(dimensions["width"], 16);
+/// assert_eq!(dimensions["height"], 9);
 /// ```
 ///
 #[macro_export]
-macro_rules! btree_map {
-    {$($k: expr => $v: expr),* $(,)?} => {
-        ::std::collections::BTreeMap::from([$(($k, $v),)*])
+macro_rules! hash_map {
+    {@list hasher: $h: expr; capacity: $c: expr; $($k: expr => $v: expr),* $(,)?} => {
+        {
+            let mut map = ::std::collections::HashMap::with_capacity_and_hasher(
+                ::core::cmp::max($c, $crate::__count!($($k)*)),
+                $h,
+            );
+            $(map.insert($k, $v);)*
+            map
+        }
+    };
+    {@list hasher: $h: expr; $($k: expr => $v: expr),* $(,)?} => {
+        {
+            let mut map = ::std::collections::HashMap::with_capacity_and_hasher(
+                $crate::__count!($($k)*),
+                $h,
+            );
+            $(map.insert($k, $v);)*
+            map
+        }
+    };
+    {@list capacity: $c: expr; $($k: expr => $v: expr),* $(,)?} => {
+        {
+            let mut map = ::std::collections::HashMap::with_capacity(
+                ::core::cmp::max($c, $crate::__count!($($k)*)),
+            );
+            $(map.insert($k, $v);)*
+            map
+        }
+    };
+    {@list $($k: expr => $v: expr),* $(,)?} => {
+        ::std::collections::HashMap::from([$(($k, $v),)*])
+    };
+    {@entries hasher: $h: expr; capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut map = ::std::collections::HashMap::with_capacity_and_hasher($c, $h);
+            $crate::__entries!(@map map []; $($t)*);
+            map
+        }
+    };
+    {@entries hasher: $h: expr; $($t: tt)*} => {
+        {
+            let mut map = ::std::collections::HashMap::with_hasher($h);
+            $crate::__entries!(@map map []; $($t)*);
+            map
+        }
+    };
+    {@entries capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut map = ::std::collections::HashMap::with_capacity($c);
+            $crate::__entries!(@map map []; $($t)*);
+            map
+        }
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut map = ::std::collections::HashMap::new();
+            $crate::__entries!(@map map []; $($t)*);
+            map
+        }
+    };
+    {capacity: $c: expr; hasher: $h: expr; $($t: tt)*} => {
+        $crate::hash_map!{hasher: $h; capacity: $c; $($t)*}
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(hash_map map []; $($t)*)
     };
 }
 
-/// Explicitly typed equivalent of [`btree_map!`].
+/// Explicitly typed equivalent of [`hash_map!`].
 ///
 /// See the [explicity typed macros](crate#explicitly-typed-macros) section.
 ///
 /// # Examples
 ///
 /// ```rust
-/// use std::collections::BTreeMap;
+/// use std::collections::HashMap;
 /// use std::fmt::Debug;
 ///
-/// use map_macro::btree_map_e;
+/// use map_macro::hash_map_e;
 ///
-/// let goodbye: BTreeMap<&str, &dyn Debug> = btree_map_e! {
+/// let goodbye: HashMap<&str, &dyn Debug> = hash_map_e! {
 ///     "en" => &"Goodbye",
 ///     "de" => &"Auf Wiedersehen",
 ///     "fr" => &"Au revoir",
 ///     "es" => &"Adios",
 ///     "cat" => &"Adéu",
 /// };
+///
+/// println!("{:?}", goodbye);
+/// ```
+///
+/// Supports the same [`hasher`](hash_map#custom-hashers) and
+/// [`capacity`](hash_map#capacity) clauses, as well as the same
+/// [comprehensions](hash_map#comprehensions), [spread](hash_map#spread),
+/// [conditional](hash_map#conditional-entries) and
+/// [shorthand](hash_map#shorthand-entries) entries as [`hash_map!`]:
+///
+/// ```rust
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::collections::HashMap;
+/// use std::fmt::Debug;
+/// use std::hash::BuildHasherDefault;
+///
+/// use map_macro::hash_map_e;
+///
+/// let goodbye: HashMap<&str, &dyn Debug, BuildHasherDefault<DefaultHasher>> = hash_map_e! {
+///     hasher: Default::default();
+///     "en" => &"Goodbye",
+///     "de" => &"Auf Wiedersehen",
+/// };
 /// ```
 ///
+/// **Note:** the pairs of spread sources are not cast, so they must already
+/// have the key and value types of the map.
+///
 #[macro_export]
-macro_rules! btree_map_e {
-    {$($k: expr => $v: expr),* $(,)?} => {
-        ::std::collections::BTreeMap::from([$(($k as _, $v as _),)*])
+macro_rules! hash_map_e {
+    {@list hasher: $h: expr; capacity: $c: expr; $($k: expr => $v: expr),* $(,)?} => {
+        {
+            let mut map = ::std::collections::HashMap::with_capacity_and_hasher(
+                ::core::cmp::max($c, $crate::__count!($($k)*)),
+                $h,
+            );
+            $(map.insert($k as _, $v as _);)*
+            map
+        }
+    };
+    {@list hasher: $h: expr; $($k: expr => $v: expr),* $(,)?} => {
+        {
+            let mut map = ::std::collections::HashMap::with_capacity_and_hasher(
+                $crate::__count!($($k)*),
+                $h,
+            );
+            $(map.insert($k as _, $v as _);)*
+            map
+        }
+    };
+    {@list capacity: $c: expr; $($k: expr => $v: expr),* $(,)?} => {
+        {
+            let mut map = ::std::collections::HashMap::with_capacity(
+                ::core::cmp::max($c, $crate::__count!($($k)*)),
+            );
+            $(map.insert($k as _, $v as _);)*
+            map
+        }
+    };
+    {@list $($k: expr => $v: expr),* $(,)?} => {
+        ::std::collections::HashMap::from([$(($k as _, $v as _),)*])
+    };
+    {@entries hasher: $h: expr; capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut map = ::std::collections::HashMap::with_capacity_and_hasher($c, $h);
+            $crate::__entries!(@map map [as _]; $($t)*);
+            map
+        }
+    };
+    {@entries hasher: $h: expr; $($t: tt)*} => {
+        {
+            let mut map = ::std::collections::HashMap::with_hasher($h);
+            $crate::__entries!(@map map [as _]; $($t)*);
+            map
+        }
+    };
+    {@entries capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut map = ::std::collections::HashMap::with_capacity($c);
+            $crate::__entries!(@map map [as _]; $($t)*);
+            map
+        }
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut map = ::std::collections::HashMap::new();
+            $crate::__entries!(@map map [as _]; $($t)*);
+            map
+        }
+    };
+    {capacity: $c: expr; hasher: $h: expr; $($t: tt)*} => {
+        $crate::hash_map_e!{hasher: $h; capacity: $c; $($t)*}
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(hash_map_e map []; $($t)*)
     };
 }
 
@@ -120,467 +373,540 @@ macro_rules! btree_map_e {
 /// assert_eq!(x.len(), 4);
 /// ```
 ///
-#[macro_export]
-macro_rules! hash_set {
-    {$($v: expr),* $(,)?} => {
-        ::std::collections::HashSet::from([$($v,)*])
-    };
-}
-
-/// Explicitly typed equivalent of [`hash_set!`].
-///
-/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+/// # Custom Hashers
 ///
-/// # Examples
+/// If the first clause of the macro is `hasher: expr;`, the set is created
+/// with [`HashSet::with_capacity_and_hasher`](::std::collections::HashSet::with_capacity_and_hasher),
+/// using the provided [`BuildHasher`](::std::hash::BuildHasher):
 ///
 /// ```rust
-/// use std::collections::HashSet;
-///
-/// use map_macro::hash_set_e;
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::BuildHasherDefault;
 ///
-/// enum Foo { A, B, C, D }
+/// use map_macro::hash_set;
 ///
-/// let x: HashSet<u8> = hash_set_e! { Foo::A, Foo::B, Foo::C, Foo::C, Foo::D };
+/// let x = hash_set! {
+///     hasher: BuildHasherDefault::<DefaultHasher>::default();
+///     1, 2, 3, 3, 4
+/// };
 ///
 /// assert_eq!(x.len(), 4);
 /// ```
 ///
-#[macro_export]
-macro_rules! hash_set_e {
-    {$($v: expr),* $(,)?} => {
-        ::std::collections::HashSet::from([$($v as _,)*])
-    };
-}
-
-/// Macro for creating a [`BTreeSet`](::std::collections::BTreeSet).
-///
-/// Syntactic sugar for [`BTreeSet::from`](::std::collections::BTreeSet::from).
+/// # Capacity
 ///
-/// # Examples
+/// The set is created with just enough capacity for the provided
+/// elements.
+/// If you intend to add more elements to it later on, you can reserve
+/// additional capacity up front with a leading `capacity: expr;` clause.
+/// The set is then created with a capacity of at least `expr`:
 ///
 /// ```rust
-/// use map_macro::btree_set;
+/// use map_macro::hash_set;
 ///
-/// let x = btree_set! { 1, 2, 3, 3, 4 };
+/// let x = hash_set! { capacity: 10; 1, 2, 3, 3, 4 };
 ///
 /// assert_eq!(x.len(), 4);
+/// assert!(x.capacity() >= 10);
 /// ```
 ///
-#[macro_export]
-macro_rules! btree_set {
-    {$($v: expr),* $(,)?} => {
-        ::std::collections::BTreeSet::from([$($v,)*])
-    };
-}
-
-/// Explicitly typed equivalent of [`btree_set!`].
+/// The `capacity` and `hasher` clauses can be combined in any order.
 ///
-/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+/// # Comprehensions
 ///
-/// # Examples
+/// Elements can also be computed from iterators with comprehension clauses,
+/// similar to Python's set comprehensions.
+/// An `element for pattern in iterator` entry inserts an element for every
+/// element of the iterator.
+/// It can be followed by an `if condition` filtering the elements, as well as
+/// by further `for` clauses, which are nested in the order they appear in.
+/// Comprehensions can be mixed with literal elements:
 ///
 /// ```rust
-/// use std::collections::BTreeSet;
+/// use map_macro::hash_set;
 ///
-/// use map_macro::btree_set_e;
+/// let remainders = hash_set! { 42, x % 3 for x in 0..10 };
 ///
-/// enum Foo { A, B, C, D }
+/// assert_eq!(remainders.len(), 4);
 ///
-/// let x: BTreeSet<u8> = btree_set_e! { Foo::A, Foo::B, Foo::C, Foo::C, Foo::D };
+/// let products = hash_set! { a * b for a in 1..4 for b in 1..4 if a != b };
 ///
-/// assert_eq!(x.len(), 4);
+/// assert!(products.contains(&6));
+/// assert!(!products.contains(&9));
 /// ```
 ///
-#[macro_export]
-macro_rules! btree_set_e {
-    {$($v: expr),* $(,)?} => {
-        ::std::collections::BTreeSet::from([$($v as _,)*])
-    };
-}
-
-/// Macro for creating a [`VecDeque`](::std::collections::VecDeque).
+/// # Spread
 ///
-/// Follows the same syntax as the [`vec!`](::std::vec!) macro.
+/// Like [`hash_map!`](crate::hash_map#spread), an entry written as
+/// `..iterable` inserts all elements of `iterable`, reserving capacity for
+/// them first:
 ///
-/// # Examples
+/// ```rust
+/// use map_macro::hash_set;
 ///
-/// ```
-/// use map_macro::vec_deque;
+/// let base = hash_set! { "read", "write" };
+///
+/// let permissions = hash_set! { ..base, "execute" };
 ///
-/// let v = vec_deque![0, 1, 2, 3];
-/// let v = vec_deque![0; 4];
+/// assert_eq!(permissions.len(), 3);
 /// ```
 ///
-#[macro_export]
-macro_rules! vec_deque {
-    {$v: expr; $c: expr} => {
-        {
-            let mut vec = ::std::collections::VecDeque::with_capacity($c);
-
-            for _ in 0..$c {
-                vec.push_back($v);
-            }
-
-            vec
-        }
-    };
-    {$($v: expr),* $(,)?} => {
-        ::std::collections::VecDeque::from([$($v,)*])
-    };
-}
-
-/// Explicitly typed equivalent of [`vec_deque!`].
+/// # Conditional Elements
 ///
-/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+/// Like [`hash_map!`](crate::hash_map#conditional-entries), elements can be
+/// followed by an `if` guard or annotated with attributes like
+/// `#[cfg(...)]`.
+/// Optional elements can be spread, because [`Option`] implements
+/// [`IntoIterator`]:
 ///
-/// # Examples
+/// ```rust
+/// use map_macro::hash_set;
 ///
-/// ```
-/// use std::collections::VecDeque;
-/// use std::fmt::Debug;
+/// let extra: Option<&str> = Some("admin");
 ///
-/// use map_macro::vec_deque_e;
+/// let roles = hash_set! {
+///     "user",
+///     "tester" if cfg!(debug_assertions),
+///     #[cfg(unix)]
+///     "unix",
+///     ..extra,
+/// };
 ///
-/// let v: VecDeque<&dyn Debug> = vec_deque_e![&0, &1, &2, &3];
-/// let v: VecDeque<&dyn Debug> = vec_deque_e![&0; 4];
+/// assert!(roles.contains("admin"));
 /// ```
 ///
-#[macro_export]
-macro_rules! vec_deque_e {
-    {$v: expr; $c: expr} => {
-        {
-            let mut vec = ::std::collections::VecDeque::with_capacity($c);
-
-            for _ in 0..$c {
-                vec.push_back($v as _);
-            }
-
-            vec
-        }
-    };
-    {$($v: expr),* $(,)?} => {
-        ::std::collections::VecDeque::from([$($v as _,)*])
-    };
-}
-
-/// Macro for creating a [`LinkedList`](::std::collections::LinkedList).
+/// # Ranges
 ///
-/// Follows the same syntax as the [`vec!`](::std::vec!) macro.
+/// A range whose start is a literal, like `1..=10` or `'a'..n`, inserts all
+/// items of the range, as if it were [spread](crate::hash_set#spread):
 ///
-/// # Examples
+/// ```rust
+/// use map_macro::hash_set;
 ///
-/// ```
-/// use map_macro::linked_list;
+/// let ports = hash_set! { 20..=23, 80, 8000..8080 };
 ///
-/// let v = linked_list![0, 1, 2, 3];
-/// let v = linked_list![0; 4];
+/// assert_eq!(ports.len(), 85);
 /// ```
 ///
+/// Other ranges, like `start..end` or `(1..3)`, are inserted as elements.
+///
 #[macro_export]
-macro_rules! linked_list {
-    {$v: expr; $c: expr} => {
+macro_rules! hash_set {
+    {@list hasher: $h: expr; capacity: $c: expr; $($v: expr),* $(,)?} => {
         {
-            let mut ll = ::std::collections::LinkedList::new();
-
-            for _ in 0..$c {
-                ll.push_back($v);
-            }
-
-            ll
+            let mut set = ::std::collections::HashSet::with_capacity_and_hasher(
+                ::core::cmp::max($c, $crate::__count!($($v)*)),
+                $h,
+            );
+            $(set.insert($v);)*
+            set
         }
     };
-    {$($v: expr),* $(,)?} => {
-        ::std::collections::LinkedList::from([$($v,)*])
+    {@list hasher: $h: expr; $($v: expr),* $(,)?} => {
+        {
+            let mut set = ::std::collections::HashSet::with_capacity_and_hasher(
+                $crate::__count!($($v)*),
+                $h,
+            );
+            $(set.insert($v);)*
+            set
+        }
     };
-}
-
-/// Explicitly typed equivalent of [`linked_list!`].
-///
-/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
-///
-/// # Examples
-///
-/// ```
-/// use std::collections::LinkedList;
-/// use std::fmt::Debug;
-///
-/// use map_macro::linked_list_e;
-///
-/// let v: LinkedList<&dyn Debug> = linked_list_e![&0, &1, &2, &3];
-/// let v: LinkedList<&dyn Debug> = linked_list_e![&0; 4];
-/// ```
-///
-#[macro_export]
-macro_rules! linked_list_e {
-    {$v: expr; $c: expr} => {
+    {@list capacity: $c: expr; $($v: expr),* $(,)?} => {
         {
-            let mut ll = ::std::collections::LinkedList::new();
-
-            for _ in 0..$c {
-                ll.push_back($v as _);
-            }
-
-            ll
+            let mut set = ::std::collections::HashSet::with_capacity(
+                ::core::cmp::max($c, $crate::__count!($($v)*)),
+            );
+            $(set.insert($v);)*
+            set
         }
     };
-    {$($v: expr),* $(,)?} => {
-        ::std::collections::LinkedList::from([$($v as _,)*])
+    {@list $($v: expr),* $(,)?} => {
+        ::std::collections::HashSet::from([$($v,)*])
     };
-}
-
-/// Macro for creating a [`BinaryHeap`](::std::collections::BinaryHeap).
-///
-/// Follows the same syntax as the [`vec!`](::std::vec!) macro.
-///
-/// # Examples
-///
-/// ```
-/// use map_macro::binary_heap;
-///
-/// let v = binary_heap![0, 1, 2, 3];
-/// let v = binary_heap![0; 4];
-/// ```
-///
-#[macro_export]
-macro_rules! binary_heap {
-    {$v: expr; $c: expr} => {
+    {@entries hasher: $h: expr; capacity: $c: expr; $($t: tt)*} => {
         {
-            let mut bh = ::std::collections::BinaryHeap::with_capacity($c);
-
-            for _ in 0..$c {
-                bh.push($v);
-            }
-
-            bh
+            let mut set = ::std::collections::HashSet::with_capacity_and_hasher($c, $h);
+            $crate::__entries!(@set set []; $($t)*);
+            set
+        }
+    };
+    {@entries hasher: $h: expr; $($t: tt)*} => {
+        {
+            let mut set = ::std::collections::HashSet::with_hasher($h);
+            $crate::__entries!(@set set []; $($t)*);
+            set
+        }
+    };
+    {@entries capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut set = ::std::collections::HashSet::with_capacity($c);
+            $crate::__entries!(@set set []; $($t)*);
+            set
+        }
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut set = ::std::collections::HashSet::new();
+            $crate::__entries!(@set set []; $($t)*);
+            set
         }
     };
-    {$($v: expr),* $(,)?} => {
-        ::std::collections::BinaryHeap::from([$($v,)*])
+    {capacity: $c: expr; hasher: $h: expr; $($t: tt)*} => {
+        $crate::hash_set!{hasher: $h; capacity: $c; $($t)*}
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(hash_set set []; $($t)*)
     };
 }
 
-/// Explicitly typed equivalent of [`binary_heap!`].
+/// Explicitly typed equivalent of [`hash_set!`].
 ///
 /// See the [explicity typed macros](crate#explicitly-typed-macros) section.
 ///
 /// # Examples
 ///
+/// ```rust
+/// use std::collections::HashSet;
+///
+/// use map_macro::hash_set_e;
+///
+/// enum Foo { A, B, C, D }
+///
+/// let x: HashSet<u8> = hash_set_e! { Foo::A, Foo::B, Foo::C, Foo::C, Foo::D };
+///
+/// assert_eq!(x.len(), 4);
 /// ```
-/// use std::collections::BinaryHeap;
 ///
-/// use map_macro::binary_heap_e;
+/// Supports the same [`hasher`](hash_set#custom-hashers) and
+/// [`capacity`](hash_set#capacity) clauses, as well as the same
+/// [comprehensions](hash_set#comprehensions), [spread](hash_set#spread)
+/// and [conditional](hash_set#conditional-elements) elements as
+/// [`hash_set!`]:
+///
+/// ```rust
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::collections::HashSet;
+/// use std::hash::BuildHasherDefault;
+///
+/// use map_macro::hash_set_e;
 ///
 /// enum Foo { A, B, C, D }
 ///
-/// let v: BinaryHeap<u8> = binary_heap_e![Foo::A, Foo::B, Foo::C, Foo::D];
-/// let v: BinaryHeap<u8> = binary_heap_e![Foo::A; 4];
+/// let x: HashSet<u8, BuildHasherDefault<DefaultHasher>> = hash_set_e! {
+///     hasher: Default::default();
+///     Foo::A, Foo::B, Foo::C, Foo::C, Foo::D
+/// };
+///
+/// assert_eq!(x.len(), 4);
 /// ```
 ///
+/// **Note:** the elements of spread sources and [ranges](hash_set#ranges)
+/// are not cast, so they must already have the element type of the set.
+///
 #[macro_export]
-macro_rules! binary_heap_e {
-    {$v: expr; $c: expr} => {
+macro_rules! hash_set_e {
+    {@list hasher: $h: expr; capacity: $c: expr; $($v: expr),* $(,)?} => {
         {
-            let mut bh = ::std::collections::BinaryHeap::with_capacity($c);
-
-            for _ in 0..$c {
-                bh.push($v as _);
-            }
-
-            bh
+            let mut set = ::std::collections::HashSet::with_capacity_and_hasher(
+                ::core::cmp::max($c, $crate::__count!($($v)*)),
+                $h,
+            );
+            $(set.insert($v as _);)*
+            set
+        }
+    };
+    {@list hasher: $h: expr; $($v: expr),* $(,)?} => {
+        {
+            let mut set = ::std::collections::HashSet::with_capacity_and_hasher(
+                $crate::__count!($($v)*),
+                $h,
+            );
+            $(set.insert($v as _);)*
+            set
+        }
+    };
+    {@list capacity: $c: expr; $($v: expr),* $(,)?} => {
+        {
+            let mut set = ::std::collections::HashSet::with_capacity(
+                ::core::cmp::max($c, $crate::__count!($($v)*)),
+            );
+            $(set.insert($v as _);)*
+            set
+        }
+    };
+    {@list $($v: expr),* $(,)?} => {
+        ::std::collections::HashSet::from([$($v as _,)*])
+    };
+    {@entries hasher: $h: expr; capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut set = ::std::collections::HashSet::with_capacity_and_hasher($c, $h);
+            $crate::__entries!(@set set [as _]; $($t)*);
+            set
         }
     };
-    {$($v: expr),* $(,)?} => {
-        ::std::collections::BinaryHeap::from([$($v as _,)*])
+    {@entries hasher: $h: expr; $($t: tt)*} => {
+        {
+            let mut set = ::std::collections::HashSet::with_hasher($h);
+            $crate::__entries!(@set set [as _]; $($t)*);
+            set
+        }
+    };
+    {@entries capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut set = ::std::collections::HashSet::with_capacity($c);
+            $crate::__entries!(@set set [as _]; $($t)*);
+            set
+        }
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut set = ::std::collections::HashSet::new();
+            $crate::__entries!(@set set [as _]; $($t)*);
+            set
+        }
+    };
+    {capacity: $c: expr; hasher: $h: expr; $($t: tt)*} => {
+        $crate::hash_set_e!{hasher: $h; capacity: $c; $($t)*}
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(hash_set_e set []; $($t)*)
     };
 }
 
-/// Version of the [`vec!`](::std::vec!) macro where the value does not have to implement [`Clone`].
-///
-/// Useful for unclonable types or where `Clone` is exerting undesired behaviour.
+/// Macro for creating a [`HashMap`](::std::collections::HashMap) that maps
+/// each key to a collection of values.
 ///
-/// # Uncloneable Types
+/// Unlike [`hash_map!`], entries with the same key don't overwrite each
+/// other.
+/// Instead, their values are collected into an inner collection, which is a
+/// [`Vec`](::std::vec::Vec) by default.
+/// Multiple values can be provided at once by writing them in brackets:
 ///
-/// When using `vec![x; count]`, the type of `x` has to implement `Clone`, because
-/// `x` is cloned `count - 1` times into all the vector elements except the first one.
-/// For example, calling `vec!` will result in a panic during compile time here,
-/// because `UnclonableWrapper` is not cloneable:
+/// ```rust
+/// use map_macro::hash_multimap;
 ///
-/// ```compile_fail
-/// struct UnclonableWrapper(u8);
+/// let routes = hash_multimap! {
+///     "/" => "index",
+///     "/users" => ["list_users", "create_user"],
+///     "/" => "health",
+/// };
 ///
-/// let x = vec![UnclonableWrapper(0); 5];
+/// assert_eq!(routes["/"], vec!["index", "health"]);
+/// assert_eq!(routes["/users"], vec!["list_users", "create_user"]);
 /// ```
 ///
-/// The `vec_no_clone!` macro takes a different approach.
-/// Instead of cloning `UnclonableWrapper(0)`, it treats it as an
-/// [expression](https://doc.rust-lang.org/reference/expressions.html) which is
-/// called 5 times in this case.
-/// So 5 independent `UnclonableWrapper` objects, each with its own location in
-/// memory, are created:
+/// Writing `k => []` adds the key with an empty collection.
+///
+/// # Inner Collection
+///
+/// Any collection that implements [`Default`] and
+/// [`Extend`](::core::iter::Extend) can hold the values.
+/// It is chosen with a leading `inner: type;` clause.
+/// `_` can be used for the parts of the type the compiler is able to infer:
 ///
 /// ```rust
-/// use map_macro::vec_no_clone;
+/// use std::collections::BTreeSet;
 ///
-/// struct UnclonableWrapper(u8);
+/// use map_macro::hash_multimap;
 ///
-/// let x = vec_no_clone![UnclonableWrapper(0); 5];
+/// let routes = hash_multimap! {
+///     inner: BTreeSet<_>;
+///     "/" => ["index", "health"],
+///     "/" => "index",
+/// };
 ///
-/// assert_eq!(x.len(), 5);
+/// assert_eq!(routes["/"].len(), 2);
 /// ```
 ///
-/// A real-world example where `vec_no_clone!` is a useful drop-in replacement
-/// for `vec!` are [atomic types](::std::sync::atomic), which are not clonable:
+/// If the type of the map is already known, the whole inner collection
+/// can be inferred with `inner: _;`:
 ///
 /// ```rust
-/// use std::sync::atomic::AtomicU8;
+/// use std::collections::{HashMap, HashSet};
 ///
-/// use map_macro::vec_no_clone;
+/// use map_macro::hash_multimap;
 ///
-/// let x = vec_no_clone![AtomicU8::new(0); 5];
+/// let routes: HashMap<&str, HashSet<&str>> = hash_multimap! {
+///     inner: _;
+///     "/" => "index",
+///     "/" => "index",
+/// };
 ///
-/// assert_eq!(x.len(), 5);
+/// assert_eq!(routes["/"].len(), 1);
 /// ```
 ///
-/// # Types where `Clone` exerts the wrong Behaviour
+#[macro_export]
+macro_rules! hash_multimap {
+    {inner: $inner: ty; $($t: tt)*} => {
+        {
+            let mut map = ::std::collections::HashMap::new();
+            $crate::__multimap!(map $inner, []; $($t)*);
+            map
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::hash_multimap!{inner: $crate::__private::alloc::vec::Vec<_>; $($t)*}
+    };
+}
+
+/// Explicitly typed equivalent of [`hash_multimap!`].
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// Supports the same [`inner`](hash_multimap#inner-collection) clause as
+/// [`hash_multimap!`].
 ///
-/// `vec_no_clone!` is not only useful for unclonable types, but also for types
-/// where cloning them is not what you want.
-/// The best example would be a reference counted pointer [`Rc`](::std::rc::Rc).
-/// When you clone an `Rc`, a new instance referencing the same location in memory
-/// is created.
-/// If you'd rather have multiple independent reference counted pointers to
-/// different memory locations, you can use `vec_no_clone!` as well:
+/// # Examples
 ///
 /// ```rust
-/// use map_macro::vec_no_clone;
-///
-/// use std::cell::RefCell;
-/// use std::rc::Rc;
-///
-/// // simply clones the reference counted pointer for each element that
-/// // is not the first
-/// let shared_vec = vec![Rc::new(RefCell::new(0)); 2];
-/// {
-///     let mut first = shared_vec[0].borrow_mut();
-///     *first += 1;
-/// }
-///
-/// assert_eq!(*shared_vec[0].borrow(), 1);
-///
-/// // the second element is a clone of the reference counted pointer at
-/// // the first element of the vector, referencing the same address in
-/// // memory, therefore being mutated as well
-/// assert_eq!(*shared_vec[1].borrow(), 1);
-///
-/// // the `vec_no_clone!` macro does not clone the object created by the
-/// // first expression but instead calls the expression for each element
-/// // in the vector, creating two independent objects, each with their
-/// // own address in memory
-/// let unshared_vec = vec_no_clone![Rc::new(RefCell::new(0)); 2];
-///
-/// {
-///     let mut first = unshared_vec[0].borrow_mut();
-///     *first += 1;
-/// }
-///
-/// assert_eq!(*unshared_vec[0].borrow(), 1);
-///
-/// // the second element is not the same cloned reference counted
-/// // pointer as it would be if it were constructed with the `vec!` macro
-/// // from the standard library like it was above, therefore it is not
-/// // mutated
-/// assert_eq!(*unshared_vec[1].borrow(), 0);
-/// ```
+/// use std::collections::HashMap;
+/// use std::fmt::Debug;
 ///
-/// # Drawbacks of using Expressions
+/// use map_macro::hash_multimap_e;
 ///
-/// Since `vec_no_clone!` treats the value as an expression, you must provide the
-/// initialization as input directly.
-/// This, for example, won't work:
+/// let values: HashMap<&str, Vec<&dyn Debug>> = hash_multimap_e! {
+///     "numbers" => [&1, &2.5],
+///     "strings" => &"Hello",
+/// };
 ///
-/// ```compile_fail
-/// use map_macro::vec_no_clone;
+/// assert_eq!(values["numbers"].len(), 2);
+/// ```
 ///
-/// struct UnclonableWrapper(u8);
+#[macro_export]
+macro_rules! hash_multimap_e {
+    {inner: $inner: ty; $($t: tt)*} => {
+        {
+            let mut map = ::std::collections::HashMap::new();
+            $crate::__multimap!(map $inner, [as _]; $($t)*);
+            map
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::hash_multimap_e!{inner: $crate::__private::alloc::vec::Vec<_>; $($t)*}
+    };
+}
+
+/// Macro for creating a [`BiHashMap`](crate::BiHashMap).
+///
+/// Every `left => right` entry pairs a left value with a right value, which
+/// can then be looked up from either side:
+///
+/// ```rust
+/// use map_macro::bi_map;
 ///
-/// let a = UnclonableWrapper(0);
+/// let codecs = bi_map! {
+///     "h264" => 27,
+///     "hevc" => 173,
+///     "av1" => 1,
+/// };
 ///
-/// // a will have moved into the first element of x, raising a compile
-/// // time error for the second element.
-/// let x = vec_no_clone![a; 5];
+/// assert_eq!(codecs.get_by_left("av1"), Some(&1));
+/// assert_eq!(codecs.get_by_right(&173), Some(&"hevc"));
 /// ```
 ///
-/// # Processing Lists of Elements
+/// # Duplicates
 ///
-/// You can also use the macro with a list of elements, like `vec!`.
-/// In fact, `vec_no_clone!` falls back to `vec!` in this case:
+/// Each value may occur only once on its side, and the macro panics when it
+/// encounters a repeated value.
+/// If all values are literals, a repeated value additionally triggers the
+/// `unreachable_patterns` lint as an error, so it usually fails to compile:
 ///
-/// ```rust
-/// use map_macro::vec_no_clone;
+/// ```compile_fail
+/// use map_macro::bi_map;
+///
+/// let codecs = bi_map! {
+///     "h264" => 27,
+///     "hevc" => 27,
+/// };
+/// ```
 ///
-/// let v1 = vec_no_clone![0, 1, 2, 3];
-/// let v2 = vec![0, 1, 2, 3];
+/// The lint can be weakened by `--cap-lints`, which Cargo passes when building
+/// dependencies, so the panic is what guarantees unique values:
 ///
-/// assert_eq!(v1, v2);
+/// ```should_panic
+/// use map_macro::bi_map;
 ///
-/// let v1: Vec<u8> = vec_no_clone![];
-/// let v2: Vec<u8> = vec![];
+/// let h264 = String::from("h264");
 ///
-/// assert_eq!(v1, v2);
+/// let codecs = bi_map! {
+///     h264.clone() => 27,
+///     h264 => 173,
+/// };
 /// ```
 ///
 #[macro_export]
-macro_rules! vec_no_clone {
-    {$v: expr; $c: expr} => {
-
+macro_rules! bi_map {
+    {$($l: literal => $r: literal),* $(,)?} => {
         {
-            let mut vec = Vec::with_capacity($c);
-
-            for _ in 0..$c {
-                vec.push($v);
-            }
-
-            vec
+            $crate::__unique_literals!($($l),*);
+            $crate::__unique_literals!($($r),*);
+            $crate::__bi_map!([] $($l => $r),*)
+        }
+    };
+    {$($l: literal => $r: expr),* $(,)?} => {
+        {
+            $crate::__unique_literals!($($l),*);
+            $crate::__bi_map!([] $($l => $r),*)
         }
     };
-    {$($v: expr),* $(,)?} => {
+    {$($l: expr => $r: literal),* $(,)?} => {
         {
-            vec![$($v),*]
+            $crate::__unique_literals!($($r),*);
+            $crate::__bi_map!([] $($l => $r),*)
         }
     };
+    {$($l: expr => $r: expr),* $(,)?} => {
+        $crate::__bi_map!([] $($l => $r),*)
+    };
 }
 
-/// Explicitly typed equivalent of [`vec_no_clone!`].
+/// Explicitly typed equivalent of [`bi_map!`].
 ///
 /// See the [explicity typed macros](crate#explicitly-typed-macros) section.
 ///
+/// Duplicates are detected the [same way](bi_map#duplicates) as by
+/// [`bi_map!`].
+/// Literals are compared before they are cast, so all values of a side
+/// consisting only of literals must have the same type.
+///
 /// # Examples
 ///
-/// ```
-/// use std::fmt::Display;
+/// ```rust
+/// use map_macro::{bi_map_e, BiHashMap};
 ///
-/// use map_macro::vec_no_clone_e;
+/// enum Codec { H264, Hevc }
 ///
-/// let v: Vec<&dyn Display> = vec_no_clone_e![&0; 4];
+/// let codecs: BiHashMap<u8, &str> = bi_map_e! {
+///     Codec::H264 => "h264",
+///     Codec::Hevc => "hevc",
+/// };
+///
+/// assert_eq!(codecs.get_by_right("hevc"), Some(&1));
 /// ```
 ///
 #[macro_export]
-macro_rules! vec_no_clone_e {
-    {$v: expr; $c: expr} => {
-
+macro_rules! bi_map_e {
+    {$($l: literal => $r: literal),* $(,)?} => {
         {
-            let mut vec = Vec::with_capacity($c);
-
-            for _ in 0..$c {
-                vec.push($v as _);
-            }
-
-            vec
+            $crate::__unique_literals!($($l),*);
+            $crate::__unique_literals!($($r),*);
+            $crate::__bi_map!([as _] $($l => $r),*)
+        }
+    };
+    {$($l: literal => $r: expr),* $(,)?} => {
+        {
+            $crate::__unique_literals!($($l),*);
+            $crate::__bi_map!([as _] $($l => $r),*)
         }
     };
-    {$($v: expr),* $(,)?} => {
+    {$($l: expr => $r: literal),* $(,)?} => {
         {
-            vec![$($v as _),*]
+            $crate::__unique_literals!($($r),*);
+            $crate::__bi_map!([as _] $($l => $r),*)
         }
     };
+    {$($l: expr => $r: expr),* $(,)?} => {
+        $crate::__bi_map!([as _] $($l => $r),*)
+    };
 }
@@ -0,0 +1,210 @@
+/// Macro for fallibly creating a [`HashMap`](::std::collections::HashMap).
+///
+/// Accepts the `key => value` entries of [`hash_map!`](crate::hash_map), but
+/// returns a [`DuplicateKeyError`](crate::DuplicateKeyError) if a key occurs
+/// more than once, instead of keeping the last entry with the key.
+#[cfg_attr(
+    feature = "checked",
+    doc = "Unlike the [`checked`](crate::checked) macros, duplicates are detected at"
+)]
+#[cfg_attr(
+    not(feature = "checked"),
+    doc = "Unlike the `checked` macros, duplicates are detected at"
+)]
+/// runtime, so keys can be arbitrary expressions.
+///
+/// The `try_*` macros don't support the [`hasher`](crate::hash_map#custom-hashers)
+/// and [`capacity`](crate::hash_map#capacity) clauses, nor the spread,
+/// conditional, shorthand and comprehension entries of `hash_map!`, because
+/// the indices of a `DuplicateKeyError` refer to the listed entries.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::try_hash_map;
+///
+/// let hello = try_hash_map! {
+///     "en" => "Hello",
+///     "de" => "Hallo",
+/// };
+///
+/// assert_eq!(hello.unwrap()["de"], "Hallo");
+///
+/// let locale = String::from("en");
+///
+/// let err = try_hash_map! {
+///     "en".to_owned() => "Hello",
+///     "de".to_owned() => "Hallo",
+///     locale => "Hi",
+/// }
+/// .unwrap_err();
+///
+/// assert_eq!(err.first_index(), 0);
+/// assert_eq!(err.second_index(), 2);
+/// assert_eq!(err.key(), "en");
+/// ```
+///
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! try_hash_map {
+    {$($k: expr => $v: expr),* $(,)?} => {
+        $crate::__try_collect!(
+            @map [::std::collections::HashMap<_, usize>] [::std::collections::HashMap<_, _>];
+            $(($k, $v)),*
+        )
+    };
+}
+
+/// Explicitly typed equivalent of [`try_hash_map!`](crate::try_hash_map).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! try_hash_map_e {
+    {$($k: expr => $v: expr),* $(,)?} => {
+        $crate::__try_collect!(
+            @map [::std::collections::HashMap<_, usize>] [::std::collections::HashMap<_, _>];
+            $(($k as _, $v as _)),*
+        )
+    };
+}
+
+/// Macro for fallibly creating a [`BTreeMap`](::std::collections::BTreeMap).
+///
+/// Accepts the `key => value` entries of [`btree_map!`](crate::btree_map), but
+/// returns a [`DuplicateKeyError`](crate::DuplicateKeyError) if a key occurs
+/// more than once.
+/// See [`try_hash_map!`](crate::try_hash_map) for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::try_btree_map;
+///
+/// let err = try_btree_map! {
+///     "en" => "Hello",
+///     "de" => "Hallo",
+///     "en" => "Hi",
+/// }
+/// .unwrap_err();
+///
+/// assert_eq!(err.first_index(), 0);
+/// assert_eq!(err.second_index(), 2);
+/// ```
+///
+#[macro_export]
+macro_rules! try_btree_map {
+    {$($k: expr => $v: expr),* $(,)?} => {
+        $crate::__try_collect!(
+            @map [$crate::__private::alloc::collections::BTreeMap<_, usize>] [$crate::__private::alloc::collections::BTreeMap<_, _>];
+            $(($k, $v)),*
+        )
+    };
+}
+
+/// Explicitly typed equivalent of [`try_btree_map!`](crate::try_btree_map).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+#[macro_export]
+macro_rules! try_btree_map_e {
+    {$($k: expr => $v: expr),* $(,)?} => {
+        $crate::__try_collect!(
+            @map [$crate::__private::alloc::collections::BTreeMap<_, usize>] [$crate::__private::alloc::collections::BTreeMap<_, _>];
+            $(($k as _, $v as _)),*
+        )
+    };
+}
+
+/// Macro for fallibly creating a [`HashSet`](::std::collections::HashSet).
+///
+/// Accepts the plain elements of [`hash_set!`](crate::hash_set), but returns a
+/// [`DuplicateKeyError`](crate::DuplicateKeyError) if an element occurs more
+/// than once.
+/// See [`try_hash_map!`](crate::try_hash_map) for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::try_hash_set;
+///
+/// let err = try_hash_set! {
+///     "en", "de", "en",
+/// }
+/// .unwrap_err();
+///
+/// assert_eq!(err.first_index(), 0);
+/// assert_eq!(err.second_index(), 2);
+/// ```
+///
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! try_hash_set {
+    {$($v: expr),* $(,)?} => {
+        $crate::__try_collect!(
+            @set [::std::collections::HashMap<_, usize>] [::std::collections::HashSet<_>];
+            $($v),*
+        )
+    };
+}
+
+/// Explicitly typed equivalent of [`try_hash_set!`](crate::try_hash_set).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! try_hash_set_e {
+    {$($v: expr),* $(,)?} => {
+        $crate::__try_collect!(
+            @set [::std::collections::HashMap<_, usize>] [::std::collections::HashSet<_>];
+            $($v as _),*
+        )
+    };
+}
+
+/// Macro for fallibly creating a [`BTreeSet`](::std::collections::BTreeSet).
+///
+/// Accepts the plain elements of [`btree_set!`](crate::btree_set), but returns a
+/// [`DuplicateKeyError`](crate::DuplicateKeyError) if an element occurs more
+/// than once.
+/// See [`try_hash_map!`](crate::try_hash_map) for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::try_btree_set;
+///
+/// let err = try_btree_set! {
+///     "en", "de", "en",
+/// }
+/// .unwrap_err();
+///
+/// assert_eq!(err.first_index(), 0);
+/// assert_eq!(err.second_index(), 2);
+/// ```
+///
+#[macro_export]
+macro_rules! try_btree_set {
+    {$($v: expr),* $(,)?} => {
+        $crate::__try_collect!(
+            @set [$crate::__private::alloc::collections::BTreeMap<_, usize>] [$crate::__private::alloc::collections::BTreeSet<_>];
+            $($v),*
+        )
+    };
+}
+
+/// Explicitly typed equivalent of [`try_btree_set!`](crate::try_btree_set).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+#[macro_export]
+macro_rules! try_btree_set_e {
+    {$($v: expr),* $(,)?} => {
+        $crate::__try_collect!(
+            @set [$crate::__private::alloc::collections::BTreeMap<_, usize>] [$crate::__private::alloc::collections::BTreeSet<_>];
+            $($v as _),*
+        )
+    };
+}
@@ -0,0 +1,208 @@
+//! Macros for initializing [`arrayvec`] vectors.
+//!
+//! # Example
+//!
+//! ```
+//! use arrayvec::ArrayVec;
+//!
+//! use map_macro::arrayvec::array_vec;
+//!
+//! let v: ArrayVec<u8, 4> = array_vec![capacity: 4; 0, 1, 2];
+//!
+//! assert_eq!(v.len(), 3);
+//! assert_eq!(v.remaining_capacity(), 1);
+//! ```
+//!
+//! # Supported Versions of `arrayvec`
+//!
+//! The macros from this module support the `0.7` versions of `arrayvec`,
+//! which define the capacity of an `ArrayVec` with a const generic
+//! parameter.
+//!
+//! **Note:** to be compatible with all `0.7` versions of `arrayvec` at once,
+//! this crate doesn't re-export `arrayvec`.
+//! That means that (I) you need to specify it as a dependency yourself and
+//! (II) you can't rename it or the macros from this module won't be able to
+//! import the needed types, resulting in a compile-time error.
+//!
+
+/// Macro for creating an [`ArrayVec`](::arrayvec::ArrayVec).
+///
+/// Follows the same syntax as the [`vec_no_clone!`](crate::vec_no_clone)
+/// macro, which means that the repeat form `array_vec![expr; n]` evaluates
+/// `expr` `n` times instead of cloning it.
+/// The list form takes the capacity of the vector in a leading
+/// `capacity: N;` clause, while the repeat form takes it from the type of the
+/// vector.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::atomic::AtomicUsize;
+///
+/// use arrayvec::ArrayVec;
+///
+/// use map_macro::arrayvec::array_vec;
+///
+/// let v = array_vec![capacity: 4; 0, 1, 2, 3];
+///
+/// assert!(v.is_full());
+///
+/// let v: ArrayVec<AtomicUsize, 4> = array_vec![AtomicUsize::new(0); 2];
+///
+/// assert_eq!(v.len(), 2);
+/// ```
+///
+/// Like [`vec_no_clone!`](crate::vec_no_clone#index-aware-repetition), the
+/// repeat form accepts an index-aware element written as `|i| expr; n`:
+///
+/// ```rust
+/// use arrayvec::ArrayVec;
+///
+/// use map_macro::arrayvec::array_vec;
+///
+/// let v: ArrayVec<usize, 4> = array_vec![|i| i * 2; 4];
+///
+/// assert_eq!(v.as_slice(), &[0, 2, 4, 6]);
+/// ```
+///
+/// # Capacity
+///
+/// The capacity of the list form must be a constant expression.
+/// Providing more elements than the capacity fails to compile, which is also
+/// reported by `cargo check`:
+///
+/// ```compile_fail
+/// use map_macro::arrayvec::array_vec;
+///
+/// let v = array_vec![capacity: 2; 0, 1, 2];
+/// ```
+///
+/// The number of elements of the repeat form is only known at runtime.
+/// Exceeding the capacity with it panics before any element is created:
+///
+/// ```should_panic
+/// use arrayvec::ArrayVec;
+///
+/// use map_macro::arrayvec::array_vec;
+///
+/// let v: ArrayVec<u8, 2> = array_vec![0; 3];
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __av_array_vec {
+    [capacity: $n: expr; $($v: expr),* $(,)?] => {
+        $crate::__av_from_list!($n; $($v),*)
+    };
+    [|$i: pat| $v: expr; $c: expr] => {
+        {
+            let count = $c;
+            let mut vec = ::arrayvec::ArrayVec::new();
+
+            ::core::assert!(
+                count <= vec.capacity(),
+                "more elements provided than the capacity of the `ArrayVec`",
+            );
+
+            for $i in 0..count {
+                vec.push($v);
+            }
+
+            vec
+        }
+    };
+    [$v: expr; $c: expr] => {
+        {
+            let count = $c;
+            let mut vec = ::arrayvec::ArrayVec::new();
+
+            ::core::assert!(
+                count <= vec.capacity(),
+                "more elements provided than the capacity of the `ArrayVec`",
+            );
+
+            for _ in 0..count {
+                vec.push($v);
+            }
+
+            vec
+        }
+    };
+    [] => {
+        ::arrayvec::ArrayVec::new()
+    };
+    [$($v: expr),+ $(,)?] => {
+        ::core::compile_error!("the list form of `array_vec!` requires a `capacity: N;` clause")
+    };
+}
+
+/// Creates an `ArrayVec` with the capacity `$n` from a list of elements,
+/// failing to compile if there are more elements than `$n`.
+///
+/// The number of elements is compared to the capacity in a `const` item, so
+/// the error is raised by `cargo check` as well:
+///
+/// ```compile_fail
+/// let v = map_macro::__av_from_list!(2; 0, 1, 2);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __av_from_list {
+    ($n: expr; $($v: expr),*) => {{
+        const _: () = ::core::assert!(
+            $crate::__count!($($v)*) <= $n,
+            "more elements provided than the capacity of the `ArrayVec`",
+        );
+
+        #[allow(unused_mut)]
+        let mut vec = ::arrayvec::ArrayVec::<_, { $n }>::new();
+        $(vec.push($v);)*
+        vec
+    }};
+}
+
+/// Explicitly typed equivalent of [`array_vec!`](self::array_vec).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Debug;
+///
+/// use arrayvec::ArrayVec;
+///
+/// use map_macro::arrayvec::array_vec_e;
+///
+/// let v: ArrayVec<&dyn Debug, 2> = array_vec_e![capacity: 2; &"Hello", &1];
+///
+/// assert_eq!(v.len(), 2);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __av_array_vec_e {
+    [capacity: $n: expr; $($v: expr),* $(,)?] => {
+        $crate::__av_from_list!($n; $($v as _),*)
+    };
+    [|$i: pat| $v: expr; $c: expr] => {
+        $crate::arrayvec::array_vec![|$i| $v as _; $c]
+    };
+    [$v: expr; $c: expr] => {
+        $crate::arrayvec::array_vec![$v as _; $c]
+    };
+    [] => {
+        ::arrayvec::ArrayVec::new()
+    };
+    [$($v: expr),+ $(,)?] => {
+        ::core::compile_error!("the list form of `array_vec_e!` requires a `capacity: N;` clause")
+    };
+}
+
+#[doc(inline)]
+pub use __av_array_vec as array_vec;
+
+#[doc(inline)]
+pub use __av_array_vec_e as array_vec_e;
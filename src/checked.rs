@@ -0,0 +1,445 @@
+//! Macros that reject duplicate literal keys at compile time.
+//!
+//! The map and set macros of this crate silently keep the last entry if the
+//! same key occurs more than once, just like
+//! [`HashMap::from`](::std::collections::HashMap::from) does:
+//!
+//! ```
+//! use map_macro::hash_map;
+//!
+//! let hello = hash_map! {
+//!     "en" => "Hello",
+//!     "de" => "Hallo",
+//!     "en" => "Hi",
+//! };
+//!
+//! assert_eq!(hello["en"], "Hi");
+//! ```
+//!
+//! The macros from this module accept the same input as their unchecked
+//! counterparts, but fail to compile if a literal key occurs more than
+//! once, pointing at both offending entries:
+//!
+//! ```compile_fail
+//! use map_macro::checked::hash_map;
+//!
+//! let hello = hash_map! {
+//!     "en" => "Hello",
+//!     "de" => "Hallo",
+//!     "en" => "Hi",
+//! };
+//! ```
+//!
+//! Checked versions of the [`hashbrown`](crate::hashbrown) macros are
+//! provided by the [`checked::hashbrown`](self::hashbrown) module.
+//!
+//! # Detected Duplicates
+//!
+//! Only keys that can be compared without evaluating them are checked:
+//!
+//! * String and byte string literals are compared by value, so `"a"` and
+//!   `"\x61"` are duplicates.
+//!
+//! * Integer and byte literals are compared by value, so `97`, `0x61`,
+//!   `97u8` and `b'a'` are duplicates.
+//!
+//! * Character literals are compared by value.
+//!
+//! * Paths, like enum variants, constants or variables, are compared by how
+//!   they are spelled, so `Foo::A` and `self::Foo::A` are **not** duplicates.
+//!
+//! * [Shorthand](crate::hash_map#shorthand-entries) entries `ident` of maps
+//!   are keyed by the name of the identifier, so `width` and `"width"` are
+//!   duplicates:
+//!
+//! ```compile_fail
+//! use map_macro::checked::hash_map;
+//!
+//! let width = 10;
+//!
+//! let size = hash_map! { width, width };
+//! ```
+//!
+//! Keys that are any other kind of expression are never reported, and
+//! neither are the keys of comprehensions and of
+//! [guarded](crate::hash_map#conditional-entries) entries, which may exclude
+//! each other:
+//!
+//! ```rust
+//! use map_macro::checked::hash_map;
+//!
+//! let build = hash_map! {
+//!     "mode" => "debug" if cfg!(debug_assertions),
+//!     "mode" => "release" if !cfg!(debug_assertions),
+//! };
+//!
+//! assert_eq!(build.len(), 1);
+//! ```
+//!
+//! **Note:** the check is performed by a procedural macro.
+//! Enabling the `checked` feature therefore adds `map-macro-proc` as a
+//! dependency.
+//!
+
+#[cfg(feature = "hashbrown")]
+pub mod hashbrown;
+
+/// Checked equivalent of [`hash_map!`](crate::hash_map).
+///
+/// Fails to compile if the same literal key occurs more than once.
+/// See the [module level documentation](crate::checked) for which keys are
+/// checked.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::checked::hash_map;
+///
+/// let goodbye = hash_map! {
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+///     "fr" => "Au revoir",
+/// };
+/// ```
+///
+/// ```compile_fail
+/// use map_macro::checked::hash_map;
+///
+/// let goodbye = hash_map! {
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+///     "en" => "Bye",
+/// };
+/// ```
+///
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checked_hash_map {
+    {$($t: tt)*} => {
+        {
+            $crate::__private::assert_unique_keys!(map $($t)*);
+            $crate::hash_map!{$($t)*}
+        }
+    };
+}
+
+/// Checked equivalent of [`hash_map_e!`](crate::hash_map_e).
+///
+/// Fails to compile if the same literal key occurs more than once.
+/// See the [module level documentation](crate::checked) for which keys are
+/// checked.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Debug;
+///
+/// use std::collections::HashMap;
+///
+/// use map_macro::checked::hash_map_e;
+///
+/// let goodbye: HashMap<&str, &dyn Debug> = hash_map_e! {
+///     "en" => &"Goodbye",
+///     "de" => &"Auf Wiedersehen",
+///     "fr" => &"Au revoir",
+/// };
+/// ```
+///
+/// ```compile_fail
+/// use std::fmt::Debug;
+///
+/// use std::collections::HashMap;
+///
+/// use map_macro::checked::hash_map_e;
+///
+/// let goodbye: HashMap<&str, &dyn Debug> = hash_map_e! {
+///     "en" => &"Goodbye",
+///     "de" => &"Auf Wiedersehen",
+///     "en" => &"Bye",
+/// };
+/// ```
+///
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checked_hash_map_e {
+    {$($t: tt)*} => {
+        {
+            $crate::__private::assert_unique_keys!(map $($t)*);
+            $crate::hash_map_e!{$($t)*}
+        }
+    };
+}
+
+/// Checked equivalent of [`btree_map!`](crate::btree_map).
+///
+/// Fails to compile if the same literal key occurs more than once.
+/// See the [module level documentation](crate::checked) for which keys are
+/// checked.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::checked::btree_map;
+///
+/// let goodbye = btree_map! {
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+///     "fr" => "Au revoir",
+/// };
+/// ```
+///
+/// ```compile_fail
+/// use map_macro::checked::btree_map;
+///
+/// let goodbye = btree_map! {
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+///     "en" => "Bye",
+/// };
+/// ```
+///
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checked_btree_map {
+    {$($t: tt)*} => {
+        {
+            $crate::__private::assert_unique_keys!(map $($t)*);
+            $crate::btree_map!{$($t)*}
+        }
+    };
+}
+
+/// Checked equivalent of [`btree_map_e!`](crate::btree_map_e).
+///
+/// Fails to compile if the same literal key occurs more than once.
+/// See the [module level documentation](crate::checked) for which keys are
+/// checked.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Debug;
+///
+/// use std::collections::BTreeMap;
+///
+/// use map_macro::checked::btree_map_e;
+///
+/// let goodbye: BTreeMap<&str, &dyn Debug> = btree_map_e! {
+///     "en" => &"Goodbye",
+///     "de" => &"Auf Wiedersehen",
+///     "fr" => &"Au revoir",
+/// };
+/// ```
+///
+/// ```compile_fail
+/// use std::fmt::Debug;
+///
+/// use std::collections::BTreeMap;
+///
+/// use map_macro::checked::btree_map_e;
+///
+/// let goodbye: BTreeMap<&str, &dyn Debug> = btree_map_e! {
+///     "en" => &"Goodbye",
+///     "de" => &"Auf Wiedersehen",
+///     "en" => &"Bye",
+/// };
+/// ```
+///
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checked_btree_map_e {
+    {$($t: tt)*} => {
+        {
+            $crate::__private::assert_unique_keys!(map $($t)*);
+            $crate::btree_map_e!{$($t)*}
+        }
+    };
+}
+
+/// Checked equivalent of [`hash_set!`](crate::hash_set).
+///
+/// Fails to compile if the same literal key occurs more than once.
+/// See the [module level documentation](crate::checked) for which keys are
+/// checked.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::checked::hash_set;
+///
+/// let x = hash_set! { 1, 2, 3, 4 };
+///
+/// assert_eq!(x.len(), 4);
+/// ```
+///
+/// ```compile_fail
+/// use map_macro::checked::hash_set;
+///
+/// let x = hash_set! { 1, 2, 3, 3, 4 };
+/// ```
+///
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checked_hash_set {
+    {$($t: tt)*} => {
+        {
+            $crate::__private::assert_unique_keys!(set $($t)*);
+            $crate::hash_set!{$($t)*}
+        }
+    };
+}
+
+/// Checked equivalent of [`hash_set_e!`](crate::hash_set_e).
+///
+/// Fails to compile if the same literal key occurs more than once.
+/// See the [module level documentation](crate::checked) for which keys are
+/// checked.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashSet;
+///
+/// use map_macro::checked::hash_set_e;
+///
+/// enum Foo { A, B, C, D }
+///
+/// let x: HashSet<u8> = hash_set_e! { Foo::A, Foo::B, Foo::C, Foo::D };
+///
+/// assert_eq!(x.len(), 4);
+/// ```
+///
+/// ```compile_fail
+/// use std::collections::HashSet;
+///
+/// use map_macro::checked::hash_set_e;
+///
+/// enum Foo { A, B, C, D }
+///
+/// let x: HashSet<u8> = hash_set_e! { Foo::A, Foo::B, Foo::C, Foo::C, Foo::D };
+/// ```
+///
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checked_hash_set_e {
+    {$($t: tt)*} => {
+        {
+            $crate::__private::assert_unique_keys!(set $($t)*);
+            $crate::hash_set_e!{$($t)*}
+        }
+    };
+}
+
+/// Checked equivalent of [`btree_set!`](crate::btree_set).
+///
+/// Fails to compile if the same literal key occurs more than once.
+/// See the [module level documentation](crate::checked) for which keys are
+/// checked.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::checked::btree_set;
+///
+/// let x = btree_set! { 1, 2, 3, 4 };
+///
+/// assert_eq!(x.len(), 4);
+/// ```
+///
+/// ```compile_fail
+/// use map_macro::checked::btree_set;
+///
+/// let x = btree_set! { 1, 2, 3, 3, 4 };
+/// ```
+///
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checked_btree_set {
+    {$($t: tt)*} => {
+        {
+            $crate::__private::assert_unique_keys!(set $($t)*);
+            $crate::btree_set!{$($t)*}
+        }
+    };
+}
+
+/// Checked equivalent of [`btree_set_e!`](crate::btree_set_e).
+///
+/// Fails to compile if the same literal key occurs more than once.
+/// See the [module level documentation](crate::checked) for which keys are
+/// checked.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeSet;
+///
+/// use map_macro::checked::btree_set_e;
+///
+/// enum Foo { A, B, C, D }
+///
+/// let x: BTreeSet<u8> = btree_set_e! { Foo::A, Foo::B, Foo::C, Foo::D };
+///
+/// assert_eq!(x.len(), 4);
+/// ```
+///
+/// ```compile_fail
+/// use std::collections::BTreeSet;
+///
+/// use map_macro::checked::btree_set_e;
+///
+/// enum Foo { A, B, C, D }
+///
+/// let x: BTreeSet<u8> = btree_set_e! { Foo::A, Foo::B, Foo::C, Foo::C, Foo::D };
+/// ```
+///
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checked_btree_set_e {
+    {$($t: tt)*} => {
+        {
+            $crate::__private::assert_unique_keys!(set $($t)*);
+            $crate::btree_set_e!{$($t)*}
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use __checked_hash_map as hash_map;
+
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use __checked_hash_map_e as hash_map_e;
+
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use __checked_btree_map as btree_map;
+
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use __checked_btree_map_e as btree_map_e;
+
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use __checked_hash_set as hash_set;
+
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use __checked_hash_set_e as hash_set_e;
+
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use __checked_btree_set as btree_set;
+
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use __checked_btree_set_e as btree_set_e;
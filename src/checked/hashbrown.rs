@@ -0,0 +1,188 @@
+//! Checked equivalents of the macros from the [`hashbrown`](crate::hashbrown)
+//! module.
+//!
+//! Like the macros from the [`checked`](crate::checked) module, these macros
+//! fail to compile if the same literal key occurs more than once:
+//!
+//! ```compile_fail
+//! use map_macro::checked::hashbrown::hash_map;
+//!
+//! let hello = hash_map! {
+//!     "en" => "Hello",
+//!     "de" => "Hallo",
+//!     "en" => "Hi",
+//! };
+//! ```
+//!
+
+/// Checked equivalent of [`hash_map!`](crate::hashbrown::hash_map).
+///
+/// Fails to compile if the same literal key occurs more than once.
+/// See the [module level documentation](crate::checked) for which keys are
+/// checked.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::checked::hashbrown::hash_map;
+///
+/// let goodbye = hash_map! {
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+///     "fr" => "Au revoir",
+/// };
+/// ```
+///
+/// ```compile_fail
+/// use map_macro::checked::hashbrown::hash_map;
+///
+/// let goodbye = hash_map! {
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+///     "en" => "Bye",
+/// };
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checked_hb_hash_map {
+    {$($t: tt)*} => {
+        {
+            $crate::__private::assert_unique_keys!(map $($t)*);
+            $crate::hashbrown::hash_map!{$($t)*}
+        }
+    };
+}
+
+/// Checked equivalent of [`hash_map_e!`](crate::hashbrown::hash_map_e).
+///
+/// Fails to compile if the same literal key occurs more than once.
+/// See the [module level documentation](crate::checked) for which keys are
+/// checked.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Debug;
+///
+/// use hashbrown::HashMap;
+///
+/// use map_macro::checked::hashbrown::hash_map_e;
+///
+/// let goodbye: HashMap<&str, &dyn Debug> = hash_map_e! {
+///     "en" => &"Goodbye",
+///     "de" => &"Auf Wiedersehen",
+///     "fr" => &"Au revoir",
+/// };
+/// ```
+///
+/// ```compile_fail
+/// use std::fmt::Debug;
+///
+/// use hashbrown::HashMap;
+///
+/// use map_macro::checked::hashbrown::hash_map_e;
+///
+/// let goodbye: HashMap<&str, &dyn Debug> = hash_map_e! {
+///     "en" => &"Goodbye",
+///     "de" => &"Auf Wiedersehen",
+///     "en" => &"Bye",
+/// };
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checked_hb_hash_map_e {
+    {$($t: tt)*} => {
+        {
+            $crate::__private::assert_unique_keys!(map $($t)*);
+            $crate::hashbrown::hash_map_e!{$($t)*}
+        }
+    };
+}
+
+/// Checked equivalent of [`hash_set!`](crate::hashbrown::hash_set).
+///
+/// Fails to compile if the same literal key occurs more than once.
+/// See the [module level documentation](crate::checked) for which keys are
+/// checked.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::checked::hashbrown::hash_set;
+///
+/// let x = hash_set! { 1, 2, 3, 4 };
+///
+/// assert_eq!(x.len(), 4);
+/// ```
+///
+/// ```compile_fail
+/// use map_macro::checked::hashbrown::hash_set;
+///
+/// let x = hash_set! { 1, 2, 3, 3, 4 };
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checked_hb_hash_set {
+    {$($t: tt)*} => {
+        {
+            $crate::__private::assert_unique_keys!(set $($t)*);
+            $crate::hashbrown::hash_set!{$($t)*}
+        }
+    };
+}
+
+/// Checked equivalent of [`hash_set_e!`](crate::hashbrown::hash_set_e).
+///
+/// Fails to compile if the same literal key occurs more than once.
+/// See the [module level documentation](crate::checked) for which keys are
+/// checked.
+///
+/// # Examples
+///
+/// ```rust
+/// use hashbrown::HashSet;
+///
+/// use map_macro::checked::hashbrown::hash_set_e;
+///
+/// enum Foo { A, B, C, D }
+///
+/// let x: HashSet<u8> = hash_set_e! { Foo::A, Foo::B, Foo::C, Foo::D };
+///
+/// assert_eq!(x.len(), 4);
+/// ```
+///
+/// ```compile_fail
+/// use hashbrown::HashSet;
+///
+/// use map_macro::checked::hashbrown::hash_set_e;
+///
+/// enum Foo { A, B, C, D }
+///
+/// let x: HashSet<u8> = hash_set_e! { Foo::A, Foo::B, Foo::C, Foo::C, Foo::D };
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checked_hb_hash_set_e {
+    {$($t: tt)*} => {
+        {
+            $crate::__private::assert_unique_keys!(set $($t)*);
+            $crate::hashbrown::hash_set_e!{$($t)*}
+        }
+    };
+}
+
+#[doc(inline)]
+pub use __checked_hb_hash_map as hash_map;
+
+#[doc(inline)]
+pub use __checked_hb_hash_map_e as hash_map_e;
+
+#[doc(inline)]
+pub use __checked_hb_hash_set as hash_set;
+
+#[doc(inline)]
+pub use __checked_hb_hash_set_e as hash_set_e;
@@ -0,0 +1,428 @@
+//! Macros for initializing [`dashmap`] maps and sets.
+//!
+//! # Example
+//!
+//! ```
+//! use map_macro::dashmap::dash_map;
+//!
+//! let hello = dash_map! {
+//!     "en" => "Hello",
+//!     "de" => "Hallo",
+//!     "fr" => "Bonjour",
+//!     "es" => "Hola",
+//!     "cat" => "Hola",
+//!     "🌍" => "👋",
+//! };
+//!
+//! assert_eq!(*hello.get("de").unwrap(), "Hallo");
+//! ```
+//!
+//! # Supported Versions of `dashmap`
+//!
+//! The macros from this module support the `5.x` and `6.x` versions of
+//! `dashmap`.
+//! Plain lists of entries are collected with the
+//! [`FromIterator`](::core::iter::FromIterator) implementations of
+//! `DashMap` and `DashSet`, while the clauses and the other kinds of entries
+//! rely on their constructors and `insert` methods, which haven't changed
+//! between these versions.
+//! The [`shards`](self::dash_map#shards) clause requires a version of
+//! `dashmap` that allows setting the amount of shards, which is the case
+//! for `5.2` and later.
+//!
+//! **Note:** to be compatible with all versions of `dashmap` at once, this
+//! crate doesn't re-export `dashmap`.
+//! That means that (I) you need to specify it as a dependency yourself and
+//! (II) you can't rename it or the macros from this module won't be able to
+//! import the needed types, resulting in a compile-time error.
+//!
+
+/// Macro for creating a [`DashMap`](::dashmap::DashMap).
+///
+/// Syntactic sugar for [`DashMap::from_iter`](::dashmap::DashMap#method.from_iter).
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::dashmap::dash_map;
+///
+/// let goodbye = dash_map! {
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+///     "fr" => "Au revoir",
+///     "es" => "Adios",
+///     "cat" => "Adéu",
+/// };
+///
+/// assert_eq!(goodbye.len(), 5);
+/// ```
+///
+/// # Custom Hashers
+///
+/// If the first clause of the macro is `hasher: expr;`, the map is created
+/// with [`DashMap::with_hasher`](::dashmap::DashMap#method.with_hasher),
+/// using the provided [`BuildHasher`](::core::hash::BuildHasher):
+///
+/// ```rust
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::BuildHasherDefault;
+///
+/// use map_macro::dashmap::dash_map;
+///
+/// let goodbye = dash_map! {
+///     hasher: BuildHasherDefault::<DefaultHasher>::default();
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+/// };
+/// ```
+///
+/// # Capacity
+///
+/// Additional capacity can be reserved up front with a leading
+/// `capacity: expr;` clause.
+/// The map is then created with a capacity of at least `expr`:
+///
+/// ```rust
+/// use map_macro::dashmap::dash_map;
+///
+/// let goodbye = dash_map! {
+///     capacity: 64;
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+/// };
+///
+/// assert!(goodbye.capacity() >= 64);
+/// ```
+///
+/// # Shards
+///
+/// A `DashMap` is split into shards, each of which is locked separately.
+/// The amount of shards can be set with a `shards: expr;` clause, which is
+/// passed to [`DashMap::with_shard_amount`](::dashmap::DashMap#method.with_shard_amount)
+/// or one of the other constructors taking the amount of shards.
+/// The amount must be a power of two greater than one, otherwise creating the
+/// map panics:
+///
+/// ```rust
+/// use map_macro::dashmap::dash_map;
+///
+/// let goodbye = dash_map! {
+///     shards: 4;
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+/// };
+///
+/// assert_eq!(goodbye.len(), 2);
+/// ```
+///
+/// The `capacity`, `hasher` and `shards` clauses can be combined in any
+/// order.
+///
+/// # Comprehensions
+///
+/// Supports the same [comprehensions](crate::hash_map#comprehensions),
+/// [spread](crate::hash_map#spread),
+/// [conditional](crate::hash_map#conditional-entries) and
+/// [shorthand](crate::hash_map#shorthand-entries) entries as the
+/// [`hash_map!`](crate::hash_map) macro for the standard library:
+///
+/// ```rust
+/// use map_macro::dashmap::dash_map;
+///
+/// let squares = dash_map! { x => x * x for x in 0..10 if x % 2 == 0 };
+///
+/// assert_eq!(*squares.get(&4).unwrap(), 16);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dm_dash_map {
+    {@list $($k: expr => $v: expr),* $(,)?} => {
+        <::dashmap::DashMap::<_, _> as ::core::iter::FromIterator<_>>::from_iter([$(($k, $v),)*])
+    };
+    {@list $($t: tt)*} => {
+        $crate::__dm_new!(DashMap map [] [] [] []; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__dm_new!(DashMap map [] [] [] []; $($t)*)
+    };
+    {shards: $s: expr; $($t: tt)*} => {
+        $crate::__dm_new!(DashMap map [] [] [] [$s]; $($t)*)
+    };
+    {hasher: $h: expr; shards: $s: expr; $($t: tt)*} => {
+        $crate::__dm_new!(DashMap map [] [] [] [$s]; hasher: $h; $($t)*)
+    };
+    {capacity: $c: expr; shards: $s: expr; $($t: tt)*} => {
+        $crate::__dm_new!(DashMap map [] [] [] [$s]; capacity: $c; $($t)*)
+    };
+    {hasher: $h: expr; capacity: $c: expr; shards: $s: expr; $($t: tt)*} => {
+        $crate::__dm_new!(DashMap map [] [] [] [$s]; hasher: $h; capacity: $c; $($t)*)
+    };
+    {capacity: $c: expr; hasher: $h: expr; shards: $s: expr; $($t: tt)*} => {
+        $crate::__dm_new!(DashMap map [] [] [] [$s]; capacity: $c; hasher: $h; $($t)*)
+    };
+    {capacity: $c: expr; hasher: $h: expr; $($t: tt)*} => {
+        $crate::dashmap::dash_map!{hasher: $h; capacity: $c; $($t)*}
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__dm_dash_map map []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`dash_map!`](self::dash_map).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Debug;
+///
+/// use dashmap::DashMap;
+///
+/// use map_macro::dashmap::dash_map_e;
+///
+/// let goodbye: DashMap<&str, &dyn Debug> = dash_map_e! {
+///     "en" => &"Goodbye",
+///     "de" => &"Auf Wiedersehen",
+///     "fr" => &"Au revoir",
+///     "es" => &"Adios",
+///     "cat" => &"Adéu",
+/// };
+///
+/// println!("{:?}", goodbye);
+/// ```
+///
+/// Supports the same [`hasher`](self::dash_map#custom-hashers),
+/// [`capacity`](self::dash_map#capacity) and [`shards`](self::dash_map#shards)
+/// clauses, as well as the same [comprehensions](self::dash_map#comprehensions)
+/// and other entries as [`dash_map!`](self::dash_map):
+///
+/// ```rust
+/// use std::fmt::Debug;
+///
+/// use dashmap::DashMap;
+///
+/// use map_macro::dashmap::dash_map_e;
+///
+/// let goodbye: DashMap<&str, &dyn Debug> = dash_map_e! {
+///     shards: 2;
+///     "en" => &"Goodbye",
+///     "de" => &"Auf Wiedersehen",
+/// };
+/// ```
+///
+/// **Note:** the pairs of spread sources are not cast.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dm_dash_map_e {
+    {@list $($k: expr => $v: expr),* $(,)?} => {
+        <::dashmap::DashMap::<_, _> as ::core::iter::FromIterator<_>>::from_iter([$(($k as _, $v as _),)*])
+    };
+    {@list $($t: tt)*} => {
+        $crate::__dm_new!(DashMap map [as _] [] [] []; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__dm_new!(DashMap map [as _] [] [] []; $($t)*)
+    };
+    {shards: $s: expr; $($t: tt)*} => {
+        $crate::__dm_new!(DashMap map [as _] [] [] [$s]; $($t)*)
+    };
+    {hasher: $h: expr; shards: $s: expr; $($t: tt)*} => {
+        $crate::__dm_new!(DashMap map [as _] [] [] [$s]; hasher: $h; $($t)*)
+    };
+    {capacity: $c: expr; shards: $s: expr; $($t: tt)*} => {
+        $crate::__dm_new!(DashMap map [as _] [] [] [$s]; capacity: $c; $($t)*)
+    };
+    {hasher: $h: expr; capacity: $c: expr; shards: $s: expr; $($t: tt)*} => {
+        $crate::__dm_new!(DashMap map [as _] [] [] [$s]; hasher: $h; capacity: $c; $($t)*)
+    };
+    {capacity: $c: expr; hasher: $h: expr; shards: $s: expr; $($t: tt)*} => {
+        $crate::__dm_new!(DashMap map [as _] [] [] [$s]; capacity: $c; hasher: $h; $($t)*)
+    };
+    {capacity: $c: expr; hasher: $h: expr; $($t: tt)*} => {
+        $crate::dashmap::dash_map_e!{hasher: $h; capacity: $c; $($t)*}
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__dm_dash_map_e map []; $($t)*)
+    };
+}
+
+/// Macro for creating a [`DashSet`](::dashmap::DashSet).
+///
+/// Syntactic sugar for [`DashSet::from_iter`](::dashmap::DashSet#method.from_iter).
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::dashmap::dash_set;
+///
+/// let x = dash_set! { 1, 2, 3, 3, 4 };
+///
+/// assert_eq!(x.len(), 4);
+/// ```
+///
+/// # Clauses
+///
+/// Like [`dash_map!`](self::dash_map), the set can be created with a custom
+/// [`hasher`](self::dash_map#custom-hashers) and additional
+/// [`capacity`](self::dash_map#capacity).
+/// `DashSet` doesn't allow setting the amount of shards, so there is no
+/// `shards` clause:
+///
+/// ```rust
+/// use map_macro::dashmap::dash_set;
+///
+/// let x = dash_set! { capacity: 64; 1, 2, 3 };
+///
+/// assert!(x.capacity() >= 64);
+/// ```
+///
+/// # Comprehensions
+///
+/// Supports the same [comprehensions](crate::hash_set#comprehensions),
+/// [spread](crate::hash_set#spread),
+/// [conditional](crate::hash_set#conditional-elements) and
+/// [range](crate::hash_set#ranges) elements as the
+/// [`hash_set!`](crate::hash_set) macro for the standard library:
+///
+/// ```rust
+/// use map_macro::dashmap::dash_set;
+///
+/// let x = dash_set! { 0, 10..20, x * 100 for x in 1..3 };
+///
+/// assert_eq!(x.len(), 13);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dm_dash_set {
+    {@list $($v: expr),* $(,)?} => {
+        <::dashmap::DashSet::<_> as ::core::iter::FromIterator<_>>::from_iter([$($v,)*])
+    };
+    {@list $($t: tt)*} => {
+        $crate::__dm_new!(DashSet set [] [] [] []; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__dm_new!(DashSet set [] [] [] []; $($t)*)
+    };
+    {capacity: $c: expr; hasher: $h: expr; $($t: tt)*} => {
+        $crate::dashmap::dash_set!{hasher: $h; capacity: $c; $($t)*}
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__dm_dash_set set []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`dash_set!`](self::dash_set).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use dashmap::DashSet;
+///
+/// use map_macro::dashmap::dash_set_e;
+///
+/// enum Foo { A, B, C, D }
+///
+/// let x: DashSet<u8> = dash_set_e! { Foo::A, Foo::B, Foo::C, Foo::C, Foo::D };
+///
+/// assert_eq!(x.len(), 4);
+/// ```
+///
+/// Supports the same [clauses](self::dash_set#clauses) and
+/// [elements](self::dash_set#comprehensions) as [`dash_set!`](self::dash_set).
+///
+/// **Note:** the elements of spread sources and ranges are not cast.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dm_dash_set_e {
+    {@list $($v: expr),* $(,)?} => {
+        <::dashmap::DashSet::<_> as ::core::iter::FromIterator<_>>::from_iter([$($v as _,)*])
+    };
+    {@list $($t: tt)*} => {
+        $crate::__dm_new!(DashSet set [as _] [] [] []; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__dm_new!(DashSet set [as _] [] [] []; $($t)*)
+    };
+    {capacity: $c: expr; hasher: $h: expr; $($t: tt)*} => {
+        $crate::dashmap::dash_set_e!{hasher: $h; capacity: $c; $($t)*}
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__dm_dash_set_e set []; $($t)*)
+    };
+}
+
+/// Creates a [`DashMap`](::dashmap::DashMap) or
+/// [`DashSet`](::dashmap::DashSet) with the constructor matching the
+/// `capacity`, `hasher` and `shards` clauses and inserts the entries into
+/// it.
+///
+/// The clauses are collected in any order into the brackets after the
+/// tokens appended to every key and value.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dm_new {
+    ($ty: ident $mode: ident $cast: tt $c: tt $h: tt $s: tt; capacity: $v: expr; $($t: tt)*) => {
+        $crate::__dm_new!($ty $mode $cast [$v] $h $s; $($t)*)
+    };
+    ($ty: ident $mode: ident $cast: tt $c: tt $h: tt $s: tt; hasher: $v: expr; $($t: tt)*) => {
+        $crate::__dm_new!($ty $mode $cast $c [$v] $s; $($t)*)
+    };
+    ($ty: ident $mode: ident $cast: tt $c: tt $h: tt $s: tt; shards: $v: expr; $($t: tt)*) => {
+        $crate::__dm_new!($ty $mode $cast $c $h [$v]; $($t)*)
+    };
+    (@new $ty: ident $mode: ident $cast: tt ($($new: tt)*); $($t: tt)*) => {
+        {
+            #[allow(unused_mut)]
+            let mut c = ::dashmap::$ty::$($new)*;
+            $crate::__entries!(@$mode c $cast; $($t)*);
+            c
+        }
+    };
+    ($ty: ident $mode: ident $cast: tt [] [] []; $($t: tt)*) => {
+        $crate::__dm_new!(@new $ty $mode $cast (new()); $($t)*)
+    };
+    ($ty: ident $mode: ident $cast: tt [$c: expr] [] []; $($t: tt)*) => {
+        $crate::__dm_new!(@new $ty $mode $cast (with_capacity($c)); $($t)*)
+    };
+    ($ty: ident $mode: ident $cast: tt [] [$h: expr] []; $($t: tt)*) => {
+        $crate::__dm_new!(@new $ty $mode $cast (with_hasher($h)); $($t)*)
+    };
+    ($ty: ident $mode: ident $cast: tt [$c: expr] [$h: expr] []; $($t: tt)*) => {
+        $crate::__dm_new!(@new $ty $mode $cast (with_capacity_and_hasher($c, $h)); $($t)*)
+    };
+    ($ty: ident $mode: ident $cast: tt [] [] [$s: expr]; $($t: tt)*) => {
+        $crate::__dm_new!(@new $ty $mode $cast (with_shard_amount($s)); $($t)*)
+    };
+    ($ty: ident $mode: ident $cast: tt [$c: expr] [] [$s: expr]; $($t: tt)*) => {
+        $crate::__dm_new!(@new $ty $mode $cast (with_capacity_and_shard_amount($c, $s)); $($t)*)
+    };
+    ($ty: ident $mode: ident $cast: tt [] [$h: expr] [$s: expr]; $($t: tt)*) => {
+        $crate::__dm_new!(@new $ty $mode $cast (with_hasher_and_shard_amount($h, $s)); $($t)*)
+    };
+    ($ty: ident $mode: ident $cast: tt [$c: expr] [$h: expr] [$s: expr]; $($t: tt)*) => {
+        $crate::__dm_new!(
+            @new $ty $mode $cast (with_capacity_and_hasher_and_shard_amount($c, $h, $s));
+            $($t)*
+        )
+    };
+}
+
+#[doc(inline)]
+pub use __dm_dash_map as dash_map;
+
+#[doc(inline)]
+pub use __dm_dash_map_e as dash_map_e;
+
+#[doc(inline)]
+pub use __dm_dash_set as dash_set;
+
+#[doc(inline)]
+pub use __dm_dash_set_e as dash_set_e;
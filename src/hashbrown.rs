@@ -17,17 +17,25 @@
 //!
 //! # Supported Versions of `hashbrown`
 //!
-//! As of writing this, up to the current `hashbrown` version `0.14` **all**
-//! versions of `hashbrown` are supported.
-//! So you can use the macros from this module with any version of `hashbrown`
-//! to date.
-//! Though highly likely, compatibility can't be guaranteed with future versions
-//! of `hashbrown` that break [SemVer compatibility](https://semver.org/#semantic-versioning-specification-semver)
-//! with `0.14`.
-//! If `hashbrown` were to remove the [`FromIterator`](::core::iter::FromIterator)
-//! implementations of `HashMap` and `HashSet` in a release that is
-//! incompatible with `0.14` (i.e. `0.15` or `1.0`) compatibility with the
-//! macros from this module would break for that new version.
+//! Which versions of `hashbrown` the macros from this module support depends
+//! on the clauses they are used with:
+//!
+//! * Without clauses, the macros use the [`Default`], [`FromIterator`](::core::iter::FromIterator)
+//!   and [`Extend`] implementations of `HashMap` and `HashSet`, as well as
+//!   their `insert` and `entry` methods, which every version of `hashbrown`
+//!   provides.
+//! * The `hasher` and `capacity` clauses use `with_hasher`,
+//!   `with_capacity_and_hasher` and `reserve`, which every version of
+//!   `hashbrown` provides as well.
+//! * The `in` clause uses `new_in`, `with_capacity_in` and
+//!   `with_capacity_and_hasher_in` with an allocator implementing the
+//!   `Allocator` trait of `allocator-api2`, which requires `hashbrown` `0.14`
+//!   or newer with its `allocator-api2` feature.
+//!   The feature is enabled by default.
+//!
+//! Compatibility can't be guaranteed with future versions of `hashbrown` that
+//! break [SemVer compatibility](https://semver.org/#semantic-versioning-specification-semver)
+//! with `0.14`, e.g. by removing one of the methods above.
 //!
 //! **Note:** to be compatible with all versions of `hashbrown` at once, this
 //! crate doesn't re-export `hashbrown`.
@@ -54,12 +62,233 @@
 /// };
 /// ```
 ///
+/// # Custom Hashers
+///
+/// If the first clause of the macro is `hasher: expr;`, the map is created
+/// with [`HashMap::with_capacity_and_hasher`](::hashbrown::HashMap#method.with_capacity_and_hasher),
+/// using the provided [`BuildHasher`](::core::hash::BuildHasher):
+///
+/// ```rust
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::BuildHasherDefault;
+///
+/// use map_macro::hashbrown::hash_map;
+///
+/// let goodbye = hash_map! {
+///     hasher: BuildHasherDefault::<DefaultHasher>::default();
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+///     "fr" => "Au revoir",
+///     "es" => "Adios",
+///     "cat" => "Adéu",
+/// };
+/// ```
+///
+/// # Capacity
+///
+/// The map is created with just enough capacity for the provided
+/// elements.
+/// If you intend to add more elements to it later on, you can reserve
+/// additional capacity up front with a leading `capacity: expr;` clause.
+/// The map is then created with a capacity of at least `expr`:
+///
+/// ```rust
+/// use map_macro::hashbrown::hash_map;
+///
+/// let mut goodbye = hash_map! {
+///     capacity: 10;
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+/// };
+///
+/// assert!(goodbye.capacity() >= 10);
+///
+/// goodbye.insert("fr", "Au revoir");
+/// ```
+///
+/// The `capacity` and `hasher` clauses can be combined in any order.
+///
+/// # Allocators
+///
+/// With an `in: expr;` clause, the map is created in the allocator `expr`
+/// with [`HashMap::new_in`](::hashbrown::HashMap#method.new_in) or one of the
+/// other constructors taking an allocator, like an arena allocator.
+/// The `in` clause can be combined with the `capacity` and `hasher` clauses
+/// in any order:
+///
+/// ```rust
+/// use allocator_api2::alloc::Global;
+///
+/// use map_macro::hashbrown::hash_map;
+///
+/// let goodbye = hash_map! {
+///     capacity: 10;
+///     in: Global;
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+/// };
+///
+/// assert!(goodbye.capacity() >= 10);
+/// ```
+///
+/// **Note:** the `in` clause requires `hashbrown` `0.14` or newer with its
+/// `allocator-api2` feature, which is enabled by default.
+///
+/// # Comprehensions
+///
+/// Entries can also be computed from iterators with `for` and `if`
+/// comprehension clauses, which can be mixed with literal entries.
+/// See the [`hash_map!`](crate::hash_map#comprehensions) macro for the
+/// standard library for details:
+///
+/// ```rust
+/// use map_macro::hashbrown::hash_map;
+///
+/// let squares = hash_map! { x => x * x for x in 0..10 if x % 2 == 0 };
+///
+/// assert_eq!(squares[&4], 16);
+/// ```
+///
+/// # Spread
+///
+/// An entry written as `..iterable` inserts all key-value pairs of
+/// `iterable`.
+/// See the [`hash_map!`](crate::hash_map#spread) macro for the standard
+/// library for details:
+///
+/// ```rust
+/// use map_macro::hashbrown::hash_map;
+///
+/// let defaults = hash_map! { "timeout" => 10, "retries" => 3 };
+///
+/// let config = hash_map! { ..defaults, "timeout" => 30 };
+///
+/// assert_eq!(config["timeout"], 30);
+/// ```
+///
+/// # Conditional Entries
+///
+/// Entries can be followed by an `if` guard, annotated with attributes like
+/// `#[cfg(...)]` or written as `key =>? option` to only be inserted if
+/// `option` is `Some`.
+/// See the [`hash_map!`](crate::hash_map#conditional-entries) macro for the
+/// standard library for details:
+///
+/// ```rust
+/// use map_macro::hashbrown::hash_map;
+///
+/// let proxy: Option<&str> = None;
+///
+/// let config = hash_map! {
+///     "log" => "debug" if cfg!(debug_assertions),
+///     "proxy" =>? proxy,
+/// };
+///
+/// assert!(!config.contains_key("proxy"));
+/// ```
+///
+/// # Shorthand Entries
+///
+/// A bare identifier is an entry with the name of the identifier as key and
+/// its value as value.
+/// See the [`hash_map!`](crate::hash_map#shorthand-entries) macro for the
+/// standard library for details:
+///
+/// ```rust
+/// use map_macro::hashbrown::hash_map;
+///
+/// let width = 16;
+/// let height = 9;
+///
+/// let dimensions = hash_map! { width, height };
+///
+/// assert_eq!(dimensions["width"], 16);
+/// ```
+///
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __hb_hash_map {
-    {$($k: expr => $v: expr),* $(,)?} => {
+    {@list hasher: $h: expr; capacity: $c: expr; $($k: expr => $v: expr),* $(,)?} => {
+        {
+            let mut map = ::hashbrown::HashMap::with_capacity_and_hasher(
+                ::core::cmp::max($c, $crate::__count!($($k)*)),
+                $h,
+            );
+            $(map.insert($k, $v);)*
+            map
+        }
+    };
+    {@list hasher: $h: expr; $($k: expr => $v: expr),* $(,)?} => {
+        {
+            let mut map = ::hashbrown::HashMap::with_capacity_and_hasher(
+                $crate::__count!($($k)*),
+                $h,
+            );
+            $(map.insert($k, $v);)*
+            map
+        }
+    };
+    {@list capacity: $c: expr; $($k: expr => $v: expr),* $(,)?} => {
+        {
+            let mut map = <::hashbrown::HashMap<_, _> as ::core::default::Default>::default();
+            map.reserve(::core::cmp::max($c, $crate::__count!($($k)*)));
+            $(map.insert($k, $v);)*
+            map
+        }
+    };
+    {@list $($k: expr => $v: expr),* $(,)?} => {
         <::hashbrown::HashMap::<_, _> as ::core::iter::FromIterator<_>>::from_iter([$(($k, $v),)*])
     };
+    {@entries hasher: $h: expr; capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut map = ::hashbrown::HashMap::with_capacity_and_hasher($c, $h);
+            $crate::__entries!(@map map []; $($t)*);
+            map
+        }
+    };
+    {@entries hasher: $h: expr; $($t: tt)*} => {
+        {
+            let mut map = ::hashbrown::HashMap::with_hasher($h);
+            $crate::__entries!(@map map []; $($t)*);
+            map
+        }
+    };
+    {@entries capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut map = <::hashbrown::HashMap<_, _> as ::core::default::Default>::default();
+            map.reserve($c);
+            $crate::__entries!(@map map []; $($t)*);
+            map
+        }
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut map = <::hashbrown::HashMap<_, _> as ::core::default::Default>::default();
+            $crate::__entries!(@map map []; $($t)*);
+            map
+        }
+    };
+    {in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashMap map [] [$a] [] []; $($t)*)
+    };
+    {hasher: $h: expr; in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashMap map [] [$a] [] []; hasher: $h; $($t)*)
+    };
+    {capacity: $c: expr; in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashMap map [] [$a] [] []; capacity: $c; $($t)*)
+    };
+    {hasher: $h: expr; capacity: $c: expr; in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashMap map [] [$a] [] []; hasher: $h; capacity: $c; $($t)*)
+    };
+    {capacity: $c: expr; hasher: $h: expr; in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashMap map [] [$a] [] []; capacity: $c; hasher: $h; $($t)*)
+    };
+    {capacity: $c: expr; hasher: $h: expr; $($t: tt)*} => {
+        $crate::hashbrown::hash_map!{hasher: $h; capacity: $c; $($t)*}
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__hb_hash_map map []; $($t)*)
+    };
 }
 
 /// Explicitly typed equivalent of [`hash_map!`](self::hash_map).
@@ -86,12 +315,117 @@ macro_rules! __hb_hash_map {
 /// println!("{:?}", goodbye);
 /// ```
 ///
+/// Supports the same [`hasher`](self::hash_map#custom-hashers),
+/// [`capacity`](self::hash_map#capacity) and [`in`](self::hash_map#allocators)
+/// clauses, as well as the same
+/// [comprehensions](self::hash_map#comprehensions),
+/// [spread](self::hash_map#spread),
+/// [conditional](self::hash_map#conditional-entries) and
+/// [shorthand](self::hash_map#shorthand-entries) entries as
+/// [`hash_map!`](self::hash_map):
+///
+/// ```rust
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::fmt::Debug;
+/// use std::hash::BuildHasherDefault;
+///
+/// use hashbrown::HashMap;
+///
+/// use map_macro::hashbrown::hash_map_e;
+///
+/// let goodbye: HashMap<&str, &dyn Debug, BuildHasherDefault<DefaultHasher>> = hash_map_e! {
+///     hasher: Default::default();
+///     "en" => &"Goodbye",
+///     "de" => &"Auf Wiedersehen",
+/// };
+/// ```
+///
+/// **Note:** the pairs of spread sources are not cast.
+///
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __hb_hash_map_e {
-    {$($k: expr => $v: expr),* $(,)?} => {
+    {@list hasher: $h: expr; capacity: $c: expr; $($k: expr => $v: expr),* $(,)?} => {
+        {
+            let mut map = ::hashbrown::HashMap::with_capacity_and_hasher(
+                ::core::cmp::max($c, $crate::__count!($($k)*)),
+                $h,
+            );
+            $(map.insert($k as _, $v as _);)*
+            map
+        }
+    };
+    {@list hasher: $h: expr; $($k: expr => $v: expr),* $(,)?} => {
+        {
+            let mut map = ::hashbrown::HashMap::with_capacity_and_hasher(
+                $crate::__count!($($k)*),
+                $h,
+            );
+            $(map.insert($k as _, $v as _);)*
+            map
+        }
+    };
+    {@list capacity: $c: expr; $($k: expr => $v: expr),* $(,)?} => {
+        {
+            let mut map = <::hashbrown::HashMap<_, _> as ::core::default::Default>::default();
+            map.reserve(::core::cmp::max($c, $crate::__count!($($k)*)));
+            $(map.insert($k as _, $v as _);)*
+            map
+        }
+    };
+    {@list $($k: expr => $v: expr),* $(,)?} => {
         <::hashbrown::HashMap::<_, _> as ::core::iter::FromIterator<_>>::from_iter([$(($k as _, $v as _),)*])
     };
+    {@entries hasher: $h: expr; capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut map = ::hashbrown::HashMap::with_capacity_and_hasher($c, $h);
+            $crate::__entries!(@map map [as _]; $($t)*);
+            map
+        }
+    };
+    {@entries hasher: $h: expr; $($t: tt)*} => {
+        {
+            let mut map = ::hashbrown::HashMap::with_hasher($h);
+            $crate::__entries!(@map map [as _]; $($t)*);
+            map
+        }
+    };
+    {@entries capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut map = <::hashbrown::HashMap<_, _> as ::core::default::Default>::default();
+            map.reserve($c);
+            $crate::__entries!(@map map [as _]; $($t)*);
+            map
+        }
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut map = <::hashbrown::HashMap<_, _> as ::core::default::Default>::default();
+            $crate::__entries!(@map map [as _]; $($t)*);
+            map
+        }
+    };
+    {in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashMap map [as _] [$a] [] []; $($t)*)
+    };
+    {hasher: $h: expr; in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashMap map [as _] [$a] [] []; hasher: $h; $($t)*)
+    };
+    {capacity: $c: expr; in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashMap map [as _] [$a] [] []; capacity: $c; $($t)*)
+    };
+    {hasher: $h: expr; capacity: $c: expr; in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashMap map [as _] [$a] [] []; hasher: $h; capacity: $c; $($t)*)
+    };
+    {capacity: $c: expr; hasher: $h: expr; in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashMap map [as _] [$a] [] []; capacity: $c; hasher: $h; $($t)*)
+    };
+    {capacity: $c: expr; hasher: $h: expr; $($t: tt)*} => {
+        $crate::hashbrown::hash_map_e!{hasher: $h; capacity: $c; $($t)*}
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__hb_hash_map_e map []; $($t)*)
+    };
 }
 
 /// Macro for creating a [`HashSet`](::hashbrown::HashSet).
@@ -108,12 +442,204 @@ macro_rules! __hb_hash_map_e {
 /// assert_eq!(x.len(), 4);
 /// ```
 ///
+/// # Custom Hashers
+///
+/// If the first clause of the macro is `hasher: expr;`, the set is created
+/// with [`HashSet::with_capacity_and_hasher`](::hashbrown::HashSet#method.with_capacity_and_hasher),
+/// using the provided [`BuildHasher`](::core::hash::BuildHasher):
+///
+/// ```rust
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::BuildHasherDefault;
+///
+/// use map_macro::hashbrown::hash_set;
+///
+/// let x = hash_set! {
+///     hasher: BuildHasherDefault::<DefaultHasher>::default();
+///     1, 2, 3, 3, 4
+/// };
+///
+/// assert_eq!(x.len(), 4);
+/// ```
+///
+/// # Capacity
+///
+/// The set is created with just enough capacity for the provided
+/// elements.
+/// If you intend to add more elements to it later on, you can reserve
+/// additional capacity up front with a leading `capacity: expr;` clause.
+/// The set is then created with a capacity of at least `expr`:
+///
+/// ```rust
+/// use map_macro::hashbrown::hash_set;
+///
+/// let x = hash_set! { capacity: 10; 1, 2, 3, 3, 4 };
+///
+/// assert_eq!(x.len(), 4);
+/// assert!(x.capacity() >= 10);
+/// ```
+///
+/// The `capacity` and `hasher` clauses can be combined in any order.
+///
+/// # Allocators
+///
+/// Like [`hash_map!`](self::hash_map#allocators), the set is created in the
+/// allocator `expr` of an `in: expr;` clause:
+///
+/// ```rust
+/// use allocator_api2::alloc::Global;
+///
+/// use map_macro::hashbrown::hash_set;
+///
+/// let x = hash_set! { in: Global; 1, 2, 3 };
+///
+/// assert_eq!(x.len(), 3);
+/// ```
+///
+/// # Comprehensions
+///
+/// Entries can also be computed from iterators with `for` and `if`
+/// comprehension clauses, which can be mixed with literal entries.
+/// See the [`hash_set!`](crate::hash_set#comprehensions) macro for the
+/// standard library for details:
+///
+/// ```rust
+/// use map_macro::hashbrown::hash_set;
+///
+/// let remainders = hash_set! { 42, x % 3 for x in 0..10 };
+///
+/// assert_eq!(remainders.len(), 4);
+/// ```
+///
+/// # Spread
+///
+/// An entry written as `..iterable` inserts all elements of `iterable`.
+/// See the [`hash_set!`](crate::hash_set#spread) macro for the standard
+/// library for details:
+///
+/// ```rust
+/// use map_macro::hashbrown::hash_set;
+///
+/// let base = hash_set! { "read", "write" };
+///
+/// let permissions = hash_set! { ..base, "execute" };
+///
+/// assert_eq!(permissions.len(), 3);
+/// ```
+///
+/// # Conditional Elements
+///
+/// Elements can be followed by an `if` guard or annotated with attributes
+/// like `#[cfg(...)]`.
+/// See the [`hash_set!`](crate::hash_set#conditional-elements) macro for the
+/// standard library for details:
+///
+/// ```rust
+/// use map_macro::hashbrown::hash_set;
+///
+/// let roles = hash_set! { "user", "tester" if false };
+///
+/// assert_eq!(roles.len(), 1);
+/// ```
+///
+/// # Ranges
+///
+/// A range whose start is a literal inserts all items of the range.
+/// See the [`hash_set!`](crate::hash_set#ranges) macro for the standard
+/// library for details:
+///
+/// ```rust
+/// use map_macro::hashbrown::hash_set;
+///
+/// let ports = hash_set! { 20..=23, 80 };
+///
+/// assert_eq!(ports.len(), 5);
+/// ```
+///
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __hb_hash_set {
-    {$($v: expr),* $(,)?} => {
+    {@list hasher: $h: expr; capacity: $c: expr; $($v: expr),* $(,)?} => {
+        {
+            let mut set = ::hashbrown::HashSet::with_capacity_and_hasher(
+                ::core::cmp::max($c, $crate::__count!($($v)*)),
+                $h,
+            );
+            $(set.insert($v);)*
+            set
+        }
+    };
+    {@list hasher: $h: expr; $($v: expr),* $(,)?} => {
+        {
+            let mut set = ::hashbrown::HashSet::with_capacity_and_hasher(
+                $crate::__count!($($v)*),
+                $h,
+            );
+            $(set.insert($v);)*
+            set
+        }
+    };
+    {@list capacity: $c: expr; $($v: expr),* $(,)?} => {
+        {
+            let mut set = <::hashbrown::HashSet<_> as ::core::default::Default>::default();
+            set.reserve(::core::cmp::max($c, $crate::__count!($($v)*)));
+            $(set.insert($v);)*
+            set
+        }
+    };
+    {@list $($v: expr),* $(,)?} => {
         <::hashbrown::HashSet::<_> as ::core::iter::FromIterator<_>>::from_iter([$($v,)*])
     };
+    {@entries hasher: $h: expr; capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut set = ::hashbrown::HashSet::with_capacity_and_hasher($c, $h);
+            $crate::__entries!(@set set []; $($t)*);
+            set
+        }
+    };
+    {@entries hasher: $h: expr; $($t: tt)*} => {
+        {
+            let mut set = ::hashbrown::HashSet::with_hasher($h);
+            $crate::__entries!(@set set []; $($t)*);
+            set
+        }
+    };
+    {@entries capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut set = <::hashbrown::HashSet<_> as ::core::default::Default>::default();
+            set.reserve($c);
+            $crate::__entries!(@set set []; $($t)*);
+            set
+        }
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut set = <::hashbrown::HashSet<_> as ::core::default::Default>::default();
+            $crate::__entries!(@set set []; $($t)*);
+            set
+        }
+    };
+    {in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashSet set [] [$a] [] []; $($t)*)
+    };
+    {hasher: $h: expr; in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashSet set [] [$a] [] []; hasher: $h; $($t)*)
+    };
+    {capacity: $c: expr; in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashSet set [] [$a] [] []; capacity: $c; $($t)*)
+    };
+    {hasher: $h: expr; capacity: $c: expr; in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashSet set [] [$a] [] []; hasher: $h; capacity: $c; $($t)*)
+    };
+    {capacity: $c: expr; hasher: $h: expr; in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashSet set [] [$a] [] []; capacity: $c; hasher: $h; $($t)*)
+    };
+    {capacity: $c: expr; hasher: $h: expr; $($t: tt)*} => {
+        $crate::hashbrown::hash_set!{hasher: $h; capacity: $c; $($t)*}
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__hb_hash_set set []; $($t)*)
+    };
 }
 
 /// Explicitly typed equivalent of [`hash_set!`](self::hash_set).
@@ -134,12 +660,477 @@ macro_rules! __hb_hash_set {
 /// assert_eq!(x.len(), 4);
 /// ```
 ///
+/// Supports the same [`hasher`](self::hash_set#custom-hashers),
+/// [`capacity`](self::hash_set#capacity) and [`in`](self::hash_set#allocators)
+/// clauses, as well as the same
+/// [comprehensions](self::hash_set#comprehensions),
+/// [spread](self::hash_set#spread),
+/// [conditional](self::hash_set#conditional-elements) and
+/// [range](self::hash_set#ranges) elements as [`hash_set!`](self::hash_set):
+///
+/// ```rust
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::BuildHasherDefault;
+///
+/// use hashbrown::HashSet;
+///
+/// use map_macro::hashbrown::hash_set_e;
+///
+/// enum Foo { A, B, C, D }
+///
+/// let x: HashSet<u8, BuildHasherDefault<DefaultHasher>> = hash_set_e! {
+///     hasher: Default::default();
+///     Foo::A, Foo::B, Foo::C, Foo::C, Foo::D
+/// };
+///
+/// assert_eq!(x.len(), 4);
+/// ```
+///
+/// **Note:** the elements of spread sources and ranges are not cast.
+///
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __hb_hash_set_e {
-    {$($v: expr),* $(,)?} => {
+    {@list hasher: $h: expr; capacity: $c: expr; $($v: expr),* $(,)?} => {
+        {
+            let mut set = ::hashbrown::HashSet::with_capacity_and_hasher(
+                ::core::cmp::max($c, $crate::__count!($($v)*)),
+                $h,
+            );
+            $(set.insert($v as _);)*
+            set
+        }
+    };
+    {@list hasher: $h: expr; $($v: expr),* $(,)?} => {
+        {
+            let mut set = ::hashbrown::HashSet::with_capacity_and_hasher(
+                $crate::__count!($($v)*),
+                $h,
+            );
+            $(set.insert($v as _);)*
+            set
+        }
+    };
+    {@list capacity: $c: expr; $($v: expr),* $(,)?} => {
+        {
+            let mut set = <::hashbrown::HashSet<_> as ::core::default::Default>::default();
+            set.reserve(::core::cmp::max($c, $crate::__count!($($v)*)));
+            $(set.insert($v as _);)*
+            set
+        }
+    };
+    {@list $($v: expr),* $(,)?} => {
         <::hashbrown::HashSet::<_> as ::core::iter::FromIterator<_>>::from_iter([$($v as _,)*])
     };
+    {@entries hasher: $h: expr; capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut set = ::hashbrown::HashSet::with_capacity_and_hasher($c, $h);
+            $crate::__entries!(@set set [as _]; $($t)*);
+            set
+        }
+    };
+    {@entries hasher: $h: expr; $($t: tt)*} => {
+        {
+            let mut set = ::hashbrown::HashSet::with_hasher($h);
+            $crate::__entries!(@set set [as _]; $($t)*);
+            set
+        }
+    };
+    {@entries capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut set = <::hashbrown::HashSet<_> as ::core::default::Default>::default();
+            set.reserve($c);
+            $crate::__entries!(@set set [as _]; $($t)*);
+            set
+        }
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut set = <::hashbrown::HashSet<_> as ::core::default::Default>::default();
+            $crate::__entries!(@set set [as _]; $($t)*);
+            set
+        }
+    };
+    {in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashSet set [as _] [$a] [] []; $($t)*)
+    };
+    {hasher: $h: expr; in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashSet set [as _] [$a] [] []; hasher: $h; $($t)*)
+    };
+    {capacity: $c: expr; in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashSet set [as _] [$a] [] []; capacity: $c; $($t)*)
+    };
+    {hasher: $h: expr; capacity: $c: expr; in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashSet set [as _] [$a] [] []; hasher: $h; capacity: $c; $($t)*)
+    };
+    {capacity: $c: expr; hasher: $h: expr; in: $a: expr; $($t: tt)*} => {
+        $crate::__hb_in!(HashSet set [as _] [$a] [] []; capacity: $c; hasher: $h; $($t)*)
+    };
+    {capacity: $c: expr; hasher: $h: expr; $($t: tt)*} => {
+        $crate::hashbrown::hash_set_e!{hasher: $h; capacity: $c; $($t)*}
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__hb_hash_set_e set []; $($t)*)
+    };
+}
+
+/// Creates a [`HashMap`](::hashbrown::HashMap) or
+/// [`HashSet`](::hashbrown::HashSet) in the allocator of an `in` clause and
+/// inserts the entries into it.
+///
+/// The `hasher` and `capacity` clauses written before the `in` clause are
+/// passed after it and collected here, as well as those written after it.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hb_in {
+    ($ty: ident $mode: ident $cast: tt $a: tt $h: tt $c: tt; hasher: $v: expr; $($t: tt)*) => {
+        $crate::__hb_in!($ty $mode $cast $a [$v] $c; $($t)*)
+    };
+    ($ty: ident $mode: ident $cast: tt $a: tt $h: tt $c: tt; capacity: $v: expr; $($t: tt)*) => {
+        $crate::__hb_in!($ty $mode $cast $a $h [$v]; $($t)*)
+    };
+    ($ty: ident $mode: ident $cast: tt [$a: expr] [$h: expr] [$c: expr]; $($t: tt)*) => {
+        {
+            let mut c = ::hashbrown::$ty::with_capacity_and_hasher_in($c, $h, $a);
+            $crate::__entries!(@$mode c $cast; $($t)*);
+            c
+        }
+    };
+    ($ty: ident $mode: ident $cast: tt [$a: expr] [$h: expr] []; $($t: tt)*) => {
+        {
+            let mut c = ::hashbrown::$ty::with_capacity_and_hasher_in(0, $h, $a);
+            $crate::__entries!(@$mode c $cast; $($t)*);
+            c
+        }
+    };
+    ($ty: ident $mode: ident $cast: tt [$a: expr] [] [$c: expr]; $($t: tt)*) => {
+        {
+            let mut c = ::hashbrown::$ty::with_capacity_in($c, $a);
+            $crate::__entries!(@$mode c $cast; $($t)*);
+            c
+        }
+    };
+    ($ty: ident $mode: ident $cast: tt [$a: expr] [] []; $($t: tt)*) => {
+        {
+            let mut c = ::hashbrown::$ty::new_in($a);
+            $crate::__entries!(@$mode c $cast; $($t)*);
+            c
+        }
+    };
+}
+
+/// Macro for fallibly creating a [`HashMap`](::hashbrown::HashMap).
+///
+/// Accepts the `key => value` entries of [`hash_map!`](self::hash_map), but
+/// returns a [`DuplicateKeyError`](crate::DuplicateKeyError) if a key occurs
+/// more than once, instead of keeping the last entry with the key.
+/// Unlike the [`checked`](crate::checked) macros, duplicates are detected at
+/// runtime, so keys can be arbitrary expressions.
+///
+/// Like [`try_hash_map!`](crate::try_hash_map), it doesn't support clauses
+/// or entries other than `key => value`.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::hashbrown::try_hash_map;
+///
+/// let hello = try_hash_map! {
+///     "en" => "Hello",
+///     "de" => "Hallo",
+/// };
+///
+/// assert_eq!(hello.unwrap()["de"], "Hallo");
+///
+/// let locale = String::from("en");
+///
+/// let err = try_hash_map! {
+///     "en".to_owned() => "Hello",
+///     "de".to_owned() => "Hallo",
+///     locale => "Hi",
+/// }
+/// .unwrap_err();
+///
+/// assert_eq!(err.first_index(), 0);
+/// assert_eq!(err.second_index(), 2);
+/// assert_eq!(err.key(), "en");
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hb_try_hash_map {
+    {$($k: expr => $v: expr),* $(,)?} => {
+        $crate::__try_collect!(
+            @map [::hashbrown::HashMap<_, usize>] [::hashbrown::HashMap<_, _>];
+            $(($k, $v)),*
+        )
+    };
+}
+
+/// Explicitly typed equivalent of [`try_hash_map!`](self::try_hash_map).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hb_try_hash_map_e {
+    {$($k: expr => $v: expr),* $(,)?} => {
+        $crate::__try_collect!(
+            @map [::hashbrown::HashMap<_, usize>] [::hashbrown::HashMap<_, _>];
+            $(($k as _, $v as _)),*
+        )
+    };
+}
+
+/// Macro for fallibly creating a [`HashSet`](::hashbrown::HashSet).
+///
+/// Accepts the plain elements of [`hash_set!`](self::hash_set), but returns a
+/// [`DuplicateKeyError`](crate::DuplicateKeyError) if an element occurs more than once.
+/// See [`try_hash_map!`](self::try_hash_map) for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::hashbrown::try_hash_set;
+///
+/// let err = try_hash_set! {
+///     "en", "de", "en",
+/// }
+/// .unwrap_err();
+///
+/// assert_eq!(err.first_index(), 0);
+/// assert_eq!(err.second_index(), 2);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hb_try_hash_set {
+    {$($v: expr),* $(,)?} => {
+        $crate::__try_collect!(
+            @set [::hashbrown::HashMap<_, usize>] [::hashbrown::HashSet<_>];
+            $($v),*
+        )
+    };
+}
+
+/// Explicitly typed equivalent of [`try_hash_set!`](self::try_hash_set).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hb_try_hash_set_e {
+    {$($v: expr),* $(,)?} => {
+        $crate::__try_collect!(
+            @set [::hashbrown::HashMap<_, usize>] [::hashbrown::HashSet<_>];
+            $($v as _),*
+        )
+    };
+}
+
+/// Macro for creating nested [`HashMap`](::hashbrown::HashMap)s.
+///
+/// Values written as `{ ... }` become inner `HashMap`s and values written as
+/// `[ ... ]` become `Vec`s.
+/// See [`nested_hash_map!`](crate::nested_hash_map) for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::hashbrown::nested_hash_map;
+///
+/// let config = nested_hash_map! {
+///     "server" => {
+///         "host" => ["localhost"],
+///         "ports" => ["8080", "8081"],
+///     },
+/// };
+///
+/// assert_eq!(config["server"]["ports"], vec!["8080", "8081"]);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hb_nested_hash_map {
+    {$($t: tt)*} => {
+        $crate::__nested!(@map hashbrown [] []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`nested_hash_map!`](self::nested_hash_map).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use hashbrown::HashMap;
+/// use std::fmt::Debug;
+///
+/// use map_macro::hashbrown::nested_hash_map_e;
+///
+/// let settings: HashMap<&str, HashMap<&str, &dyn Debug>> = nested_hash_map_e! {
+///     "window" => {
+///         "title" => &"map-macro",
+///         "width" => &800,
+///     },
+/// };
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hb_nested_hash_map_e {
+    {$($t: tt)*} => {
+        $crate::__nested!(@map hashbrown [as _] []; $($t)*)
+    };
+}
+
+/// Macro for creating a [`HashMap`](::hashbrown::HashMap) that maps each
+/// key to a collection of values.
+///
+/// Entries with the same key don't overwrite each other, their values are
+/// collected into an inner collection instead, which is a
+/// [`Vec`](::std::vec::Vec) by default.
+/// Supports the same `k => [v1, v2]` entries and
+/// [`inner`](crate::hash_multimap#inner-collection) clause as
+/// [`hash_multimap!`](crate::hash_multimap) for the standard library.
+///
+/// # Examples
+///
+/// ```rust
+/// use hashbrown::HashSet;
+///
+/// use map_macro::hashbrown::hash_multimap;
+///
+/// let routes = hash_multimap! {
+///     "/" => "index",
+///     "/users" => ["list_users", "create_user"],
+///     "/" => "health",
+/// };
+///
+/// assert_eq!(routes["/"], vec!["index", "health"]);
+///
+/// let routes = hash_multimap! {
+///     inner: HashSet<_>;
+///     "/" => ["index", "index"],
+/// };
+///
+/// assert_eq!(routes["/"].len(), 1);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hb_hash_multimap {
+    {inner: $inner: ty; $($t: tt)*} => {
+        {
+            let mut map = <::hashbrown::HashMap<_, _> as ::core::default::Default>::default();
+            $crate::__multimap!(map $inner, []; $($t)*);
+            map
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::hashbrown::hash_multimap!{inner: $crate::__private::alloc::vec::Vec<_>; $($t)*}
+    };
+}
+
+/// Explicitly typed equivalent of [`hash_multimap!`](self::hash_multimap).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Debug;
+///
+/// use hashbrown::HashMap;
+///
+/// use map_macro::hashbrown::hash_multimap_e;
+///
+/// let values: HashMap<&str, Vec<&dyn Debug>> = hash_multimap_e! {
+///     "numbers" => [&1, &2.5],
+///     "strings" => &"Hello",
+/// };
+///
+/// assert_eq!(values["numbers"].len(), 2);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hb_hash_multimap_e {
+    {inner: $inner: ty; $($t: tt)*} => {
+        {
+            let mut map = <::hashbrown::HashMap<_, _> as ::core::default::Default>::default();
+            $crate::__multimap!(map $inner, [as _]; $($t)*);
+            map
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::hashbrown::hash_multimap_e!{inner: $crate::__private::alloc::vec::Vec<_>; $($t)*}
+    };
+}
+
+/// Macro for declaring lazily initialized [`HashMap`](::hashbrown::HashMap)
+/// statics.
+///
+/// Declares a [`LazyLock`](::std::sync::LazyLock) that is initialized with
+/// [`hash_map_e!`] when it is accessed for the first time.
+/// See [`lazy_hash_map!`](crate::lazy_hash_map) for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use hashbrown::HashMap;
+///
+/// use map_macro::hashbrown::lazy_hash_map;
+///
+/// lazy_hash_map! {
+///     static GOODBYE: HashMap<&str, &str> = {
+///         "en" => "Goodbye",
+///         "de" => "Auf Wiedersehen",
+///     };
+/// }
+///
+/// assert_eq!(GOODBYE["de"], "Auf Wiedersehen");
+/// ```
+///
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hb_lazy_hash_map {
+    {$($t: tt)*} => {
+        $crate::__lazy!($crate::hashbrown::hash_map_e; $($t)*);
+    };
+}
+
+/// Macro for declaring lazily initialized [`HashSet`](::hashbrown::HashSet)
+/// statics.
+///
+/// Declares a [`LazyLock`](::std::sync::LazyLock) that is initialized with
+/// [`hash_set_e!`] when it is accessed for the first time.
+/// See [`lazy_hash_map!`](crate::lazy_hash_map) for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use hashbrown::HashSet;
+///
+/// use map_macro::hashbrown::lazy_hash_set;
+///
+/// lazy_hash_set! {
+///     static LANGUAGES: HashSet<&str> = { "en", "de", "fr" };
+/// }
+///
+/// assert!(LANGUAGES.contains("de"));
+/// ```
+///
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hb_lazy_hash_set {
+    {$($t: tt)*} => {
+        $crate::__lazy!($crate::hashbrown::hash_set_e; $($t)*);
+    };
 }
 
 #[doc(inline)]
@@ -153,3 +1144,35 @@ pub use __hb_hash_set as hash_set;
 
 #[doc(inline)]
 pub use __hb_hash_set_e as hash_set_e;
+
+#[doc(inline)]
+pub use __hb_try_hash_map as try_hash_map;
+
+#[doc(inline)]
+pub use __hb_try_hash_map_e as try_hash_map_e;
+
+#[doc(inline)]
+pub use __hb_try_hash_set as try_hash_set;
+
+#[doc(inline)]
+pub use __hb_try_hash_set_e as try_hash_set_e;
+
+#[doc(inline)]
+pub use __hb_nested_hash_map as nested_hash_map;
+
+#[doc(inline)]
+pub use __hb_nested_hash_map_e as nested_hash_map_e;
+
+#[doc(inline)]
+pub use __hb_hash_multimap as hash_multimap;
+
+#[doc(inline)]
+pub use __hb_hash_multimap_e as hash_multimap_e;
+
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use __hb_lazy_hash_map as lazy_hash_map;
+
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use __hb_lazy_hash_set as lazy_hash_set;
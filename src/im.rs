@@ -0,0 +1,459 @@
+//! Macros for initializing the persistent collections of [`im`].
+//!
+//! # Example
+//!
+//! ```
+//! use map_macro::im::hash_map;
+//!
+//! let hello = hash_map! {
+//!     "en" => "Hello",
+//!     "de" => "Hallo",
+//!     "fr" => "Bonjour",
+//!     "es" => "Hola",
+//!     "cat" => "Hola",
+//!     "🌍" => "👋",
+//! };
+//!
+//! let updated = hello.update("it", "Ciao");
+//!
+//! assert_eq!(hello.len(), 6);
+//! assert_eq!(updated.len(), 7);
+//! ```
+//!
+//! # Supported Versions of `im`
+//!
+//! The macros from this module support the `15.x` versions of `im`.
+//! The collections are created with their
+//! [`FromIterator`](::core::iter::FromIterator) implementations only, so
+//! earlier versions of `im` are highly likely to work as well.
+//! The collections of `im-rc` are not supported, because the crate has a
+//! different name.
+//! Hash maps and sets are created with the default
+//! [`RandomState`](::std::collections::hash_map::RandomState) hasher.
+//! None of the macros support the `capacity` and `hasher` clauses, because
+//! the persistent collections don't have a capacity and can't be collected
+//! with a custom hasher.
+//!
+//! **Note:** to be compatible with all versions of `im` at once, this crate
+//! doesn't re-export `im`.
+//! That means that (I) you need to specify it as a dependency yourself and
+//! (II) you can't rename it or the macros from this module won't be able to
+//! import the needed types, resulting in a compile-time error.
+//!
+
+/// Macro for creating a [`HashMap`](::im::HashMap).
+///
+/// Syntactic sugar for [`HashMap::from_iter`](::im::HashMap#method.from_iter).
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::im::hash_map;
+///
+/// let goodbye = hash_map! {
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+///     "fr" => "Au revoir",
+///     "es" => "Adios",
+///     "cat" => "Adéu",
+/// };
+///
+/// assert_eq!(goodbye["de"], "Auf Wiedersehen");
+/// ```
+///
+/// # Comprehensions
+///
+/// Supports the same [comprehensions](crate::hash_map#comprehensions),
+/// [spread](crate::hash_map#spread),
+/// [conditional](crate::hash_map#conditional-entries) and
+/// [shorthand](crate::hash_map#shorthand-entries) entries as the
+/// [`hash_map!`](crate::hash_map) macro for the standard library:
+///
+/// ```rust
+/// use map_macro::im::hash_map;
+///
+/// let squares = hash_map! { x => x * x for x in 0..10 if x % 2 == 0 };
+///
+/// assert_eq!(squares[&4], 16);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __im_hash_map {
+    {@list $($t: tt)*} => {
+        $crate::__from_iter!(@list map [] ::im::HashMap<_, _>; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__from_iter!(@entries map [] ::im::HashMap<_, _>; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__im_hash_map map []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`hash_map!`](self::hash_map).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Debug;
+///
+/// use im::HashMap;
+///
+/// use map_macro::im::hash_map_e;
+///
+/// let goodbye: HashMap<&str, &dyn Debug> = hash_map_e! {
+///     "en" => &"Goodbye",
+///     "de" => &"Auf Wiedersehen",
+///     "fr" => &"Au revoir",
+///     "es" => &"Adios",
+///     "cat" => &"Adéu",
+/// };
+///
+/// println!("{:?}", goodbye);
+/// ```
+///
+/// Supports the same [comprehensions](self::hash_map#comprehensions) and
+/// other entries as [`hash_map!`](self::hash_map).
+///
+/// **Note:** the pairs of spread sources are not cast.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __im_hash_map_e {
+    {@list $($t: tt)*} => {
+        $crate::__from_iter!(@list map [as _] ::im::HashMap<_, _>; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__from_iter!(@entries map [as _] ::im::HashMap<_, _>; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__im_hash_map_e map []; $($t)*)
+    };
+}
+
+/// Macro for creating a [`HashSet`](::im::HashSet).
+///
+/// Syntactic sugar for [`HashSet::from_iter`](::im::HashSet#method.from_iter).
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::im::hash_set;
+///
+/// let x = hash_set! { 1, 2, 3, 3, 4 };
+///
+/// assert_eq!(x.len(), 4);
+/// ```
+///
+/// # Comprehensions
+///
+/// Supports the same [comprehensions](crate::hash_set#comprehensions),
+/// [spread](crate::hash_set#spread),
+/// [conditional](crate::hash_set#conditional-elements) and
+/// [range](crate::hash_set#ranges) elements as the
+/// [`hash_set!`](crate::hash_set) macro for the standard library:
+///
+/// ```rust
+/// use map_macro::im::hash_set;
+///
+/// let x = hash_set! { 0, 10..20, x * 100 for x in 1..3 };
+///
+/// assert_eq!(x.len(), 13);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __im_hash_set {
+    {@list $($t: tt)*} => {
+        $crate::__from_iter!(@list set [] ::im::HashSet<_>; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__from_iter!(@entries set [] ::im::HashSet<_>; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__im_hash_set set []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`hash_set!`](self::hash_set).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use im::HashSet;
+///
+/// use map_macro::im::hash_set_e;
+///
+/// enum Foo { A, B, C, D }
+///
+/// let x: HashSet<u8> = hash_set_e! { Foo::A, Foo::B, Foo::C, Foo::C, Foo::D };
+///
+/// assert_eq!(x.len(), 4);
+/// ```
+///
+/// Supports the same [elements](self::hash_set#comprehensions) as
+/// [`hash_set!`](self::hash_set).
+///
+/// **Note:** the elements of spread sources and ranges are not cast.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __im_hash_set_e {
+    {@list $($t: tt)*} => {
+        $crate::__from_iter!(@list set [as _] ::im::HashSet<_>; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__from_iter!(@entries set [as _] ::im::HashSet<_>; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__im_hash_set_e set []; $($t)*)
+    };
+}
+
+/// Macro for creating an [`OrdMap`](::im::OrdMap).
+///
+/// Syntactic sugar for [`OrdMap::from_iter`](::im::OrdMap#method.from_iter).
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::im::ord_map;
+///
+/// let goodbye = ord_map! {
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+///     "fr" => "Au revoir",
+/// };
+///
+/// assert_eq!(goodbye.keys().copied().collect::<Vec<_>>(), ["de", "en", "fr"]);
+/// ```
+///
+/// Supports the same [comprehensions](self::hash_map#comprehensions) and
+/// other entries as [`hash_map!`](self::hash_map).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __im_ord_map {
+    {@list $($t: tt)*} => {
+        $crate::__from_iter!(@list map [] ::im::OrdMap<_, _>; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__from_iter!(@entries map [] ::im::OrdMap<_, _>; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__im_ord_map map []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`ord_map!`](self::ord_map).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use im::OrdMap;
+///
+/// use map_macro::im::ord_map_e;
+///
+/// let lengths: OrdMap<&str, u64> = ord_map_e! {
+///     "en" => 7u8,
+///     "de" => 15u16,
+/// };
+///
+/// assert_eq!(lengths["de"], 15);
+/// ```
+///
+/// **Note:** the pairs of spread sources are not cast.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __im_ord_map_e {
+    {@list $($t: tt)*} => {
+        $crate::__from_iter!(@list map [as _] ::im::OrdMap<_, _>; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__from_iter!(@entries map [as _] ::im::OrdMap<_, _>; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__im_ord_map_e map []; $($t)*)
+    };
+}
+
+/// Macro for creating an [`OrdSet`](::im::OrdSet).
+///
+/// Syntactic sugar for [`OrdSet::from_iter`](::im::OrdSet#method.from_iter).
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::im::ord_set;
+///
+/// let x = ord_set! { 3, 1, 2, 3, 0..=1 };
+///
+/// assert_eq!(x.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3]);
+/// ```
+///
+/// Supports the same [elements](self::hash_set#comprehensions) as
+/// [`hash_set!`](self::hash_set).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __im_ord_set {
+    {@list $($t: tt)*} => {
+        $crate::__from_iter!(@list set [] ::im::OrdSet<_>; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__from_iter!(@entries set [] ::im::OrdSet<_>; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__im_ord_set set []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`ord_set!`](self::ord_set).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use im::OrdSet;
+///
+/// use map_macro::im::ord_set_e;
+///
+/// let x: OrdSet<u64> = ord_set_e! { 1u8, 2u16, 3u32 };
+///
+/// assert_eq!(x.len(), 3);
+/// ```
+///
+/// **Note:** the elements of spread sources and ranges are not cast.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __im_ord_set_e {
+    {@list $($t: tt)*} => {
+        $crate::__from_iter!(@list set [as _] ::im::OrdSet<_>; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__from_iter!(@entries set [as _] ::im::OrdSet<_>; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__im_ord_set_e set []; $($t)*)
+    };
+}
+
+/// Macro for creating a [`Vector`](::im::Vector).
+///
+/// Syntactic sugar for [`Vector::from_iter`](::im::Vector#method.from_iter).
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::im::vector;
+///
+/// let v = vector![1, 2, 3];
+///
+/// let mut updated = v.clone();
+/// updated.push_back(4);
+///
+/// assert_eq!(v.len(), 3);
+/// assert_eq!(updated.len(), 4);
+/// ```
+///
+/// Supports the same [spread](crate::vec_deque#spread) and
+/// [range](crate::vec_deque#ranges) elements as the
+/// [`vec_deque!`](crate::vec_deque) macro for the standard library:
+///
+/// ```rust
+/// use map_macro::im::vector;
+///
+/// let tail = vec![40, 50];
+///
+/// let v = vector![0, 1..4, ..tail];
+///
+/// assert_eq!(v, vector![0, 1, 2, 3, 40, 50]);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __im_vector {
+    [@list $($t: tt)*] => {
+        $crate::__from_iter!(@list seq [] ::im::Vector<_>; $($t)*)
+    };
+    [@entries $($t: tt)*] => {
+        $crate::__from_iter!(@entries seq [] ::im::Vector<_>; $($t)*)
+    };
+    [$($t: tt)*] => {
+        $crate::__list!(__im_vector set []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`vector!`](self::vector).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Debug;
+///
+/// use im::Vector;
+///
+/// use map_macro::im::vector_e;
+///
+/// let v: Vector<&dyn Debug> = vector_e![&"1", &2, &3.0];
+///
+/// assert_eq!(v.len(), 3);
+/// ```
+///
+/// **Note:** the elements of spread sources and ranges are not cast.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __im_vector_e {
+    [@list $($t: tt)*] => {
+        $crate::__from_iter!(@list seq [as _] ::im::Vector<_>; $($t)*)
+    };
+    [@entries $($t: tt)*] => {
+        $crate::__from_iter!(@entries seq [as _] ::im::Vector<_>; $($t)*)
+    };
+    [$($t: tt)*] => {
+        $crate::__list!(__im_vector_e set []; $($t)*)
+    };
+}
+
+#[doc(inline)]
+pub use __im_hash_map as hash_map;
+
+#[doc(inline)]
+pub use __im_hash_map_e as hash_map_e;
+
+#[doc(inline)]
+pub use __im_hash_set as hash_set;
+
+#[doc(inline)]
+pub use __im_hash_set_e as hash_set_e;
+
+#[doc(inline)]
+pub use __im_ord_map as ord_map;
+
+#[doc(inline)]
+pub use __im_ord_map_e as ord_map_e;
+
+#[doc(inline)]
+pub use __im_ord_set as ord_set;
+
+#[doc(inline)]
+pub use __im_ord_set_e as ord_set_e;
+
+#[doc(inline)]
+pub use __im_vector as vector;
+
+#[doc(inline)]
+pub use __im_vector_e as vector_e;
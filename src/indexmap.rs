@@ -0,0 +1,525 @@
+//! Macros for initializing [`indexmap`] maps and sets.
+//!
+//! [`IndexMap`](::indexmap::IndexMap) and [`IndexSet`](::indexmap::IndexSet)
+//! preserve the order in which their entries were inserted, which makes the
+//! collections created by the macros from this module iterate in the same
+//! order as the entries were written.
+//!
+//! # Example
+//!
+//! ```
+//! use map_macro::indexmap::index_map;
+//!
+//! let hello = index_map! {
+//!     "en" => "Hello",
+//!     "de" => "Hallo",
+//!     "fr" => "Bonjour",
+//!     "es" => "Hola",
+//!     "cat" => "Hola",
+//!     "🌍" => "👋",
+//! };
+//!
+//! assert_eq!(hello.keys().next(), Some(&"en"));
+//! assert_eq!(hello.get_index(5), Some((&"🌍", &"👋")));
+//! ```
+//!
+//! If the same key occurs more than once, the key keeps the position of its
+//! first occurrence, but the value of its last occurrence:
+//!
+//! ```
+//! use map_macro::indexmap::index_map;
+//!
+//! let hello = index_map! {
+//!     "en" => "Hello",
+//!     "de" => "Hallo",
+//!     "en" => "Hi",
+//! };
+//!
+//! assert_eq!(hello.get_index(0), Some((&"en", &"Hi")));
+//! ```
+//!
+//! # Supported Versions of `indexmap`
+//!
+//! As of writing this, up to the current `indexmap` version `2` **all**
+//! versions of `indexmap` starting from `1.0` are supported.
+//! The macros only rely on the [`FromIterator`](::core::iter::FromIterator)
+//! implementations of `IndexMap` and `IndexSet`, as well as on the
+//! constructors and methods they share with the collections from the
+//! standard library.
+//! Compatibility can't be guaranteed with future versions of `indexmap` that
+//! break [SemVer compatibility](https://semver.org/#semantic-versioning-specification-semver)
+//! with `2`.
+//!
+//! Unless the `hasher: expr;` clause is used, the created collections use
+//! the default hasher of `indexmap`, which is only available if `indexmap`
+//! is compiled with support for the standard library.
+//!
+//! **Note:** to be compatible with all versions of `indexmap` at once, this
+//! crate doesn't re-export `indexmap`.
+//! That means that (I) you need to specify it as a dependency yourself and
+//! (II) you can't rename it or the macros from this module won't be able to
+//! import the needed types, resulting in a compile-time error.
+//!
+
+/// Macro for creating an [`IndexMap`](::indexmap::IndexMap).
+///
+/// Syntactic sugar for [`IndexMap::from_iter`](::indexmap::IndexMap#method.from_iter).
+/// The entries of the map are ordered the same way they are provided.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::indexmap::index_map;
+///
+/// let goodbye = index_map! {
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+///     "fr" => "Au revoir",
+///     "es" => "Adios",
+///     "cat" => "Adéu",
+/// };
+///
+/// assert_eq!(
+///     goodbye.keys().copied().collect::<Vec<_>>(),
+///     vec!["en", "de", "fr", "es", "cat"],
+/// );
+/// ```
+///
+/// # Custom Hashers
+///
+/// If the first clause of the macro is `hasher: expr;`, the map is created
+/// with [`IndexMap::with_capacity_and_hasher`](::indexmap::IndexMap#method.with_capacity_and_hasher),
+/// using the provided [`BuildHasher`](::core::hash::BuildHasher):
+///
+/// ```rust
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::BuildHasherDefault;
+///
+/// use map_macro::indexmap::index_map;
+///
+/// let goodbye = index_map! {
+///     hasher: BuildHasherDefault::<DefaultHasher>::default();
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+/// };
+/// ```
+///
+/// # Capacity
+///
+/// The map is created with just enough capacity for the provided
+/// elements.
+/// If you intend to add more elements to it later on, you can reserve
+/// additional capacity up front with a leading `capacity: expr;` clause.
+/// The map is then created with a capacity of at least `expr`:
+///
+/// ```rust
+/// use map_macro::indexmap::index_map;
+///
+/// let goodbye = index_map! {
+///     capacity: 10;
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+/// };
+///
+/// assert!(goodbye.capacity() >= 10);
+/// ```
+///
+/// The `capacity` and `hasher` clauses can be combined in any order.
+///
+/// # Comprehensions
+///
+/// Entries can also be computed from iterators with `for` and `if`
+/// comprehension clauses, which can be mixed with literal entries.
+/// See the [`hash_map!`](crate::hash_map#comprehensions) macro for the
+/// standard library for details:
+///
+/// ```rust
+/// use map_macro::indexmap::index_map;
+///
+/// let squares = index_map! { x => x * x for x in 0..10 if x % 2 == 0 };
+///
+/// assert_eq!(squares.get_index(2), Some((&4, &16)));
+/// ```
+///
+/// # Conditional Entries
+///
+/// Entries can be followed by an `if` guard, annotated with attributes like
+/// `#[cfg(...)]` or written as `key =>? option` to only be inserted if
+/// `option` is `Some`.
+/// See the [`hash_map!`](crate::hash_map#conditional-entries) macro for the
+/// standard library for details:
+///
+/// ```rust
+/// use map_macro::indexmap::index_map;
+///
+/// let proxy: Option<&str> = None;
+///
+/// let config = index_map! {
+///     "host" => "localhost",
+///     "log" => "debug" if cfg!(debug_assertions),
+///     "proxy" =>? proxy,
+/// };
+///
+/// assert_eq!(config.get_index(0), Some((&"host", &"localhost")));
+/// assert!(!config.contains_key("proxy"));
+/// ```
+///
+/// # Shorthand Entries
+///
+/// A bare identifier is an entry with the name of the identifier as key and
+/// its value as value.
+/// See the [`hash_map!`](crate::hash_map#shorthand-entries) macro for the
+/// standard library for details:
+///
+/// ```rust
+/// use map_macro::indexmap::index_map;
+///
+/// let width = 16;
+/// let height = 9;
+///
+/// let dimensions = index_map! { width, height };
+///
+/// assert_eq!(dimensions.get_index(0), Some((&"width", &16)));
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ix_index_map {
+    {capacity: $c: expr; hasher: $h: expr; $($t: tt)*} => {
+        $crate::indexmap::index_map!{hasher: $h; capacity: $c; $($t)*}
+    };
+    {hasher: $h: expr; capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut map = ::indexmap::IndexMap::with_capacity_and_hasher($c, $h);
+            $crate::__entries!(@map map []; $($t)*);
+            map
+        }
+    };
+    {hasher: $h: expr; $($t: tt)*} => {
+        {
+            let mut map = ::indexmap::IndexMap::with_hasher($h);
+            $crate::__entries!(@map map []; $($t)*);
+            map
+        }
+    };
+    {capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut map = <::indexmap::IndexMap<_, _> as ::core::default::Default>::default();
+            map.reserve($c);
+            $crate::__entries!(@map map []; $($t)*);
+            map
+        }
+    };
+    {@list $($k: expr => $v: expr),* $(,)?} => {
+        <::indexmap::IndexMap::<_, _> as ::core::iter::FromIterator<_>>::from_iter([$(($k, $v),)*])
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut map = <::indexmap::IndexMap<_, _> as ::core::default::Default>::default();
+            $crate::__entries!(@map map []; $($t)*);
+            map
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__ix_index_map map []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`index_map!`](self::index_map).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Debug;
+///
+/// use indexmap::IndexMap;
+///
+/// use map_macro::indexmap::index_map_e;
+///
+/// let goodbye: IndexMap<&str, &dyn Debug> = index_map_e! {
+///     "en" => &"Goodbye",
+///     "de" => &"Auf Wiedersehen",
+///     "fr" => &"Au revoir",
+///     "es" => &"Adios",
+///     "cat" => &"Adéu",
+/// };
+///
+/// println!("{:?}", goodbye);
+/// ```
+///
+/// Supports the same [`hasher`](self::index_map#custom-hashers) and
+/// [`capacity`](self::index_map#capacity) clauses, as well as the same
+/// [comprehensions](self::index_map#comprehensions),
+/// [conditional](self::index_map#conditional-entries) and
+/// [shorthand](self::index_map#shorthand-entries) entries as
+/// [`index_map!`](self::index_map).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ix_index_map_e {
+    {capacity: $c: expr; hasher: $h: expr; $($t: tt)*} => {
+        $crate::indexmap::index_map_e!{hasher: $h; capacity: $c; $($t)*}
+    };
+    {hasher: $h: expr; capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut map = ::indexmap::IndexMap::with_capacity_and_hasher($c, $h);
+            $crate::__entries!(@map map [as _]; $($t)*);
+            map
+        }
+    };
+    {hasher: $h: expr; $($t: tt)*} => {
+        {
+            let mut map = ::indexmap::IndexMap::with_hasher($h);
+            $crate::__entries!(@map map [as _]; $($t)*);
+            map
+        }
+    };
+    {capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut map = <::indexmap::IndexMap<_, _> as ::core::default::Default>::default();
+            map.reserve($c);
+            $crate::__entries!(@map map [as _]; $($t)*);
+            map
+        }
+    };
+    {@list $($k: expr => $v: expr),* $(,)?} => {
+        <::indexmap::IndexMap::<_, _> as ::core::iter::FromIterator<_>>::from_iter([$(($k as _, $v as _),)*])
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut map = <::indexmap::IndexMap<_, _> as ::core::default::Default>::default();
+            $crate::__entries!(@map map [as _]; $($t)*);
+            map
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__ix_index_map_e map []; $($t)*)
+    };
+}
+
+/// Macro for creating an [`IndexSet`](::indexmap::IndexSet).
+///
+/// Syntactic sugar for [`IndexSet::from_iter`](::indexmap::IndexSet#method.from_iter).
+/// The elements of the set are ordered the same way they are provided.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::indexmap::index_set;
+///
+/// let x = index_set! { 3, 1, 2, 3, 4 };
+///
+/// assert_eq!(x.len(), 4);
+/// assert_eq!(x.iter().copied().collect::<Vec<_>>(), vec![3, 1, 2, 4]);
+/// ```
+///
+/// # Custom Hashers
+///
+/// If the first clause of the macro is `hasher: expr;`, the set is created
+/// with [`IndexSet::with_capacity_and_hasher`](::indexmap::IndexSet#method.with_capacity_and_hasher),
+/// using the provided [`BuildHasher`](::core::hash::BuildHasher):
+///
+/// ```rust
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::BuildHasherDefault;
+///
+/// use map_macro::indexmap::index_set;
+///
+/// let x = index_set! {
+///     hasher: BuildHasherDefault::<DefaultHasher>::default();
+///     1, 2, 3, 3, 4
+/// };
+///
+/// assert_eq!(x.len(), 4);
+/// ```
+///
+/// # Capacity
+///
+/// The set is created with just enough capacity for the provided
+/// elements.
+/// If you intend to add more elements to it later on, you can reserve
+/// additional capacity up front with a leading `capacity: expr;` clause.
+/// The set is then created with a capacity of at least `expr`:
+///
+/// ```rust
+/// use map_macro::indexmap::index_set;
+///
+/// let x = index_set! { capacity: 10; 1, 2, 3, 3, 4 };
+///
+/// assert_eq!(x.len(), 4);
+/// assert!(x.capacity() >= 10);
+/// ```
+///
+/// The `capacity` and `hasher` clauses can be combined in any order.
+///
+/// # Comprehensions
+///
+/// Entries can also be computed from iterators with `for` and `if`
+/// comprehension clauses, which can be mixed with literal entries.
+/// See the [`hash_set!`](crate::hash_set#comprehensions) macro for the
+/// standard library for details:
+///
+/// ```rust
+/// use map_macro::indexmap::index_set;
+///
+/// let remainders = index_set! { 42, x % 3 for x in 0..10 };
+///
+/// assert_eq!(remainders.iter().copied().collect::<Vec<_>>(), vec![42, 0, 1, 2]);
+/// ```
+///
+/// # Conditional Elements
+///
+/// Elements can be followed by an `if` guard or annotated with attributes
+/// like `#[cfg(...)]`.
+/// See the [`hash_set!`](crate::hash_set#conditional-elements) macro for the
+/// standard library for details:
+///
+/// ```rust
+/// use map_macro::indexmap::index_set;
+///
+/// let roles = index_set! { "user", "tester" if false, "admin" };
+///
+/// assert_eq!(roles.iter().copied().collect::<Vec<_>>(), vec!["user", "admin"]);
+/// ```
+///
+/// # Ranges
+///
+/// A range whose start is a literal inserts all items of the range.
+/// See the [`hash_set!`](crate::hash_set#ranges) macro for the standard
+/// library for details:
+///
+/// ```rust
+/// use map_macro::indexmap::index_set;
+///
+/// let s = index_set! { 0, 5..8 };
+///
+/// assert_eq!(s.iter().copied().collect::<Vec<_>>(), vec![0, 5, 6, 7]);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ix_index_set {
+    {capacity: $c: expr; hasher: $h: expr; $($t: tt)*} => {
+        $crate::indexmap::index_set!{hasher: $h; capacity: $c; $($t)*}
+    };
+    {hasher: $h: expr; capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut set = ::indexmap::IndexSet::with_capacity_and_hasher($c, $h);
+            $crate::__entries!(@set set []; $($t)*);
+            set
+        }
+    };
+    {hasher: $h: expr; $($t: tt)*} => {
+        {
+            let mut set = ::indexmap::IndexSet::with_hasher($h);
+            $crate::__entries!(@set set []; $($t)*);
+            set
+        }
+    };
+    {capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut set = <::indexmap::IndexSet<_> as ::core::default::Default>::default();
+            set.reserve($c);
+            $crate::__entries!(@set set []; $($t)*);
+            set
+        }
+    };
+    {@list $($v: expr),* $(,)?} => {
+        <::indexmap::IndexSet::<_> as ::core::iter::FromIterator<_>>::from_iter([$($v,)*])
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut set = <::indexmap::IndexSet<_> as ::core::default::Default>::default();
+            $crate::__entries!(@set set []; $($t)*);
+            set
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__ix_index_set set []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`index_set!`](self::index_set).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Debug;
+///
+/// use indexmap::IndexSet;
+///
+/// use map_macro::indexmap::index_set_e;
+///
+/// enum Foo { A, B, C, D }
+///
+/// let x: IndexSet<u8> = index_set_e! { Foo::A, Foo::B, Foo::C, Foo::C, Foo::D };
+///
+/// assert_eq!(x.len(), 4);
+/// assert_eq!(x.get_index(1), Some(&1));
+/// ```
+///
+/// Supports the same [`hasher`](self::index_set#custom-hashers) and
+/// [`capacity`](self::index_set#capacity) clauses, as well as the same
+/// [comprehensions](self::index_set#comprehensions),
+/// [conditional](self::index_set#conditional-elements) and
+/// [range](self::index_set#ranges) elements as
+/// [`index_set!`](self::index_set).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ix_index_set_e {
+    {capacity: $c: expr; hasher: $h: expr; $($t: tt)*} => {
+        $crate::indexmap::index_set_e!{hasher: $h; capacity: $c; $($t)*}
+    };
+    {hasher: $h: expr; capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut set = ::indexmap::IndexSet::with_capacity_and_hasher($c, $h);
+            $crate::__entries!(@set set [as _]; $($t)*);
+            set
+        }
+    };
+    {hasher: $h: expr; $($t: tt)*} => {
+        {
+            let mut set = ::indexmap::IndexSet::with_hasher($h);
+            $crate::__entries!(@set set [as _]; $($t)*);
+            set
+        }
+    };
+    {capacity: $c: expr; $($t: tt)*} => {
+        {
+            let mut set = <::indexmap::IndexSet<_> as ::core::default::Default>::default();
+            set.reserve($c);
+            $crate::__entries!(@set set [as _]; $($t)*);
+            set
+        }
+    };
+    {@list $($v: expr),* $(,)?} => {
+        <::indexmap::IndexSet::<_> as ::core::iter::FromIterator<_>>::from_iter([$($v as _,)*])
+    };
+    {@entries $($t: tt)*} => {
+        {
+            let mut set = <::indexmap::IndexSet<_> as ::core::default::Default>::default();
+            $crate::__entries!(@set set [as _]; $($t)*);
+            set
+        }
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__ix_index_set_e set []; $($t)*)
+    };
+}
+
+#[doc(inline)]
+pub use __ix_index_map as index_map;
+
+#[doc(inline)]
+pub use __ix_index_map_e as index_map_e;
+
+#[doc(inline)]
+pub use __ix_index_set as index_set;
+
+#[doc(inline)]
+pub use __ix_index_set_e as index_set_e;
@@ -133,5 +133,65 @@ extern crate std;
 #[cfg(feature = "hashbrown")]
 pub mod hashbrown;
 
+#[cfg(feature = "indexmap")]
+pub mod indexmap;
+
+#[cfg(feature = "dashmap")]
+pub mod dashmap;
+
+#[cfg(feature = "im")]
+pub mod im;
+
+#[cfg(feature = "rpds")]
+pub mod rpds;
+
+#[cfg(feature = "smallvec")]
+pub mod smallvec;
+
+#[cfg(feature = "arrayvec")]
+pub mod arrayvec;
+
+#[cfg(feature = "tinyvec")]
+pub mod tinyvec;
+
+#[cfg(feature = "checked")]
+pub mod checked;
+
+#[cfg(feature = "static")]
+pub mod static_map;
+
+#[cfg(feature = "serde_json")]
+pub mod serde_json;
+
+#[cfg(feature = "alloc")]
+mod _alloc;
+
 #[cfg(feature = "std")]
 mod _std;
+
+#[cfg(feature = "std")]
+mod _lazy;
+
+#[cfg(feature = "std")]
+mod _nested;
+
+#[cfg(feature = "alloc")]
+mod _try;
+
+#[cfg(feature = "std")]
+mod _bi_map;
+
+mod _error;
+mod _priority;
+
+mod _core;
+mod _internal;
+
+#[doc(hidden)]
+pub mod __private;
+
+pub use _error::DuplicateKeyError;
+pub use _priority::Prioritized;
+
+#[cfg(feature = "std")]
+pub use _bi_map::BiHashMap;
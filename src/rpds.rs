@@ -0,0 +1,533 @@
+//! Macros for initializing the persistent collections of [`rpds`].
+//!
+//! # Example
+//!
+//! ```
+//! use map_macro::rpds::ht_map;
+//!
+//! let hello = ht_map! {
+//!     "en" => "Hello",
+//!     "de" => "Hallo",
+//!     "fr" => "Bonjour",
+//!     "es" => "Hola",
+//!     "cat" => "Hola",
+//!     "🌍" => "👋",
+//! };
+//!
+//! let updated = hello.insert("it", "Ciao");
+//!
+//! assert_eq!(hello.size(), 6);
+//! assert_eq!(updated.size(), 7);
+//! ```
+//!
+//! # Supported Versions of `rpds`
+//!
+//! The macros from this module support the `1.x` versions of `rpds`.
+//! The collections are created with their
+//! [`FromIterator`](::core::iter::FromIterator) implementations only, so
+//! earlier versions of `rpds` are highly likely to work as well.
+//! The collections are created with the default pointer kind, i.e. they are
+//! backed by [`Rc`](::std::rc::Rc) and not by [`Arc`](::std::sync::Arc) like
+//! the `Sync` variants, e.g. `HashTrieMapSync`.
+//! Hash maps and sets are created with the default
+//! [`RandomState`](::std::collections::hash_map::RandomState) hasher.
+//! None of the macros support the `capacity` and `hasher` clauses, because
+//! the persistent collections don't have a capacity and can't be collected
+//! with a custom hasher.
+//!
+//! **Note:** to be compatible with all versions of `rpds` at once, this
+//! crate doesn't re-export `rpds`.
+//! That means that (I) you need to specify it as a dependency yourself and
+//! (II) you can't rename it or the macros from this module won't be able to
+//! import the needed types, resulting in a compile-time error.
+//!
+
+/// Macro for creating a [`HashTrieMap`](::rpds::HashTrieMap).
+///
+/// Syntactic sugar for [`HashTrieMap::from_iter`](::rpds::HashTrieMap#method.from_iter).
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::rpds::ht_map;
+///
+/// let goodbye = ht_map! {
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+///     "fr" => "Au revoir",
+///     "es" => "Adios",
+///     "cat" => "Adéu",
+/// };
+///
+/// assert_eq!(goodbye["de"], "Auf Wiedersehen");
+/// ```
+///
+/// # Comprehensions
+///
+/// Supports the same [comprehensions](crate::hash_map#comprehensions),
+/// [spread](crate::hash_map#spread),
+/// [conditional](crate::hash_map#conditional-entries) and
+/// [shorthand](crate::hash_map#shorthand-entries) entries as the
+/// [`hash_map!`](crate::hash_map) macro for the standard library:
+///
+/// ```rust
+/// use map_macro::rpds::ht_map;
+///
+/// let squares = ht_map! { x => x * x for x in 0..10 if x % 2 == 0 };
+///
+/// assert_eq!(squares[&4], 16);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rpds_ht_map {
+    {@list $($t: tt)*} => {
+        $crate::__from_iter!(@list map [] ::rpds::HashTrieMap<_, _>; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__from_iter!(@entries map [] ::rpds::HashTrieMap<_, _>; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__rpds_ht_map map []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`ht_map!`](self::ht_map).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Debug;
+///
+/// use rpds::HashTrieMap;
+///
+/// use map_macro::rpds::ht_map_e;
+///
+/// let goodbye: HashTrieMap<&str, &dyn Debug> = ht_map_e! {
+///     "en" => &"Goodbye",
+///     "de" => &"Auf Wiedersehen",
+///     "fr" => &"Au revoir",
+///     "es" => &"Adios",
+///     "cat" => &"Adéu",
+/// };
+///
+/// println!("{:?}", goodbye);
+/// ```
+///
+/// Supports the same [comprehensions](self::ht_map#comprehensions) and
+/// other entries as [`ht_map!`](self::ht_map).
+///
+/// **Note:** the pairs of spread sources are not cast.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rpds_ht_map_e {
+    {@list $($t: tt)*} => {
+        $crate::__from_iter!(@list map [as _] ::rpds::HashTrieMap<_, _>; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__from_iter!(@entries map [as _] ::rpds::HashTrieMap<_, _>; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__rpds_ht_map_e map []; $($t)*)
+    };
+}
+
+/// Macro for creating a [`HashTrieSet`](::rpds::HashTrieSet).
+///
+/// Syntactic sugar for [`HashTrieSet::from_iter`](::rpds::HashTrieSet#method.from_iter).
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::rpds::ht_set;
+///
+/// let x = ht_set! { 1, 2, 3, 3, 4 };
+///
+/// assert_eq!(x.size(), 4);
+/// ```
+///
+/// # Comprehensions
+///
+/// Supports the same [comprehensions](crate::hash_set#comprehensions),
+/// [spread](crate::hash_set#spread),
+/// [conditional](crate::hash_set#conditional-elements) and
+/// [range](crate::hash_set#ranges) elements as the
+/// [`hash_set!`](crate::hash_set) macro for the standard library:
+///
+/// ```rust
+/// use map_macro::rpds::ht_set;
+///
+/// let x = ht_set! { 0, 10..20, x * 100 for x in 1..3 };
+///
+/// assert_eq!(x.size(), 13);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rpds_ht_set {
+    {@list $($t: tt)*} => {
+        $crate::__from_iter!(@list set [] ::rpds::HashTrieSet<_>; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__from_iter!(@entries set [] ::rpds::HashTrieSet<_>; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__rpds_ht_set set []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`ht_set!`](self::ht_set).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use rpds::HashTrieSet;
+///
+/// use map_macro::rpds::ht_set_e;
+///
+/// enum Foo { A, B, C, D }
+///
+/// let x: HashTrieSet<u8> = ht_set_e! { Foo::A, Foo::B, Foo::C, Foo::C, Foo::D };
+///
+/// assert_eq!(x.size(), 4);
+/// ```
+///
+/// Supports the same [elements](self::ht_set#comprehensions) as
+/// [`ht_set!`](self::ht_set).
+///
+/// **Note:** the elements of spread sources and ranges are not cast.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rpds_ht_set_e {
+    {@list $($t: tt)*} => {
+        $crate::__from_iter!(@list set [as _] ::rpds::HashTrieSet<_>; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__from_iter!(@entries set [as _] ::rpds::HashTrieSet<_>; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__rpds_ht_set_e set []; $($t)*)
+    };
+}
+
+/// Macro for creating a [`RedBlackTreeMap`](::rpds::RedBlackTreeMap).
+///
+/// Syntactic sugar for [`RedBlackTreeMap::from_iter`](::rpds::RedBlackTreeMap#method.from_iter).
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::rpds::rbt_map;
+///
+/// let goodbye = rbt_map! {
+///     "en" => "Goodbye",
+///     "de" => "Auf Wiedersehen",
+///     "fr" => "Au revoir",
+/// };
+///
+/// assert_eq!(goodbye.keys().copied().collect::<Vec<_>>(), ["de", "en", "fr"]);
+/// ```
+///
+/// Supports the same [comprehensions](self::ht_map#comprehensions) and
+/// other entries as [`ht_map!`](self::ht_map).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rpds_rbt_map {
+    {@list $($t: tt)*} => {
+        $crate::__from_iter!(@list map [] ::rpds::RedBlackTreeMap<_, _>; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__from_iter!(@entries map [] ::rpds::RedBlackTreeMap<_, _>; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__rpds_rbt_map map []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`rbt_map!`](self::rbt_map).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use rpds::RedBlackTreeMap;
+///
+/// use map_macro::rpds::rbt_map_e;
+///
+/// let lengths: RedBlackTreeMap<&str, u64> = rbt_map_e! {
+///     "en" => 7u8,
+///     "de" => 15u16,
+/// };
+///
+/// assert_eq!(lengths["de"], 15);
+/// ```
+///
+/// **Note:** the pairs of spread sources are not cast.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rpds_rbt_map_e {
+    {@list $($t: tt)*} => {
+        $crate::__from_iter!(@list map [as _] ::rpds::RedBlackTreeMap<_, _>; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__from_iter!(@entries map [as _] ::rpds::RedBlackTreeMap<_, _>; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__rpds_rbt_map_e map []; $($t)*)
+    };
+}
+
+/// Macro for creating a [`RedBlackTreeSet`](::rpds::RedBlackTreeSet).
+///
+/// Syntactic sugar for [`RedBlackTreeSet::from_iter`](::rpds::RedBlackTreeSet#method.from_iter).
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::rpds::rbt_set;
+///
+/// let x = rbt_set! { 3, 1, 2, 3, 0..=1 };
+///
+/// assert_eq!(x.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3]);
+/// ```
+///
+/// Supports the same [elements](self::ht_set#comprehensions) as
+/// [`ht_set!`](self::ht_set).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rpds_rbt_set {
+    {@list $($t: tt)*} => {
+        $crate::__from_iter!(@list set [] ::rpds::RedBlackTreeSet<_>; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__from_iter!(@entries set [] ::rpds::RedBlackTreeSet<_>; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__rpds_rbt_set set []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`rbt_set!`](self::rbt_set).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use rpds::RedBlackTreeSet;
+///
+/// use map_macro::rpds::rbt_set_e;
+///
+/// let x: RedBlackTreeSet<u64> = rbt_set_e! { 1u8, 2u16, 3u32 };
+///
+/// assert_eq!(x.size(), 3);
+/// ```
+///
+/// **Note:** the elements of spread sources and ranges are not cast.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rpds_rbt_set_e {
+    {@list $($t: tt)*} => {
+        $crate::__from_iter!(@list set [as _] ::rpds::RedBlackTreeSet<_>; $($t)*)
+    };
+    {@entries $($t: tt)*} => {
+        $crate::__from_iter!(@entries set [as _] ::rpds::RedBlackTreeSet<_>; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__list!(__rpds_rbt_set_e set []; $($t)*)
+    };
+}
+
+/// Macro for creating a [`Vector`](::rpds::Vector).
+///
+/// Syntactic sugar for [`Vector::from_iter`](::rpds::Vector#method.from_iter).
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::rpds::vector;
+///
+/// let v = vector![1, 2, 3];
+///
+/// let updated = v.push_back(4);
+///
+/// assert_eq!(v.len(), 3);
+/// assert_eq!(updated.len(), 4);
+/// ```
+///
+/// Supports the same [spread](crate::vec_deque#spread) and
+/// [range](crate::vec_deque#ranges) elements as the
+/// [`vec_deque!`](crate::vec_deque) macro for the standard library:
+///
+/// ```rust
+/// use map_macro::rpds::vector;
+///
+/// let tail = vec![40, 50];
+///
+/// let v = vector![0, 1..4, ..tail];
+///
+/// assert_eq!(v, vector![0, 1, 2, 3, 40, 50]);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rpds_vector {
+    [@list $($t: tt)*] => {
+        $crate::__from_iter!(@list seq [] ::rpds::Vector<_>; $($t)*)
+    };
+    [@entries $($t: tt)*] => {
+        $crate::__from_iter!(@entries seq [] ::rpds::Vector<_>; $($t)*)
+    };
+    [$($t: tt)*] => {
+        $crate::__list!(__rpds_vector set []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`vector!`](self::vector).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Debug;
+///
+/// use rpds::Vector;
+///
+/// use map_macro::rpds::vector_e;
+///
+/// let v: Vector<&dyn Debug> = vector_e![&"1", &2, &3.0];
+///
+/// assert_eq!(v.len(), 3);
+/// ```
+///
+/// **Note:** the elements of spread sources and ranges are not cast.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rpds_vector_e {
+    [@list $($t: tt)*] => {
+        $crate::__from_iter!(@list seq [as _] ::rpds::Vector<_>; $($t)*)
+    };
+    [@entries $($t: tt)*] => {
+        $crate::__from_iter!(@entries seq [as _] ::rpds::Vector<_>; $($t)*)
+    };
+    [$($t: tt)*] => {
+        $crate::__list!(__rpds_vector_e set []; $($t)*)
+    };
+}
+
+/// Macro for creating a [`List`](::rpds::List).
+///
+/// Syntactic sugar for [`List::from_iter`](::rpds::List#method.from_iter).
+/// The first element of the macro is the first element of the list.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::rpds::list;
+///
+/// let l = list![1, 2, 3];
+///
+/// let updated = l.push_front(0);
+///
+/// assert_eq!(l.first(), Some(&1));
+/// assert_eq!(updated.first(), Some(&0));
+/// ```
+///
+/// Supports the same [elements](self::vector) as [`vector!`](self::vector).
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rpds_list {
+    [@list $($t: tt)*] => {
+        $crate::__from_iter!(@list seq [] ::rpds::List<_>; $($t)*)
+    };
+    [@entries $($t: tt)*] => {
+        $crate::__from_iter!(@entries seq [] ::rpds::List<_>; $($t)*)
+    };
+    [$($t: tt)*] => {
+        $crate::__list!(__rpds_list set []; $($t)*)
+    };
+}
+
+/// Explicitly typed equivalent of [`list!`](self::list).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Debug;
+///
+/// use rpds::List;
+///
+/// use map_macro::rpds::list_e;
+///
+/// let l: List<&dyn Debug> = list_e![&"1", &2, &3.0];
+///
+/// assert_eq!(l.len(), 3);
+/// ```
+///
+/// **Note:** the elements of spread sources and ranges are not cast.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rpds_list_e {
+    [@list $($t: tt)*] => {
+        $crate::__from_iter!(@list seq [as _] ::rpds::List<_>; $($t)*)
+    };
+    [@entries $($t: tt)*] => {
+        $crate::__from_iter!(@entries seq [as _] ::rpds::List<_>; $($t)*)
+    };
+    [$($t: tt)*] => {
+        $crate::__list!(__rpds_list_e set []; $($t)*)
+    };
+}
+
+#[doc(inline)]
+pub use __rpds_ht_map as ht_map;
+
+#[doc(inline)]
+pub use __rpds_ht_map_e as ht_map_e;
+
+#[doc(inline)]
+pub use __rpds_ht_set as ht_set;
+
+#[doc(inline)]
+pub use __rpds_ht_set_e as ht_set_e;
+
+#[doc(inline)]
+pub use __rpds_rbt_map as rbt_map;
+
+#[doc(inline)]
+pub use __rpds_rbt_map_e as rbt_map_e;
+
+#[doc(inline)]
+pub use __rpds_rbt_set as rbt_set;
+
+#[doc(inline)]
+pub use __rpds_rbt_set_e as rbt_set_e;
+
+#[doc(inline)]
+pub use __rpds_vector as vector;
+
+#[doc(inline)]
+pub use __rpds_vector_e as vector_e;
+
+#[doc(inline)]
+pub use __rpds_list as list;
+
+#[doc(inline)]
+pub use __rpds_list_e as list_e;
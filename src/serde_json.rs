@@ -0,0 +1,388 @@
+//! Macros for initializing [`serde_json`] objects.
+//!
+//! # Example
+//!
+//! ```
+//! use serde_json::json;
+//!
+//! use map_macro::serde_json::json_map;
+//!
+//! let hello = json_map! {
+//!     "en" => "Hello",
+//!     "de" => "Hallo",
+//!     "lengths" => [5, 5],
+//! };
+//!
+//! assert_eq!(hello["de"], "Hallo");
+//! assert_eq!(hello["lengths"], json!([5, 5]));
+//! ```
+//!
+//! The values are converted with [`serde_json::to_value`], which fails for
+//! some values, e.g. maps with keys that aren't strings.
+//! [`json_map!`](crate::serde_json::json_map) panics in that case, while
+//! [`try_json_map!`](crate::serde_json::try_json_map) returns the error.
+//!
+//! # Supported Versions of `serde_json`
+//!
+//! The macros from this module support the `1.x` versions of `serde_json`.
+//! Unlike the other modules of this crate, it depends on `serde` and
+//! `serde_json`, because converting the values to JSON requires their
+//! [`Serialize`](::serde::Serialize) trait.
+//! The [`Map`](::serde_json::Map) created by the macros is the one of the
+//! `serde_json` version that your crate depends on, as long as it is a `1.x`
+//! version.
+//!
+
+/// Macro for creating a JSON object, i.e. a
+/// [`Map<String, Value>`](::serde_json::Map).
+///
+/// Keys are converted with [`Into<String>`] and values with
+/// [`serde_json::to_value`](::serde_json::to_value), so every type that
+/// implements [`Serialize`](::serde::Serialize) can be used as a value,
+/// including arrays, vectors and options:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+///
+/// use map_macro::serde_json::json_map;
+///
+/// let user = json_map! {
+///     "name" => "Alice",
+///     String::from("age") => 42,
+///     'x' => [1, 2],
+///     "email" => None::<&str>,
+/// };
+///
+/// assert_eq!(
+///     Value::Object(user),
+///     json!({ "name": "Alice", "age": 42, "x": [1, 2], "email": null }),
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Creating the map panics if a value can't be converted to JSON, e.g.
+/// because it is a map with keys that aren't strings.
+/// Use [`try_json_map!`](crate::serde_json::try_json_map) to get the error
+/// instead.
+///
+/// # Nesting
+///
+/// Like with [`nested_hash_map!`](crate::nested_hash_map), values written as
+/// `{ ... }` are nested objects, which take the same entries as `json_map!`
+/// itself.
+/// So are the elements of an array value written as `{ ... }`.
+/// Any other [`Value`](::serde_json::Value), for example created by
+/// [`json!`](::serde_json::json), can be used as a value as well:
+///
+/// ```rust
+/// use serde_json::json;
+///
+/// use map_macro::serde_json::json_map;
+///
+/// let config = json_map! {
+///     "server" => {
+///         "host" => "localhost",
+///         "ports" => [80, 443],
+///     },
+///     "users" => [{ "name" => "Alice" }, { "name" => "Bob" }],
+///     "features" => json!(["tls", 1]),
+/// };
+///
+/// assert_eq!(config["server"]["ports"][1], 443);
+/// assert_eq!(config["users"][1]["name"], "Bob");
+/// assert_eq!(config["features"][0], "tls");
+/// ```
+///
+/// Wrap block expressions in parentheses to keep them from being interpreted
+/// as objects:
+///
+/// ```rust
+/// use map_macro::serde_json::json_map;
+///
+/// let m = json_map! { "a" => ({ let x = 1; x + 1 }) };
+///
+/// assert_eq!(m["a"], 2);
+/// ```
+///
+/// # Spread
+///
+/// An entry written as `..iterable` inserts all pairs of `iterable`, whose
+/// keys and values are converted like the other entries.
+/// This merges objects, with later entries overriding earlier ones:
+///
+/// ```rust
+/// use map_macro::serde_json::json_map;
+///
+/// let defaults = json_map! { "color" => "auto", "verbose" => false };
+///
+/// let config = json_map! {
+///     ..defaults,
+///     "verbose" => true,
+/// };
+///
+/// assert_eq!(config["color"], "auto");
+/// assert_eq!(config["verbose"], true);
+/// ```
+///
+/// # Comprehensions
+///
+/// Supports the same [comprehensions](crate::hash_map#comprehensions),
+/// [conditional](crate::hash_map#conditional-entries) and
+/// [shorthand](crate::hash_map#shorthand-entries) entries as the
+/// [`hash_map!`](crate::hash_map) macro:
+///
+/// ```rust
+/// use map_macro::serde_json::json_map;
+///
+/// let name = "Alice";
+/// let nickname: Option<&str> = None;
+///
+/// let user = json_map! {
+///     name,
+///     "nickname" =>? nickname,
+///     format!("score_{}", i) => i * 10 for i in 0..2,
+/// };
+///
+/// assert_eq!(user.len(), 3);
+/// assert_eq!(user["score_1"], 10);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sj_json_map {
+    {@list $fin: ident $($k: expr => $v: expr),* $(,)?} => {
+        {
+            #[allow(unused_mut)]
+            let mut map = <$crate::__private::JsonMap as ::core::default::Default>::default();
+            $(map.insert($k, $v);)*
+            map.$fin()
+        }
+    };
+    {@entries $fin: ident $($t: tt)*} => {
+        {
+            let mut map = <$crate::__private::JsonMap as ::core::default::Default>::default();
+            $crate::__entries!(@map map []; $($t)*);
+            map.$fin()
+        }
+    };
+    // nested objects and arrays of them, whose errors are passed on to the
+    // object containing them
+    {@value {$($m: tt)*}} => {
+        $crate::__private::JsonObject($crate::__sj_json_map!(@object into_result $($m)*))
+    };
+    {@value [$($v: tt),* $(,)?]} => {
+        [$($crate::__sj_json_map!(@value $v)),*]
+    };
+    {@value $v: expr} => {
+        $v
+    };
+    // entries are rewritten until none of their values is written as a
+    // nested object or an array anymore, then passed to `__list!`
+    {@nest $fin: ident [$($acc: tt)*];} => {
+        $crate::__list!(__sj_json_map map [$fin]; $($acc)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; #[$m: meta] $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* #[$m]]; $($rest)*)
+    };
+    // entries whose values are single token trees, e.g. nested objects, are
+    // rewritten up to eight at a time, stopping in front of an attribute
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: tt, # $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $crate::__sj_json_map!(@value $av),]; # $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, # $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $crate::__sj_json_map!(@value $av), $bk => $crate::__sj_json_map!(@value $bv),]; # $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt, # $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $crate::__sj_json_map!(@value $av), $bk => $crate::__sj_json_map!(@value $bv), $ck => $crate::__sj_json_map!(@value $cv),]; # $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt, $dk: expr => $dv: tt, # $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $crate::__sj_json_map!(@value $av), $bk => $crate::__sj_json_map!(@value $bv), $ck => $crate::__sj_json_map!(@value $cv), $dk => $crate::__sj_json_map!(@value $dv),]; # $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt, $dk: expr => $dv: tt, $ek: expr => $ev: tt, # $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $crate::__sj_json_map!(@value $av), $bk => $crate::__sj_json_map!(@value $bv), $ck => $crate::__sj_json_map!(@value $cv), $dk => $crate::__sj_json_map!(@value $dv), $ek => $crate::__sj_json_map!(@value $ev),]; # $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt, $dk: expr => $dv: tt, $ek: expr => $ev: tt, $fk: expr => $fv: tt, # $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $crate::__sj_json_map!(@value $av), $bk => $crate::__sj_json_map!(@value $bv), $ck => $crate::__sj_json_map!(@value $cv), $dk => $crate::__sj_json_map!(@value $dv), $ek => $crate::__sj_json_map!(@value $ev), $fk => $crate::__sj_json_map!(@value $fv),]; # $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt, $dk: expr => $dv: tt, $ek: expr => $ev: tt, $fk: expr => $fv: tt, $gk: expr => $gv: tt, # $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $crate::__sj_json_map!(@value $av), $bk => $crate::__sj_json_map!(@value $bv), $ck => $crate::__sj_json_map!(@value $cv), $dk => $crate::__sj_json_map!(@value $dv), $ek => $crate::__sj_json_map!(@value $ev), $fk => $crate::__sj_json_map!(@value $fv), $gk => $crate::__sj_json_map!(@value $gv),]; # $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt, $dk: expr => $dv: tt, $ek: expr => $ev: tt, $fk: expr => $fv: tt, $gk: expr => $gv: tt, $hk: expr => $hv: tt $(, $($rest: tt)*)?} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $crate::__sj_json_map!(@value $av), $bk => $crate::__sj_json_map!(@value $bv), $ck => $crate::__sj_json_map!(@value $cv), $dk => $crate::__sj_json_map!(@value $dv), $ek => $crate::__sj_json_map!(@value $ev), $fk => $crate::__sj_json_map!(@value $fv), $gk => $crate::__sj_json_map!(@value $gv), $hk => $crate::__sj_json_map!(@value $hv),]; $($($rest)*)?)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt, $dk: expr => $dv: tt, $ek: expr => $ev: tt, $fk: expr => $fv: tt, $gk: expr => $gv: tt $(, $($rest: tt)*)?} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $crate::__sj_json_map!(@value $av), $bk => $crate::__sj_json_map!(@value $bv), $ck => $crate::__sj_json_map!(@value $cv), $dk => $crate::__sj_json_map!(@value $dv), $ek => $crate::__sj_json_map!(@value $ev), $fk => $crate::__sj_json_map!(@value $fv), $gk => $crate::__sj_json_map!(@value $gv),]; $($($rest)*)?)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt, $dk: expr => $dv: tt, $ek: expr => $ev: tt, $fk: expr => $fv: tt $(, $($rest: tt)*)?} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $crate::__sj_json_map!(@value $av), $bk => $crate::__sj_json_map!(@value $bv), $ck => $crate::__sj_json_map!(@value $cv), $dk => $crate::__sj_json_map!(@value $dv), $ek => $crate::__sj_json_map!(@value $ev), $fk => $crate::__sj_json_map!(@value $fv),]; $($($rest)*)?)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt, $dk: expr => $dv: tt, $ek: expr => $ev: tt $(, $($rest: tt)*)?} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $crate::__sj_json_map!(@value $av), $bk => $crate::__sj_json_map!(@value $bv), $ck => $crate::__sj_json_map!(@value $cv), $dk => $crate::__sj_json_map!(@value $dv), $ek => $crate::__sj_json_map!(@value $ev),]; $($($rest)*)?)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt, $dk: expr => $dv: tt $(, $($rest: tt)*)?} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $crate::__sj_json_map!(@value $av), $bk => $crate::__sj_json_map!(@value $bv), $ck => $crate::__sj_json_map!(@value $cv), $dk => $crate::__sj_json_map!(@value $dv),]; $($($rest)*)?)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt, $ck: expr => $cv: tt $(, $($rest: tt)*)?} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $crate::__sj_json_map!(@value $av), $bk => $crate::__sj_json_map!(@value $bv), $ck => $crate::__sj_json_map!(@value $cv),]; $($($rest)*)?)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: tt, $bk: expr => $bv: tt $(, $($rest: tt)*)?} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $crate::__sj_json_map!(@value $av), $bk => $crate::__sj_json_map!(@value $bv),]; $($($rest)*)?)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: tt $(, $($rest: tt)*)?} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $crate::__sj_json_map!(@value $av),]; $($($rest)*)?)
+    };
+    // nested objects and arrays followed by a guard or a comprehension, the
+    // rest of which is copied until the next `,`
+    {@nest $fin: ident [$($acc: tt)*]; $k: expr => {$($m: tt)*} $($rest: tt)*} => {
+        $crate::__sj_json_map!(
+            @copy $fin [$($acc)* $k => $crate::__sj_json_map!(@value {$($m)*})];
+            $($rest)*
+        )
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $k: expr => [$($v: tt)*] $($rest: tt)*} => {
+        $crate::__sj_json_map!(
+            @copy $fin [$($acc)* $k => $crate::__sj_json_map!(@value [$($v)*])];
+            $($rest)*
+        )
+    };
+    // the values of other entries are expressions, which are rewritten up to
+    // eight at a time as well, stopping in front of an attribute, a nested
+    // object or an array, which would be parsed as an expression otherwise
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, # $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av,]; # $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $k: expr => {$($m: tt)*} $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av,]; $k => {$($m)*} $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $k: expr => [$($m: tt)*] $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av,]; $k => [$($m)*] $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, # $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv,]; # $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $k: expr => {$($m: tt)*} $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv,]; $k => {$($m)*} $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $k: expr => [$($m: tt)*] $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv,]; $k => [$($m)*] $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, # $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv, $ck => $cv,]; # $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $k: expr => {$($m: tt)*} $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv, $ck => $cv,]; $k => {$($m)*} $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $k: expr => [$($m: tt)*] $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv, $ck => $cv,]; $k => [$($m)*] $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, # $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv,]; # $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $k: expr => {$($m: tt)*} $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv,]; $k => {$($m)*} $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $k: expr => [$($m: tt)*] $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv,]; $k => [$($m)*] $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, # $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv, $ek => $ev,]; # $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $k: expr => {$($m: tt)*} $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv, $ek => $ev,]; $k => {$($m)*} $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $k: expr => [$($m: tt)*] $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv, $ek => $ev,]; $k => [$($m)*] $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, # $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv, $ek => $ev, $fk => $fv,]; # $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, $k: expr => {$($m: tt)*} $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv, $ek => $ev, $fk => $fv,]; $k => {$($m)*} $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, $k: expr => [$($m: tt)*] $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv, $ek => $ev, $fk => $fv,]; $k => [$($m)*] $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, $gk: expr => $gv: expr, # $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv, $ek => $ev, $fk => $fv, $gk => $gv,]; # $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, $gk: expr => $gv: expr, $k: expr => {$($m: tt)*} $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv, $ek => $ev, $fk => $fv, $gk => $gv,]; $k => {$($m)*} $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, $gk: expr => $gv: expr, $k: expr => [$($m: tt)*] $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv, $ek => $ev, $fk => $fv, $gk => $gv,]; $k => [$($m)*] $($rest)*)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $ak: expr => $av: expr, $bk: expr => $bv: expr, $ck: expr => $cv: expr, $dk: expr => $dv: expr, $ek: expr => $ev: expr, $fk: expr => $fv: expr, $gk: expr => $gv: expr, $hk: expr => $hv: expr $(, $($rest: tt)*)?} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $ak => $av, $bk => $bv, $ck => $cv, $dk => $dv, $ek => $ev, $fk => $fv, $gk => $gv, $hk => $hv,]; $($($rest)*)?)
+    };
+    {@nest $fin: ident [$($acc: tt)*]; $k: expr => $v: expr $(, $($rest: tt)*)?} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* $k => $v,]; $($($rest)*)?)
+    };
+    {@nest $fin: ident $acc: tt; $($rest: tt)*} => {
+        $crate::__sj_json_map!(@copy $fin $acc; $($rest)*)
+    };
+    {@copy $fin: ident [$($acc: tt)*];} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)*];)
+    };
+    {@copy $fin: ident [$($acc: tt)*]; , $($rest: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin [$($acc)* ,]; $($rest)*)
+    };
+    {@copy $fin: ident [$($acc: tt)*]; $t: tt $($rest: tt)*} => {
+        $crate::__sj_json_map!(@copy $fin [$($acc)* $t]; $($rest)*)
+    };
+    // entries whose keys and values are single token trees don't need to
+    // be rewritten
+    {@object $fin: ident $($k: tt => $v: tt),* $(,)?} => {
+        $crate::__sj_json_map!{@list $fin $($k => $crate::__sj_json_map!(@value $v)),*}
+    };
+    {@object $fin: ident $($t: tt)*} => {
+        $crate::__sj_json_map!(@nest $fin []; $($t)*)
+    };
+    {$($t: tt)*} => {
+        $crate::__sj_json_map!(@object into_inner $($t)*)
+    };
+}
+
+#[doc(inline)]
+pub use __sj_json_map as json_map;
+
+/// Fallible version of [`json_map!`](crate::serde_json::json_map).
+///
+/// Accepts the same entries as `json_map!`, but returns the
+/// [`Error`](::serde_json::Error) of the first value that can't be converted
+/// to JSON, including the values of nested objects, instead of panicking.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use map_macro::serde_json::try_json_map;
+///
+/// let user = try_json_map! {
+///     "name" => "Alice",
+///     "roles" => { "admin" => false },
+/// };
+///
+/// assert_eq!(user.unwrap()["roles"]["admin"], false);
+///
+/// let invalid: BTreeMap<(u8, u8), u8> = BTreeMap::from([((0, 0), 0)]);
+///
+/// let err = try_json_map! {
+///     "name" => "Alice",
+///     "roles" => { "admin" => invalid },
+/// };
+///
+/// assert!(err.is_err());
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sj_try_json_map {
+    {$($t: tt)*} => {
+        $crate::__sj_json_map!(@object into_result $($t)*)
+    };
+}
+
+#[doc(inline)]
+pub use __sj_try_json_map as try_json_map;
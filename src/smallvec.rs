@@ -0,0 +1,155 @@
+//! Macros for initializing [`smallvec`] vectors.
+//!
+//! # Example
+//!
+//! ```
+//! use smallvec::SmallVec;
+//!
+//! use map_macro::smallvec::small_vec;
+//!
+//! let v: SmallVec<[u8; 4]> = small_vec![0, 1, 2, 3];
+//!
+//! assert!(!v.spilled());
+//! ```
+//!
+//! # Supported Versions of `smallvec`
+//!
+//! The macros from this module support all `1.x` versions of `smallvec`.
+//! They rely on the [`FromIterator`](::core::iter::FromIterator)
+//! implementation, as well as on the `with_capacity` and `push` methods of
+//! `SmallVec<A>`, where `A` is the inline array type.
+//!
+//! **Note:** to be compatible with all versions of `smallvec` at once, this
+//! crate doesn't re-export `smallvec`.
+//! That means that (I) you need to specify it as a dependency yourself and
+//! (II) you can't rename it or the macros from this module won't be able to
+//! import the needed types, resulting in a compile-time error.
+//!
+
+/// Macro for creating a [`SmallVec`](::smallvec::SmallVec).
+///
+/// Follows the same syntax as the [`vec_no_clone!`](crate::vec_no_clone)
+/// macro, which means that the repeat form `small_vec![expr; n]` evaluates
+/// `expr` `n` times instead of cloning it.
+/// The size of the inline buffer is taken from the type of the vector.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::cell::RefCell;
+///
+/// use smallvec::SmallVec;
+///
+/// use map_macro::smallvec::small_vec;
+///
+/// let v: SmallVec<[u8; 4]> = small_vec![0, 1, 2, 3];
+///
+/// assert_eq!(v.as_slice(), &[0, 1, 2, 3]);
+///
+/// let v: SmallVec<[RefCell<Vec<u8>>; 2]> = small_vec![RefCell::new(Vec::new()); 2];
+///
+/// v[0].borrow_mut().push(1);
+///
+/// assert_eq!(v[1].borrow().len(), 0);
+/// ```
+///
+/// Like [`vec_no_clone!`](crate::vec_no_clone#index-aware-repetition), the
+/// repeat form accepts an index-aware element written as `|i| expr; n`:
+///
+/// ```rust
+/// use smallvec::SmallVec;
+///
+/// use map_macro::smallvec::small_vec;
+///
+/// let v: SmallVec<[usize; 4]> = small_vec![|i| i * 2; 4];
+///
+/// assert_eq!(v.as_slice(), &[0, 2, 4, 6]);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sv_small_vec {
+    [|$i: pat| $v: expr; $c: expr] => {
+        {
+            let count = $c;
+            let mut vec = ::smallvec::SmallVec::<_>::with_capacity(count);
+
+            for $i in 0..count {
+                vec.push($v);
+            }
+
+            vec
+        }
+    };
+    [$v: expr; $c: expr] => {
+        {
+            let count = $c;
+            let mut vec = ::smallvec::SmallVec::<_>::with_capacity(count);
+
+            for _ in 0..count {
+                vec.push($v);
+            }
+
+            vec
+        }
+    };
+    [$($v: expr),* $(,)?] => {
+        <::smallvec::SmallVec<_> as ::core::iter::FromIterator<_>>::from_iter([$($v,)*])
+    };
+}
+
+/// Explicitly typed equivalent of [`small_vec!`](self::small_vec).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fmt::Debug;
+///
+/// use smallvec::SmallVec;
+///
+/// use map_macro::smallvec::small_vec_e;
+///
+/// let v: SmallVec<[&dyn Debug; 2]> = small_vec_e![&"Hello", &1];
+///
+/// assert_eq!(v.len(), 2);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sv_small_vec_e {
+    [|$i: pat| $v: expr; $c: expr] => {
+        {
+            let count = $c;
+            let mut vec = ::smallvec::SmallVec::<_>::with_capacity(count);
+
+            for $i in 0..count {
+                vec.push($v as _);
+            }
+
+            vec
+        }
+    };
+    [$v: expr; $c: expr] => {
+        {
+            let count = $c;
+            let mut vec = ::smallvec::SmallVec::<_>::with_capacity(count);
+
+            for _ in 0..count {
+                vec.push($v as _);
+            }
+
+            vec
+        }
+    };
+    [$($v: expr),* $(,)?] => {
+        <::smallvec::SmallVec<_> as ::core::iter::FromIterator<_>>::from_iter([$($v as _,)*])
+    };
+}
+
+#[doc(inline)]
+pub use __sv_small_vec as small_vec;
+
+#[doc(inline)]
+pub use __sv_small_vec_e as small_vec_e;
@@ -0,0 +1,509 @@
+//! Maps and sets with a perfect hash function computed at compile time.
+//!
+//! The [`static_hash_map!`](crate::static_hash_map) and
+//! [`static_hash_set!`](crate::static_hash_set) macros compute a
+//! [perfect hash function][chd] from the literal keys of the provided
+//! entries while your program is compiled.
+//! They expand to a [`StaticHashMap`] or [`StaticHashSet`] that merely
+//! references a table of the entries, which makes them suitable for
+//! initializing `static` and `const` items, even in `no_std` environments
+//! without an allocator:
+//!
+//! ```rust
+//! use map_macro::static_hash_map;
+//! use map_macro::static_map::StaticHashMap;
+//!
+//! static MIME_TYPES: StaticHashMap<&str, &str> = static_hash_map! {
+//!     "html" => "text/html",
+//!     "css" => "text/css",
+//!     "js" => "text/javascript",
+//!     "png" => "image/png",
+//! };
+//!
+//! assert_eq!(MIME_TYPES.get("css"), Some(&"text/css"));
+//! assert_eq!(MIME_TYPES.get("gif"), None);
+//! ```
+//!
+//! # Supported Keys
+//!
+//! Because the hash function is computed at compile time, every key must be
+//! a string, byte string, integer, character or boolean literal.
+//! Duplicate keys are rejected, just like with the
+//! [`checked`](crate::checked) macros:
+//!
+//! ```compile_fail
+//! use map_macro::static_hash_set;
+//! use map_macro::static_map::StaticHashSet;
+//!
+//! static LANGUAGES: StaticHashSet<&str> = static_hash_set! { "en", "de", "en" };
+//! ```
+//!
+//! Byte string keys must be stored as `&[u8]`.
+//!
+//! **Note:** the hash function is computed by a procedural macro.
+//! Enabling the `static` feature therefore adds `map-macro-proc` as a
+//! dependency.
+//!
+//! [chd]: https://cmph.sourceforge.net/papers/esa09.pdf
+//!
+
+use core::borrow::Borrow;
+use core::fmt;
+use core::iter::FusedIterator;
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Macro for creating a [`StaticHashMap`](crate::static_map::StaticHashMap).
+///
+/// Accepts the same `key => value` entries as [`hash_map!`](crate::hash_map),
+/// but the keys must be literals.
+/// See the [`static_map`](crate::static_map) module for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::static_hash_map;
+/// use map_macro::static_map::StaticHashMap;
+///
+/// static KEYWORDS: StaticHashMap<&str, u8> = static_hash_map! {
+///     "fn" => 0,
+///     "let" => 1,
+///     "match" => 2,
+/// };
+///
+/// assert_eq!(KEYWORDS.len(), 3);
+/// assert_eq!(KEYWORDS.get("let"), Some(&1));
+/// assert!(!KEYWORDS.contains_key("loop"));
+/// ```
+///
+#[macro_export]
+macro_rules! static_hash_map {
+    {$($t: tt)*} => {
+        $crate::__private::static_hash_table!($crate; map $($t)*)
+    };
+}
+
+/// Macro for creating a [`StaticHashSet`](crate::static_map::StaticHashSet).
+///
+/// Accepts the same elements as [`hash_set!`](crate::hash_set), but the
+/// elements must be literals.
+/// See the [`static_map`](crate::static_map) module for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use map_macro::static_hash_set;
+/// use map_macro::static_map::StaticHashSet;
+///
+/// static PRIMES: StaticHashSet<u32> = static_hash_set! { 2, 3, 5, 7, 11, 13 };
+///
+/// assert_eq!(PRIMES.len(), 6);
+/// assert!(PRIMES.contains(&11));
+/// assert!(!PRIMES.contains(&12));
+/// ```
+///
+#[macro_export]
+macro_rules! static_hash_set {
+    {$($t: tt)*} => {
+        $crate::__private::static_hash_table!($crate; set $($t)*)
+    };
+}
+
+/// Map with a perfect hash function computed at compile time.
+///
+/// Created with the [`static_hash_map!`](crate::static_hash_map) macro.
+///
+/// Entries are stored in the order of their hashes, not in the order in
+/// which they were provided.
+///
+pub struct StaticHashMap<K: 'static, V: 'static> {
+    seed: u64,
+    disps: &'static [(u32, u32)],
+    entries: &'static [(K, V)],
+}
+
+impl<K, V> StaticHashMap<K, V> {
+    #[doc(hidden)]
+    pub const fn __new(
+        seed: u64,
+        disps: &'static [(u32, u32)],
+        entries: &'static [(K, V)],
+    ) -> Self {
+        Self {
+            seed,
+            disps,
+            entries,
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    ///
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    ///
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns a reference to the value corresponding to `key`.
+    ///
+    #[must_use]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: ?Sized + StaticHash + Eq,
+        K: Borrow<Q>,
+    {
+        self.get_key_value(key).map(|(_, v)| v)
+    }
+
+    /// Returns the entry corresponding to `key`.
+    ///
+    #[must_use]
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: ?Sized + StaticHash + Eq,
+        K: Borrow<Q>,
+    {
+        let index = lookup(self.seed, self.disps, self.entries.len(), key)?;
+        let (k, v) = &self.entries[index];
+
+        if k.borrow() == key {
+            Some((k, v))
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the map contains an entry for `key`.
+    ///
+    #[must_use]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: ?Sized + StaticHash + Eq,
+        K: Borrow<Q>,
+    {
+        self.get_key_value(key).is_some()
+    }
+
+    /// Returns an iterator over the entries of the map.
+    ///
+    #[must_use]
+    pub fn iter(&self) -> Iter<'static, K, V> {
+        Iter(self.entries.iter())
+    }
+}
+
+impl<K, V> Clone for StaticHashMap<K, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V> Copy for StaticHashMap<K, V> {}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for StaticHashMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a StaticHashMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the entries of a [`StaticHashMap`].
+///
+#[derive(Clone, Debug)]
+pub struct Iter<'a, K, V>(core::slice::Iter<'a, (K, V)>);
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, v)| (k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(k, v)| (k, v))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+/// Set with a perfect hash function computed at compile time.
+///
+/// Created with the [`static_hash_set!`](crate::static_hash_set) macro.
+///
+/// Elements are stored in the order of their hashes, not in the order in
+/// which they were provided.
+///
+pub struct StaticHashSet<T: 'static> {
+    seed: u64,
+    disps: &'static [(u32, u32)],
+    elements: &'static [T],
+}
+
+impl<T> StaticHashSet<T> {
+    #[doc(hidden)]
+    pub const fn __new(seed: u64, disps: &'static [(u32, u32)], elements: &'static [T]) -> Self {
+        Self {
+            seed,
+            disps,
+            elements,
+        }
+    }
+
+    /// Returns the number of elements in the set.
+    ///
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns `true` if the set contains no elements.
+    ///
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns a reference to the element equal to `value`.
+    ///
+    #[must_use]
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        Q: ?Sized + StaticHash + Eq,
+        T: Borrow<Q>,
+    {
+        let index = lookup(self.seed, self.disps, self.elements.len(), value)?;
+        let element = &self.elements[index];
+
+        if element.borrow() == value {
+            Some(element)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the set contains an element equal to `value`.
+    ///
+    #[must_use]
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        Q: ?Sized + StaticHash + Eq,
+        T: Borrow<Q>,
+    {
+        self.get(value).is_some()
+    }
+
+    /// Returns an iterator over the elements of the set.
+    ///
+    #[must_use]
+    pub fn iter(&self) -> SetIter<'static, T> {
+        SetIter(self.elements.iter())
+    }
+}
+
+impl<T> Clone for StaticHashSet<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for StaticHashSet<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for StaticHashSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a StaticHashSet<T> {
+    type Item = &'a T;
+    type IntoIter = SetIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the elements of a [`StaticHashSet`].
+///
+#[derive(Clone, Debug)]
+pub struct SetIter<'a, T>(core::slice::Iter<'a, T>);
+
+impl<'a, T> Iterator for SetIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for SetIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for SetIter<'_, T> {}
+
+impl<T> FusedIterator for SetIter<'_, T> {}
+
+/// Types that can be used as keys of a [`StaticHashMap`] or elements of a
+/// [`StaticHashSet`].
+///
+/// Implementations must write the same bytes the procedural macro computing
+/// the perfect hash function writes for the corresponding literal, so this
+/// trait is only implemented for the types of literals.
+///
+pub trait StaticHash {
+    /// Feeds `self` into `state`.
+    ///
+    fn static_hash(&self, state: &mut StaticHasher);
+}
+
+impl StaticHash for str {
+    fn static_hash(&self, state: &mut StaticHasher) {
+        state.write(self.as_bytes());
+    }
+}
+
+impl StaticHash for [u8] {
+    fn static_hash(&self, state: &mut StaticHasher) {
+        state.write(self);
+    }
+}
+
+impl StaticHash for char {
+    fn static_hash(&self, state: &mut StaticHasher) {
+        state.write(&u32::from(*self).to_le_bytes());
+    }
+}
+
+impl StaticHash for bool {
+    fn static_hash(&self, state: &mut StaticHasher) {
+        state.write(&[u8::from(*self)]);
+    }
+}
+
+impl<T: ?Sized + StaticHash> StaticHash for &T {
+    fn static_hash(&self, state: &mut StaticHasher) {
+        (**self).static_hash(state);
+    }
+}
+
+// integers are hashed by value, independent of their type
+macro_rules! impl_static_hash_int {
+    ($($t: ty => $wide: ty),*) => {
+        $(
+            impl StaticHash for $t {
+                #[allow(clippy::cast_sign_loss, clippy::cast_lossless)]
+                fn static_hash(&self, state: &mut StaticHasher) {
+                    state.write(&(*self as $wide as u128).to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_static_hash_int! {
+    u8 => u128, u16 => u128, u32 => u128, u64 => u128, u128 => u128, usize => u128,
+    i8 => i128, i16 => i128, i32 => i128, i64 => i128, i128 => i128, isize => i128
+}
+
+/// Hasher used to compute the perfect hash function of a [`StaticHashMap`]
+/// or [`StaticHashSet`].
+///
+pub struct StaticHasher {
+    state: u64,
+}
+
+impl StaticHasher {
+    fn new(seed: u64) -> Self {
+        Self {
+            state: FNV_OFFSET ^ mix(seed),
+        }
+    }
+
+    /// Feeds `bytes` into the hasher.
+    ///
+    pub fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.state ^= u64::from(*b);
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn finish(&self) -> Hashes {
+        let h1 = mix(self.state);
+        let h2 = mix(h1 ^ 0x9e37_79b9_7f4a_7c15);
+
+        Hashes {
+            g: (h1 >> 32) as u32,
+            f1: h1 as u32,
+            f2: h2 as u32,
+        }
+    }
+}
+
+struct Hashes {
+    g: u32,
+    f1: u32,
+    f2: u32,
+}
+
+/// Returns the index in the table of entries at which `key` would be stored.
+///
+fn lookup<Q: ?Sized + StaticHash>(
+    seed: u64,
+    disps: &[(u32, u32)],
+    len: usize,
+    key: &Q,
+) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    let mut state = StaticHasher::new(seed);
+    key.static_hash(&mut state);
+    let hashes = state.finish();
+
+    let (d1, d2) = disps[hashes.g as usize % disps.len()];
+
+    Some(displace(hashes.f1, hashes.f2, d1, d2) as usize % len)
+}
+
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+fn displace(f1: u32, f2: u32, d1: u32, d2: u32) -> u32 {
+    d2.wrapping_add(f1.wrapping_mul(d1)).wrapping_add(f2)
+}
@@ -0,0 +1,156 @@
+//! Macros for initializing [`tinyvec`] vectors.
+//!
+//! # Example
+//!
+//! ```
+//! use tinyvec::TinyVec;
+//!
+//! use map_macro::tinyvec::tiny_vec;
+//!
+//! let v: TinyVec<[u8; 4]> = tiny_vec![0, 1, 2, 3];
+//!
+//! assert!(v.is_inline());
+//! ```
+//!
+//! # Supported Versions of `tinyvec`
+//!
+//! The macros from this module support all `1.x` versions of `tinyvec`.
+//! They rely on the [`FromIterator`](::core::iter::FromIterator)
+//! implementation, as well as on the `with_capacity` and `push` methods of
+//! `TinyVec<A>`, where `A` is the inline array type.
+//! `TinyVec` is only available if the `alloc` feature of `tinyvec` is
+//! enabled.
+//!
+//! **Note:** to be compatible with all versions of `tinyvec` at once, this
+//! crate doesn't re-export `tinyvec`.
+//! That means that (I) you need to specify it as a dependency yourself and
+//! (II) you can't rename it or the macros from this module won't be able to
+//! import the needed types, resulting in a compile-time error.
+//!
+
+/// Macro for creating a [`TinyVec`](::tinyvec::TinyVec).
+///
+/// Follows the same syntax as the [`vec_no_clone!`](crate::vec_no_clone)
+/// macro, which means that the repeat form `tiny_vec![expr; n]` evaluates
+/// `expr` `n` times instead of cloning it.
+/// The size of the inline buffer is taken from the type of the vector.
+/// Like for `TinyVec` itself, the elements must implement [`Default`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::cell::RefCell;
+///
+/// use tinyvec::TinyVec;
+///
+/// use map_macro::tinyvec::tiny_vec;
+///
+/// let v: TinyVec<[u8; 4]> = tiny_vec![0, 1, 2, 3];
+///
+/// assert_eq!(v.as_slice(), &[0, 1, 2, 3]);
+///
+/// let v: TinyVec<[RefCell<Vec<u8>>; 2]> = tiny_vec![RefCell::new(Vec::new()); 2];
+///
+/// v[0].borrow_mut().push(1);
+///
+/// assert_eq!(v[1].borrow().len(), 0);
+/// ```
+///
+/// Like [`vec_no_clone!`](crate::vec_no_clone#index-aware-repetition), the
+/// repeat form accepts an index-aware element written as `|i| expr; n`:
+///
+/// ```rust
+/// use tinyvec::TinyVec;
+///
+/// use map_macro::tinyvec::tiny_vec;
+///
+/// let v: TinyVec<[usize; 4]> = tiny_vec![|i| i * 2; 4];
+///
+/// assert_eq!(v.as_slice(), &[0, 2, 4, 6]);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tv_tiny_vec {
+    [|$i: pat| $v: expr; $c: expr] => {
+        {
+            let count = $c;
+            let mut vec = ::tinyvec::TinyVec::<_>::with_capacity(count);
+
+            for $i in 0..count {
+                vec.push($v);
+            }
+
+            vec
+        }
+    };
+    [$v: expr; $c: expr] => {
+        {
+            let count = $c;
+            let mut vec = ::tinyvec::TinyVec::<_>::with_capacity(count);
+
+            for _ in 0..count {
+                vec.push($v);
+            }
+
+            vec
+        }
+    };
+    [$($v: expr),* $(,)?] => {
+        <::tinyvec::TinyVec<_> as ::core::iter::FromIterator<_>>::from_iter([$($v,)*])
+    };
+}
+
+/// Explicitly typed equivalent of [`tiny_vec!`](self::tiny_vec).
+///
+/// See the [explicity typed macros](crate#explicitly-typed-macros) section.
+///
+/// # Examples
+///
+/// ```rust
+/// use tinyvec::TinyVec;
+///
+/// use map_macro::tinyvec::tiny_vec_e;
+///
+/// let v: TinyVec<[u64; 2]> = tiny_vec_e![0u8, 1u16, 2u32];
+///
+/// assert_eq!(v.as_slice(), &[0, 1, 2]);
+/// ```
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tv_tiny_vec_e {
+    [|$i: pat| $v: expr; $c: expr] => {
+        {
+            let count = $c;
+            let mut vec = ::tinyvec::TinyVec::<_>::with_capacity(count);
+
+            for $i in 0..count {
+                vec.push($v as _);
+            }
+
+            vec
+        }
+    };
+    [$v: expr; $c: expr] => {
+        {
+            let count = $c;
+            let mut vec = ::tinyvec::TinyVec::<_>::with_capacity(count);
+
+            for _ in 0..count {
+                vec.push($v as _);
+            }
+
+            vec
+        }
+    };
+    [$($v: expr),* $(,)?] => {
+        <::tinyvec::TinyVec<_> as ::core::iter::FromIterator<_>>::from_iter([$($v as _,)*])
+    };
+}
+
+#[doc(inline)]
+pub use __tv_tiny_vec as tiny_vec;
+
+#[doc(inline)]
+pub use __tv_tiny_vec_e as tiny_vec_e;
@@ -0,0 +1,110 @@
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use map_macro::{array_no_clone, array_no_clone_e};
+
+#[derive(PartialEq, Debug)]
+struct UnclonableWrapper(usize);
+
+#[test]
+fn array_no_clone1() {
+    let a = array_no_clone![UnclonableWrapper(0); 3];
+
+    assert_eq!(
+        a,
+        [
+            UnclonableWrapper(0),
+            UnclonableWrapper(0),
+            UnclonableWrapper(0),
+        ],
+    );
+}
+
+#[test]
+fn array_no_clone_atomic() {
+    let a: [AtomicUsize; 4] = array_no_clone![AtomicUsize::new(0); 4];
+
+    a[2].fetch_add(1, Ordering::Relaxed);
+
+    assert_eq!(a[0].load(Ordering::Relaxed), 0);
+    assert_eq!(a[2].load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn array_no_clone_cell() {
+    let a: [Cell<Option<Box<u8>>>; 2] = array_no_clone![Cell::new(None); 2];
+
+    a[0].set(Some(Box::new(1)));
+
+    assert_eq!(a[0].take(), Some(Box::new(1)));
+    assert_eq!(a[1].take(), None);
+}
+
+#[test]
+fn array_no_clone_const_len() {
+    const LEN: usize = 2;
+
+    let a = array_no_clone![UnclonableWrapper(1); LEN * 2];
+
+    assert_eq!(a.len(), 4);
+}
+
+#[test]
+fn array_no_clone_empty() {
+    let a: [UnclonableWrapper; 0] = array_no_clone![UnclonableWrapper(0); 0];
+
+    assert_eq!(a, []);
+}
+
+#[test]
+fn array_no_clone_indexed() {
+    let a = array_no_clone![|i| UnclonableWrapper(i); 3];
+
+    assert_eq!(
+        a,
+        [
+            UnclonableWrapper(0),
+            UnclonableWrapper(1),
+            UnclonableWrapper(2),
+        ],
+    );
+}
+
+#[test]
+fn array_no_clone_panic() {
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        array_no_clone![|i| {
+            assert!(i != 2, "boom");
+            DropCounter(&drops)
+        }; 4]
+    }));
+
+    assert!(res.is_err());
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn array_no_clone_e1() {
+    let a: [&dyn Debug; 2] = array_no_clone_e![&UnclonableWrapper(0); 2];
+
+    assert_eq!(format!("{:?}", a[1]), "UnclonableWrapper(0)");
+}
+
+#[test]
+fn array_no_clone_e_indexed() {
+    let a: [u64; 3] = array_no_clone_e![|i| i; 3];
+
+    assert_eq!(a, [0, 1, 2]);
+}
@@ -0,0 +1,129 @@
+use arrayvec::ArrayVec;
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::panic::catch_unwind;
+
+use map_macro::arrayvec::{array_vec, array_vec_e};
+
+#[derive(PartialEq, Debug)]
+struct UnclonableWrapper(usize);
+
+#[derive(Debug)]
+struct Dyn1;
+
+#[derive(Debug)]
+struct Dyn2;
+
+#[test]
+fn array_vec1() {
+    let v: ArrayVec<u8, 4> = array_vec![capacity: 4; 0, 1, 2, 3];
+
+    assert_eq!(v.as_slice(), &[0, 1, 2, 3]);
+    assert!(v.is_full());
+}
+
+#[test]
+fn array_vec_remaining_capacity() {
+    let v: ArrayVec<u8, 4> = array_vec![capacity: 4; 0, 1];
+
+    assert_eq!(v.as_slice(), &[0, 1]);
+    assert_eq!(v.remaining_capacity(), 2);
+}
+
+#[test]
+fn array_vec_const_capacity() {
+    const CAPACITY: usize = 3;
+
+    let v = array_vec![capacity: CAPACITY; 0u8];
+
+    assert_eq!(v.as_slice(), &[0]);
+    assert_eq!(v.capacity(), 3);
+}
+
+#[test]
+fn array_vec_empty() {
+    let v: ArrayVec<u8, 2> = array_vec![];
+
+    assert!(v.is_empty());
+}
+
+#[test]
+fn array_vec_repeat() {
+    let v: ArrayVec<RefCell<Vec<u8>>, 2> = array_vec![RefCell::new(Vec::new()); 2];
+
+    v[0].borrow_mut().push(1);
+
+    assert_eq!(v[0].borrow().len(), 1);
+    assert_eq!(v[1].borrow().len(), 0);
+}
+
+#[test]
+fn array_vec_repeat_unclonable() {
+    let v: ArrayVec<UnclonableWrapper, 4> = array_vec![UnclonableWrapper(0); 3];
+
+    assert_eq!(v.len(), 3);
+    assert_eq!(v[2], UnclonableWrapper(0));
+}
+
+#[test]
+fn array_vec_repeat_exceeds_capacity() {
+    let mut calls = 0;
+
+    let res = catch_unwind(move || {
+        let _: ArrayVec<u8, 2> = array_vec![{
+            calls += 1;
+            0
+        }; 3];
+        calls
+    });
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn array_vec_indexed() {
+    let v: ArrayVec<UnclonableWrapper, 4> = array_vec![|i| UnclonableWrapper(i); 3];
+
+    assert_eq!(
+        v.as_slice(),
+        &[
+            UnclonableWrapper(0),
+            UnclonableWrapper(1),
+            UnclonableWrapper(2),
+        ],
+    );
+}
+
+#[test]
+fn array_vec_count_evaluated_once() {
+    let mut calls = 0;
+
+    let v: ArrayVec<usize, 4> = array_vec![|i| i; {
+        calls += 1;
+        3
+    }];
+
+    assert_eq!(v.as_slice(), &[0, 1, 2]);
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn array_vec_e1() {
+    let v: ArrayVec<&dyn Debug, 3> = array_vec_e![capacity: 3; &Dyn1, &Dyn2, &"Hello"];
+
+    assert_eq!(v.len(), 3);
+}
+
+#[test]
+fn array_vec_e2() {
+    let v: ArrayVec<&dyn Debug, 2> = array_vec_e![&Dyn1; 2];
+
+    assert_eq!(v.len(), 2);
+}
+
+#[test]
+fn array_vec_e_indexed() {
+    let v: ArrayVec<u64, 4> = array_vec_e![|i| i; 3];
+
+    assert_eq!(v.as_slice(), &[0, 1, 2]);
+}
@@ -0,0 +1,153 @@
+use std::fmt::Debug;
+
+use map_macro::{bi_map, bi_map_e, BiHashMap};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+enum Codec {
+    H264,
+    Hevc,
+}
+
+#[test]
+fn bi_map1() {
+    let m = bi_map! {
+        "h264" => 27,
+        "hevc" => 173,
+    };
+
+    assert_eq!(m.len(), 2);
+
+    assert_eq!(m.get_by_left("h264"), Some(&27));
+    assert_eq!(m.get_by_left("hevc"), Some(&173));
+    assert_eq!(m.get_by_right(&27), Some(&"h264"));
+    assert_eq!(m.get_by_right(&173), Some(&"hevc"));
+
+    assert_eq!(m.get_by_left("av1"), None);
+    assert_eq!(m.get_by_right(&1), None);
+}
+
+#[test]
+fn bi_map_expressions() {
+    let m = bi_map! {
+        Codec::H264 => String::from("h264"),
+        Codec::Hevc => String::from("hevc"),
+    };
+
+    assert_eq!(m.get_by_left(&Codec::Hevc).unwrap(), "hevc");
+    assert_eq!(m.get_by_right("h264"), Some(&Codec::H264));
+}
+
+#[test]
+fn bi_map_mixed() {
+    let m = bi_map! {
+        "h264" => Codec::H264,
+        "hevc" => Codec::Hevc,
+    };
+
+    assert!(m.contains_right(&Codec::H264));
+
+    let m = bi_map! {
+        Codec::H264 => 27,
+        Codec::Hevc => 173,
+    };
+
+    assert!(m.contains_left(&Codec::Hevc));
+}
+
+#[test]
+fn bi_map_empty() {
+    let m: BiHashMap<u8, u8> = bi_map! {};
+
+    assert!(m.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "duplicate left value")]
+fn bi_map_duplicate_left() {
+    let _ = bi_map! {
+        Codec::H264 => 27,
+        Codec::H264 => 173,
+    };
+}
+
+#[test]
+#[should_panic(expected = "duplicate right value")]
+fn bi_map_duplicate_right() {
+    let _ = bi_map! {
+        Codec::H264 => String::from("h264"),
+        Codec::Hevc => String::from("h264"),
+    };
+}
+
+#[test]
+fn bi_map_try_insert() {
+    let mut m = bi_map! { 1 => 'a' };
+
+    assert_eq!(m.try_insert(1, 'b'), Err((1, 'b')));
+    assert_eq!(m.try_insert(2, 'a'), Err((2, 'a')));
+    assert_eq!(m.try_insert(2, 'b'), Ok(()));
+
+    assert_eq!(m, bi_map! { 2 => 'b', 1 => 'a' });
+}
+
+#[test]
+fn bi_map_iter() {
+    let m = bi_map! { 1 => 'a', 2 => 'b' };
+
+    let mut pairs: Vec<_> = m.iter().collect();
+    pairs.sort_unstable();
+
+    assert_eq!(pairs, vec![(&1, &'a'), (&2, &'b')]);
+}
+
+#[test]
+fn bi_map_lookup_without_clone() {
+    #[derive(PartialEq, Eq, Hash)]
+    struct Id(u8);
+
+    let m: BiHashMap<Id, Id> = BiHashMap::new();
+
+    assert!(m.get_by_left(&Id(0)).is_none());
+    assert!(m.get_by_right(&Id(0)).is_none());
+    assert!(!m.contains_left(&Id(0)));
+    assert!(!m.contains_right(&Id(0)));
+}
+
+#[test]
+fn bi_map_e1() {
+    let m: BiHashMap<u8, &str> = bi_map_e! {
+        Codec::H264 => "h264",
+        Codec::Hevc => "hevc",
+    };
+
+    assert_eq!(m.get_by_right("hevc"), Some(&1));
+}
+
+#[test]
+fn bi_map_e2() {
+    let m: BiHashMap<u64, i64> = bi_map_e! {
+        1 => -1,
+        2 => -2,
+    };
+
+    assert_eq!(m.get_by_left(&2), Some(&-2));
+    assert_eq!(m.get_by_right(&-1), Some(&1));
+}
+
+#[test]
+fn bi_map_e_expressions() {
+    let m: BiHashMap<u64, i64> = bi_map_e! {
+        1u8 => -1i8,
+        u16::MAX => i32::from(-2i16),
+    };
+
+    assert_eq!(m.get_by_left(&65535), Some(&-2));
+}
+
+#[test]
+fn bi_map_e_debug() {
+    let m: BiHashMap<u8, &str> = bi_map_e! { 0 => "zero" };
+
+    assert_eq!(format!("{:?}", m.get_by_left(&0).unwrap()), "\"zero\"");
+    let _: &dyn Debug = &m;
+}
@@ -1,4 +1,6 @@
-use map_macro::binary_heap;
+use std::collections::BinaryHeap;
+
+use map_macro::{binary_heap, binary_heap_e};
 
 #[test]
 fn binary_heap1() {
@@ -27,3 +29,66 @@ fn binary_heap2() {
 
     assert_eq!(bh.pop(), None);
 }
+
+#[test]
+fn binary_heap_capacity() {
+    let mut bh = binary_heap![capacity: 10; 0, 1, 2, 3];
+
+    assert!(bh.capacity() >= 10);
+
+    assert_eq!(bh.pop().unwrap(), 3);
+    assert_eq!(bh.pop().unwrap(), 2);
+    assert_eq!(bh.pop().unwrap(), 1);
+    assert_eq!(bh.pop().unwrap(), 0);
+
+    assert_eq!(bh.pop(), None);
+}
+
+#[test]
+fn binary_heap_e_capacity() {
+    let bh: BinaryHeap<u16> = binary_heap_e![capacity: 10; 0u8, 1u8];
+
+    assert_eq!(bh.len(), 2);
+    assert!(bh.capacity() >= 10);
+}
+
+#[test]
+fn binary_heap_indexed() {
+    let bh: BinaryHeap<usize> = binary_heap![|i| i * 2; 4];
+
+    assert_eq!(bh.into_sorted_vec(), vec![0, 2, 4, 6]);
+}
+
+#[test]
+fn binary_heap_indexed_count_evaluated_once() {
+    let mut calls = 0;
+
+    let bh: BinaryHeap<usize> = binary_heap![|i| i; {
+        calls += 1;
+        3
+    }];
+
+    assert_eq!(bh.into_sorted_vec(), vec![0, 1, 2]);
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn binary_heap_e_indexed() {
+    let bh: BinaryHeap<u64> = binary_heap_e![|i| i; 3];
+
+    assert_eq!(bh.into_sorted_vec(), vec![0, 1, 2]);
+}
+
+#[test]
+fn binary_heap_spread() {
+    let h = binary_heap![3, ..vec![1, 5], 4];
+
+    assert_eq!(h.into_sorted_vec(), vec![1, 3, 4, 5]);
+}
+
+#[test]
+fn binary_heap_range_elements() {
+    let h = binary_heap![5, 1..3, 3..=4];
+
+    assert_eq!(h.into_sorted_vec(), vec![1, 2, 3, 4, 5]);
+}
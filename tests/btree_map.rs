@@ -53,3 +53,61 @@ fn btree_map_e2() {
         "2" => &Dyn2,
     });
 }
+
+#[test]
+fn btree_map_comprehension() {
+    let m = btree_map! {
+        (a, b) => a + b for a in 0..3 for b in 0..a,
+        (9, 9) => 0,
+    };
+
+    assert_eq!(
+        m,
+        BTreeMap::from([((1, 0), 1), ((2, 0), 2), ((2, 1), 3), ((9, 9), 0)])
+    );
+}
+
+#[test]
+fn btree_map_e_comprehension() {
+    let m: BTreeMap<&str, &dyn Debug> = btree_map_e! {
+        "a" => &Dyn1,
+        k => &Dyn2 for k in ["b", "c"],
+    };
+
+    assert_eq!(m.len(), 3);
+}
+
+#[test]
+fn btree_map_spread() {
+    let defaults = btree_map! { "timeout" => 10, "retries" => 3 };
+
+    let m = btree_map! { ..defaults, "timeout" => 30 };
+
+    assert_eq!(m, btree_map! { "retries" => 3, "timeout" => 30 });
+}
+
+#[test]
+fn btree_map_conditional() {
+    let proxy: Option<&str> = None;
+
+    let m = btree_map! {
+        "host" => "localhost",
+        "log" => "debug" if false,
+        #[cfg(any())]
+        "socket" => "/tmp/app.sock",
+        "proxy" =>? proxy,
+        "port" =>? Some("80"),
+    };
+
+    assert_eq!(m, btree_map! { "host" => "localhost", "port" => "80" });
+}
+
+#[test]
+fn btree_map_shorthand() {
+    let width = 16;
+    let height = 9;
+
+    let m = btree_map! { width, height, "depth" => 1 };
+
+    assert_eq!(m, btree_map! { "depth" => 1, "height" => 9, "width" => 16 });
+}
@@ -1,4 +1,6 @@
-use map_macro::btree_set;
+use std::collections::BTreeSet;
+
+use map_macro::{btree_set, btree_set_e};
 
 #[test]
 fn btree_set1() {
@@ -27,3 +29,38 @@ fn btree_set2() {
 
     assert!(!s.contains(&4));
 }
+
+#[test]
+fn btree_set_comprehension() {
+    let s = btree_set! { "a", w for w in "b c d".split(' ') if w != "c" };
+
+    assert_eq!(s, BTreeSet::from(["a", "b", "d"]));
+}
+
+#[test]
+fn btree_set_e_comprehension() {
+    let s: BTreeSet<u32> = btree_set_e! { c for c in ['a', 'b'] };
+
+    assert_eq!(s, BTreeSet::from([97, 98]));
+}
+
+#[test]
+fn btree_set_spread() {
+    let s = btree_set! { 0, ..[3, 1], 2 };
+
+    assert!(s.into_iter().eq(0..4));
+}
+
+#[test]
+fn btree_set_conditional() {
+    let s = btree_set! { 0, 1 if false, #[cfg(any())] 2, 3 if true };
+
+    assert_eq!(s, BTreeSet::from([0, 3]));
+}
+
+#[test]
+fn btree_set_range_elements() {
+    let s = btree_set! { 'a'..='c', 'x'..'z' };
+
+    assert!(s.into_iter().eq(['a', 'b', 'c', 'x', 'y']));
+}
@@ -0,0 +1,150 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::BuildHasherDefault;
+
+use map_macro::checked::{
+    btree_map, btree_map_e, btree_set, btree_set_e, hash_map, hash_map_e, hash_set, hash_set_e,
+};
+
+#[derive(Debug)]
+struct Dyn1;
+
+#[derive(Debug)]
+struct Dyn2;
+
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Locale {
+    En,
+    De,
+}
+
+#[test]
+fn hash_map1() {
+    let m = hash_map! {
+        "en" => "Hello",
+        "de" => "Hallo",
+        "fr" => "Bonjour",
+        "es" => "Hola",
+    };
+
+    assert_eq!(m["en"], "Hello");
+    assert_eq!(m["de"], "Hallo");
+    assert_eq!(m["fr"], "Bonjour");
+    assert_eq!(m["es"], "Hola");
+}
+
+#[test]
+fn hash_map_non_literal_keys() {
+    let en = " en ";
+
+    let m = hash_map! {
+        en.trim() => "Hello",
+        en.trim() => "Hi",
+    };
+
+    assert_eq!(m.len(), 1);
+    assert_eq!(m["en"], "Hi");
+}
+
+#[test]
+fn hash_map_comprehension() {
+    let m = hash_map! {
+        0 => 0,
+        x => x for x in 0..3,
+        x => x * 2 for x in 0..3,
+    };
+
+    assert_eq!(m, HashMap::from([(0, 0), (1, 2), (2, 4)]));
+}
+
+#[test]
+fn hash_map_guards() {
+    let debug = cfg!(debug_assertions);
+
+    let m = hash_map! {
+        "mode" => "debug" if debug,
+        "mode" => "release" if !debug,
+        "level" => "info",
+    };
+
+    assert_eq!(m.len(), 2);
+    assert_eq!(m["mode"], if debug { "debug" } else { "release" });
+}
+
+#[test]
+fn hash_map_paths() {
+    let m = hash_map! {
+        Locale::En => "Hello",
+        Locale::De => "Hallo",
+    };
+
+    assert_eq!(m[&Locale::En], "Hello");
+    assert_eq!(m[&Locale::De], "Hallo");
+}
+
+#[test]
+fn hash_map_hasher() {
+    let m: HashMap<_, _, BuildHasherDefault<DefaultHasher>> = hash_map! {
+        hasher: BuildHasherDefault::default();
+        "en" => "Hello",
+        "de" => "Hallo",
+    };
+
+    assert_eq!(m.len(), 2);
+}
+
+#[test]
+fn hash_map_e1() {
+    drop::<HashMap<&str, &dyn Debug>>(hash_map_e! {
+        "1" => &Dyn1,
+        "2" => &Dyn2,
+    });
+}
+
+#[test]
+fn btree_map1() {
+    let m = btree_map! {
+        -1 => "a",
+        1 => "b",
+        0 => "c",
+    };
+
+    assert_eq!(m.len(), 3);
+}
+
+#[test]
+fn btree_map_e1() {
+    drop::<BTreeMap<&str, &dyn Debug>>(btree_map_e! {
+        "1" => &Dyn1,
+        "2" => &Dyn2,
+    });
+}
+
+#[test]
+fn hash_set1() {
+    let s = hash_set! { "a", "b", "c", "d" };
+
+    assert_eq!(s.len(), 4);
+}
+
+#[test]
+fn hash_set_e1() {
+    let s: HashSet<u8> = hash_set_e! { 0, 1, 2, 3 };
+
+    assert_eq!(s.len(), 4);
+}
+
+#[test]
+fn btree_set1() {
+    let s = btree_set! { Locale::En, Locale::De };
+
+    assert_eq!(s.len(), 2);
+}
+
+#[test]
+fn btree_set_e1() {
+    let s: BTreeSet<u8> = btree_set_e! { 0, 1, 2, 3 };
+
+    assert_eq!(s.len(), 4);
+}
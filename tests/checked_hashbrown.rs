@@ -0,0 +1,40 @@
+use hashbrown::HashMap;
+use std::fmt::Debug;
+
+use map_macro::checked::hashbrown::{hash_map, hash_map_e, hash_set};
+
+#[derive(Debug)]
+struct Dyn1;
+
+#[derive(Debug)]
+struct Dyn2;
+
+#[test]
+fn hash_map1() {
+    let m = hash_map! {
+        "en" => "Hello",
+        "de" => "Hallo",
+        "fr" => "Bonjour",
+        "es" => "Hola",
+    };
+
+    assert_eq!(m["en"], "Hello");
+    assert_eq!(m["de"], "Hallo");
+    assert_eq!(m["fr"], "Bonjour");
+    assert_eq!(m["es"], "Hola");
+}
+
+#[test]
+fn hash_map_e1() {
+    drop::<HashMap<&str, &dyn Debug>>(hash_map_e! {
+        "1" => &Dyn1,
+        "2" => &Dyn2,
+    });
+}
+
+#[test]
+fn hash_set1() {
+    let s = hash_set! { 0, 1, 2, 3 };
+
+    assert_eq!(s.len(), 4);
+}
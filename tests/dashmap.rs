@@ -0,0 +1,129 @@
+use dashmap::{DashMap, DashSet};
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
+use std::hash::BuildHasherDefault;
+
+use map_macro::dashmap::{dash_map, dash_map_e, dash_set, dash_set_e};
+
+#[derive(Debug)]
+struct Dyn1;
+
+#[derive(Debug)]
+struct Dyn2;
+
+#[test]
+fn dash_map1() {
+    let m = dash_map! {
+        "en" => "Hello",
+        "de" => "Hallo",
+        "fr" => "Bonjour",
+    };
+
+    assert_eq!(m.len(), 3);
+    assert_eq!(*m.get("fr").unwrap(), "Bonjour");
+}
+
+#[test]
+fn dash_map_e1() {
+    let m: DashMap<&str, &dyn Debug> = dash_map_e! {
+        "1" => &Dyn1,
+        "2" => &Dyn2,
+    };
+
+    assert_eq!(m.len(), 2);
+}
+
+#[test]
+fn dash_map_clauses() {
+    let m: DashMap<_, _, BuildHasherDefault<DefaultHasher>> = dash_map! {
+        capacity: 64;
+        hasher: BuildHasherDefault::default();
+        "en" => "Hello",
+    };
+
+    assert_eq!(m.len(), 1);
+    assert!(m.capacity() >= 64);
+}
+
+#[test]
+fn dash_map_shards() {
+    let m = dash_map! { shards: 4; 0 => 0, 1 => 1 };
+
+    assert_eq!(m.len(), 2);
+
+    let m: DashMap<_, _, BuildHasherDefault<DefaultHasher>> = dash_map! {
+        hasher: BuildHasherDefault::default();
+        shards: 2;
+        capacity: 64;
+        0 => 0,
+    };
+
+    assert!(m.capacity() >= 64);
+
+    let m = dash_map! { capacity: 64; shards: 8; x => x for x in 0..3 };
+
+    assert_eq!(m.len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "is_power_of_two")]
+fn dash_map_shards_invalid() {
+    let _ = dash_map! { shards: 3; 0 => 0 };
+}
+
+#[test]
+fn dash_map_e_shards() {
+    let m: DashMap<u16, &dyn Debug> = dash_map_e! { shards: 2; 0u8 => &Dyn1 };
+
+    assert_eq!(m.len(), 1);
+}
+
+#[test]
+fn dash_map_entries() {
+    let defaults = dash_map! { "timeout" => 10, "retries" => 3 };
+
+    let m = dash_map! {
+        ..defaults,
+        "timeout" => 30,
+        "debug" => 1 if false,
+        "proxy" =>? None,
+        k => k.len() for k in ["a", "bb"],
+    };
+
+    assert_eq!(m.len(), 4);
+    assert_eq!(*m.get("timeout").unwrap(), 30);
+}
+
+#[test]
+fn dash_set1() {
+    let s = dash_set! { 0, 1, 2, 3, 0 };
+
+    assert_eq!(s.len(), 4);
+}
+
+#[test]
+fn dash_set_e1() {
+    let s: DashSet<u16> = dash_set_e! { 0u8, 1u8 };
+
+    assert_eq!(s.len(), 2);
+}
+
+#[test]
+fn dash_set_clauses() {
+    let s: DashSet<_, BuildHasherDefault<DefaultHasher>> = dash_set! {
+        hasher: BuildHasherDefault::default();
+        capacity: 64;
+        0, 1, 2
+    };
+
+    assert_eq!(s.len(), 3);
+    assert!(s.capacity() >= 64);
+}
+
+#[test]
+fn dash_set_entries() {
+    let s = dash_set! { 0, 1..3, ..[3, 4], 5 if false, x * 10 for x in 1..3 };
+
+    assert_eq!(s.len(), 7);
+    assert!(s.contains(&20));
+}
@@ -0,0 +1,123 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::Debug;
+
+use map_macro::{
+    btree_set, extend_map, extend_map_e, extend_set, extend_set_e, hash_map, vec_deque,
+};
+
+#[derive(Debug)]
+struct Dyn1;
+
+#[derive(Debug)]
+struct Dyn2;
+
+#[test]
+fn extend_map1() {
+    let mut m = hash_map! {
+        "en" => "Hello",
+        "de" => "Hallo",
+    };
+
+    extend_map!(m, "fr" => "Bonjour", "es" => "Hola", "de" => "Guten Tag");
+
+    assert_eq!(m.len(), 4);
+    assert_eq!(m["de"], "Guten Tag");
+    assert_eq!(m["fr"], "Bonjour");
+    assert_eq!(m["es"], "Hola");
+}
+
+#[test]
+fn extend_map2() {
+    fn extend(m: &mut BTreeMap<u8, char>) {
+        extend_map!(*m, 1 => 'b', 2 => 'c',);
+    }
+
+    let mut m = BTreeMap::from([(0, 'a')]);
+
+    extend(&mut m);
+
+    assert_eq!(m, BTreeMap::from([(0, 'a'), (1, 'b'), (2, 'c')]));
+}
+
+#[test]
+fn extend_map_reserve() {
+    let mut m = HashMap::from([(0, 0)]);
+
+    extend_map!(m, 1 => 1, 1 => 2, 1 => 3, 1 => 4, 1 => 5, 1 => 6, 1 => 7, 1 => 8);
+
+    assert_eq!(m.len(), 2);
+    assert!(m.capacity() >= 9);
+}
+
+#[test]
+fn extend_map_e1() {
+    let mut m: HashMap<&str, &dyn Debug> = HashMap::new();
+
+    extend_map_e!(m, "en" => &Dyn1, "de" => &Dyn2);
+
+    assert_eq!(m.len(), 2);
+}
+
+#[test]
+fn extend_map_e2() {
+    let mut m: BTreeMap<u16, fn() -> &'static str> = BTreeMap::new();
+
+    extend_map_e!(m, 0u8 => || "Hello", 1u8 => || "Hallo");
+
+    assert_eq!(m[&1](), "Hallo");
+}
+
+#[test]
+fn extend_set1() {
+    let mut s = btree_set! { "en", "de" };
+
+    extend_set!(s, "fr", "es", "de");
+
+    assert_eq!(s, BTreeSet::from(["de", "en", "es", "fr"]));
+}
+
+#[test]
+fn extend_set2() {
+    let mut v = vec_deque![0, 1];
+
+    extend_set!(v, 2, 3);
+
+    assert_eq!(v, [0, 1, 2, 3]);
+}
+
+#[test]
+fn extend_set3() {
+    let mut v = vec![0, 1];
+
+    extend_set!(v, 2, 3,);
+
+    assert_eq!(v, [0, 1, 2, 3]);
+}
+
+#[test]
+fn extend_set_e1() {
+    let mut v: VecDeque<&dyn Debug> = VecDeque::new();
+
+    extend_set_e!(v, &Dyn1, &Dyn2, &0);
+
+    assert_eq!(v.len(), 3);
+}
+
+#[test]
+fn extend_set_e2() {
+    let mut s: BTreeSet<u16> = BTreeSet::new();
+
+    extend_set_e!(s, 0u8, 1u8);
+
+    assert_eq!(s, BTreeSet::from([0, 1]));
+}
+
+#[test]
+fn extend_set_reserve() {
+    let mut s = HashSet::from([0]);
+
+    extend_set!(s, 1, 1, 1, 1, 1, 1, 1, 1);
+
+    assert_eq!(s.len(), 2);
+    assert!(s.capacity() >= 9);
+}
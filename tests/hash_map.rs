@@ -1,5 +1,7 @@
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::hash::BuildHasherDefault;
 
 use map_macro::{hash_map, hash_map_e};
 
@@ -54,3 +56,342 @@ fn hash_map_e2() {
         "2" => &Dyn2,
     });
 }
+
+#[test]
+fn hash_map_hasher() {
+    let m: HashMap<_, _, BuildHasherDefault<DefaultHasher>> = hash_map! {
+        hasher: BuildHasherDefault::default();
+        "en" => "Hello",
+        "de" => "Hallo",
+    };
+
+    assert_eq!(m["en"], "Hello");
+    assert_eq!(m["de"], "Hallo");
+}
+
+#[test]
+fn hash_map_hasher_empty() {
+    let m: HashMap<u8, u8, BuildHasherDefault<DefaultHasher>> = hash_map! {
+        hasher: BuildHasherDefault::default();
+    };
+
+    assert!(m.is_empty());
+}
+
+#[test]
+fn hash_map_e_hasher() {
+    drop::<HashMap<&str, &dyn Debug, BuildHasherDefault<DefaultHasher>>>(hash_map_e! {
+        hasher: BuildHasherDefault::default();
+        "1" => &Dyn1,
+        "2" => &Dyn2,
+    });
+}
+
+#[test]
+fn hash_map_capacity() {
+    let m = hash_map! {
+        capacity: 10;
+        "en" => "Hello",
+        "de" => "Hallo",
+    };
+
+    assert_eq!(m.len(), 2);
+    assert!(m.capacity() >= 10);
+}
+
+#[test]
+fn hash_map_capacity_smaller_than_entries() {
+    let m = hash_map! {
+        capacity: 0;
+        "en" => "Hello",
+        "de" => "Hallo",
+    };
+
+    assert_eq!(m.len(), 2);
+    assert!(m.capacity() >= 2);
+}
+
+#[test]
+fn hash_map_capacity_and_hasher() {
+    let m1: HashMap<_, _, BuildHasherDefault<DefaultHasher>> = hash_map! {
+        capacity: 10;
+        hasher: BuildHasherDefault::default();
+        "en" => "Hello",
+    };
+
+    let m2: HashMap<_, _, BuildHasherDefault<DefaultHasher>> = hash_map! {
+        hasher: BuildHasherDefault::default();
+        capacity: 10;
+        "en" => "Hello",
+    };
+
+    assert!(m1.capacity() >= 10);
+    assert!(m2.capacity() >= 10);
+    assert_eq!(m1, m2);
+}
+
+#[test]
+fn hash_map_e_capacity() {
+    let m: HashMap<&str, &dyn Debug> = hash_map_e! {
+        capacity: 10;
+        "1" => &Dyn1,
+        "2" => &Dyn2,
+    };
+
+    assert!(m.capacity() >= 10);
+}
+
+#[test]
+fn hash_map_comprehension() {
+    let m = hash_map! { x => x * x for x in 0..5 if x % 2 == 0 };
+
+    assert_eq!(m, HashMap::from([(0, 0), (2, 4), (4, 16)]));
+}
+
+#[test]
+fn hash_map_comprehension_nested() {
+    let m = hash_map! { (a, b) => a * b for a in 1..3 for b in a..3 if a != b };
+
+    assert_eq!(m, HashMap::from([((1, 2), 2)]));
+}
+
+#[test]
+fn hash_map_comprehension_mixed() {
+    let m = hash_map! {
+        "zero" => 0,
+        s => s.len() for s in ["a", "bb", "zero"],
+        "ccc" => 3,
+    };
+
+    assert_eq!(
+        m,
+        HashMap::from([("zero", 4), ("a", 1), ("bb", 2), ("ccc", 3)])
+    );
+}
+
+#[test]
+fn hash_map_comprehension_hasher() {
+    let m: HashMap<_, _, BuildHasherDefault<DefaultHasher>> = hash_map! {
+        capacity: 10;
+        hasher: BuildHasherDefault::default();
+        i => i for i in 0..3
+    };
+
+    assert_eq!(m.len(), 3);
+    assert!(m.capacity() >= 10);
+}
+
+#[test]
+fn hash_map_e_comprehension() {
+    let m: HashMap<u16, &dyn Debug> = hash_map_e! {
+        0u8 => &Dyn1,
+        i => &Dyn2 for i in 1u8..3,
+    };
+
+    assert_eq!(m.len(), 3);
+}
+
+#[test]
+fn hash_map_spread() {
+    let defaults = hash_map! { "timeout" => 10, "retries" => 3 };
+
+    let m = hash_map! { ..defaults.clone(), "timeout" => 30 };
+
+    assert_eq!(m, hash_map! { "timeout" => 30, "retries" => 3 });
+
+    let m = hash_map! { "timeout" => 30, ..defaults };
+
+    assert_eq!(m, hash_map! { "timeout" => 10, "retries" => 3 });
+}
+
+#[test]
+fn hash_map_spread_multiple() {
+    let m = hash_map! { ..[(0, 0)], 1 => 1, ..vec![(2, 2)], x => x for x in 3..5 };
+
+    assert_eq!(m.len(), 5);
+    assert_eq!(m[&4], 4);
+}
+
+#[test]
+fn hash_map_spread_capacity() {
+    let m = hash_map! { ..(0..100).map(|x| (x, x)) };
+
+    assert_eq!(m.len(), 100);
+    assert_eq!(
+        m.capacity(),
+        HashMap::<u8, u8>::with_capacity(100).capacity()
+    );
+
+    let m = hash_map! { capacity: 200; ..(0..100).map(|x| (x, x)) };
+
+    assert!(m.capacity() >= 200);
+}
+
+#[test]
+fn hash_map_spread_hasher() {
+    let m = hash_map! {
+        hasher: BuildHasherDefault::<DefaultHasher>::default();
+        ..[("a", 1)],
+        "b" => 2,
+    };
+
+    assert_eq!(m.len(), 2);
+}
+
+#[test]
+fn hash_map_e_spread() {
+    let defaults: HashMap<&str, &dyn Debug> = hash_map_e! { "a" => &0 };
+
+    let m: HashMap<&str, &dyn Debug> = hash_map_e! { ..defaults, "b" => &"b" };
+
+    assert_eq!(m.len(), 2);
+}
+
+#[test]
+fn hash_map_guard() {
+    let m = hash_map! {
+        "a" => 1 if true,
+        "b" => 2 if false,
+        "c" => if true { 3 } else { 4 },
+        "d" => 4 if 1 + 1 == 2,
+    };
+
+    assert_eq!(m, HashMap::from([("a", 1), ("c", 3), ("d", 4)]));
+}
+
+#[test]
+fn hash_map_guard_lazy() {
+    let mut evaluated = 0;
+
+    let m: HashMap<u8, u8> = hash_map! {
+        0 => { evaluated += 1; 0 } if false,
+        1 => { evaluated += 1; 1 } if true,
+    };
+
+    assert_eq!(m.len(), 1);
+    assert_eq!(evaluated, 1);
+}
+
+#[test]
+fn hash_map_cfg() {
+    let m = hash_map! {
+        #[cfg(all())]
+        "a" => 1,
+        #[cfg(any())]
+        "b" => 2,
+        #[cfg(all())]
+        #[allow(clippy::identity_op)]
+        "c" => 3 * 1 if true,
+    };
+
+    assert_eq!(m, HashMap::from([("a", 1), ("c", 3)]));
+}
+
+#[test]
+fn hash_map_optional() {
+    let proxy: Option<&str> = None;
+    let port = Some("80");
+
+    let m = hash_map! {
+        "host" => "localhost",
+        "proxy" =>? proxy,
+        "port" =>? port,
+        "tls" =>? Some("1.3") if false,
+    };
+
+    assert_eq!(m, HashMap::from([("host", "localhost"), ("port", "80")]));
+}
+
+#[test]
+fn hash_map_optional_comprehension() {
+    let m = hash_map! { x =>? x.checked_sub(1) for x in 0u8..3 };
+
+    assert_eq!(m, HashMap::from([(1, 0), (2, 1)]));
+}
+
+#[test]
+fn hash_map_e_conditional() {
+    let m: HashMap<&str, &dyn Debug> = hash_map_e! {
+        "a" => &Dyn1 if true,
+        "b" => &Dyn2 if false,
+        "c" =>? Some(&Dyn2),
+        #[cfg(any())]
+        "d" => &Dyn1,
+    };
+
+    assert_eq!(m.len(), 2);
+}
+
+#[test]
+fn hash_map_shorthand() {
+    let width = 16;
+    let height = 9;
+    let depth = 1;
+
+    let m = hash_map! { width, "area" => width * height, height, depth if false };
+
+    assert_eq!(
+        m,
+        HashMap::from([("width", 16), ("area", 144), ("height", 9)])
+    );
+}
+
+#[test]
+fn hash_map_e_shorthand() {
+    let width = 16u8;
+
+    let m: HashMap<&str, u32> = hash_map_e! { width, "height" => 9u16 };
+
+    assert_eq!(m, HashMap::from([("width", 16), ("height", 9)]));
+}
+
+/// Invokes `$m!` with 2^n distinct keys, one for each token in brackets.
+macro_rules! with_keys {
+    ($m: ident [] $($k: tt)*) => {
+        $m!($($k)*)
+    };
+    ($m: ident [$_n: tt $($n: tt)*] $($k: tt)*) => {
+        with_keys!($m [$($n)*] $((0, $k))* $((1, $k))*)
+    };
+}
+
+macro_rules! long_map {
+    ($($k: tt)*) => {
+        hash_map! { $($k => 0),* }
+    };
+}
+
+macro_rules! long_map_for {
+    ($($k: tt)*) => {
+        hash_map! { $($k => 0,)* k => 1 for k in [$($k),*] }
+    };
+}
+
+macro_rules! long_map_calls {
+    ($($k: tt)*) => {
+        hash_map! { $(Some($k) => 0),* }
+    };
+}
+
+#[test]
+#[allow(clippy::large_stack_arrays)]
+fn hash_map_long() {
+    let m = with_keys!(long_map [. . . . . . . . . . .] ());
+
+    assert_eq!(m.len(), 2048);
+}
+
+#[test]
+fn hash_map_long_calls() {
+    let m = with_keys!(long_map_calls [. . . . . . . .] ());
+
+    assert_eq!(m.len(), 256);
+}
+
+#[test]
+fn hash_map_long_for() {
+    let m = with_keys!(long_map_for [. . . . . . . .] ());
+
+    assert_eq!(m.len(), 256);
+    assert!(m.values().all(|v| *v == 1));
+}
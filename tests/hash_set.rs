@@ -1,4 +1,8 @@
-use map_macro::hash_set;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::BuildHasherDefault;
+
+use map_macro::{hash_set, hash_set_e};
 
 #[test]
 fn hash_set1() {
@@ -27,3 +31,202 @@ fn hash_set2() {
 
     assert!(!s.contains(&4));
 }
+
+#[test]
+fn hash_set_hasher() {
+    let s: HashSet<_, BuildHasherDefault<DefaultHasher>> = hash_set! {
+        hasher: BuildHasherDefault::default();
+        0, 1, 2, 3, 0
+    };
+
+    assert_eq!(s.len(), 4);
+}
+
+#[test]
+fn hash_set_e_hasher() {
+    let s: HashSet<u8, BuildHasherDefault<DefaultHasher>> = hash_set_e! {
+        hasher: BuildHasherDefault::default();
+        0, 1, 2, 3, 0,
+    };
+
+    assert_eq!(s.len(), 4);
+}
+
+#[test]
+fn hash_set_capacity() {
+    let s = hash_set! { capacity: 10; 0, 1, 2, 3, 0 };
+
+    assert_eq!(s.len(), 4);
+    assert!(s.capacity() >= 10);
+}
+
+#[test]
+fn hash_set_capacity_and_hasher() {
+    let s: HashSet<_, BuildHasherDefault<DefaultHasher>> = hash_set! {
+        capacity: 10;
+        hasher: BuildHasherDefault::default();
+        0, 1, 2, 3, 0
+    };
+
+    assert_eq!(s.len(), 4);
+    assert!(s.capacity() >= 10);
+}
+
+#[test]
+fn hash_set_e_capacity() {
+    let s: HashSet<u8> = hash_set_e! { capacity: 10; 0, 1, 2, 3, 0 };
+
+    assert_eq!(s.len(), 4);
+    assert!(s.capacity() >= 10);
+}
+
+#[test]
+fn hash_set_comprehension() {
+    let s = hash_set! { x % 3 for x in 0..10 };
+
+    assert_eq!(s, HashSet::from([0, 1, 2]));
+}
+
+#[test]
+fn hash_set_comprehension_mixed() {
+    let s = hash_set! { capacity: 10; 10, x * y for x in 1..3 for y in 1..3 if x != y, 20 };
+
+    assert_eq!(s, HashSet::from([2, 10, 20]));
+    assert!(s.capacity() >= 10);
+}
+
+#[test]
+fn hash_set_e_comprehension() {
+    let s: HashSet<u16> = hash_set_e! { x for x in [1u8, 2, 3] if x > 1 };
+
+    assert_eq!(s, HashSet::from([2, 3]));
+}
+
+#[test]
+fn hash_set_spread() {
+    let base = hash_set! { 1, 2 };
+
+    assert_eq!(hash_set! { ..base.clone(), 3 }, HashSet::from([1, 2, 3]));
+    assert_eq!(hash_set! { 3, ..base.clone() }, HashSet::from([1, 2, 3]));
+    assert_eq!(
+        hash_set! { 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, ..base, 10 },
+        (0..11).collect(),
+    );
+}
+
+#[test]
+fn hash_set_spread_capacity() {
+    let s = hash_set! { ..0..100 };
+
+    assert_eq!(s.len(), 100);
+    assert_eq!(s.capacity(), HashSet::<u8>::with_capacity(100).capacity());
+
+    let s = hash_set! { capacity: 200; ..0..100, 100 };
+
+    assert!(s.capacity() >= 200);
+}
+
+#[test]
+fn hash_set_ranges() {
+    let start = 2;
+
+    let s = hash_set! { (0..2), start..4 };
+
+    assert!(s.contains(&(0..2)));
+    assert!(s.contains(&(2..4)));
+}
+
+#[test]
+fn hash_set_e_spread() {
+    let s: HashSet<u16> = hash_set_e! { 0u8, ..vec![1u16, 2] };
+
+    assert_eq!(s, HashSet::from([0, 1, 2]));
+}
+
+#[test]
+fn hash_set_conditional() {
+    let extra: Option<u8> = None;
+
+    let s = hash_set! {
+        0 if true,
+        1 if false,
+        #[cfg(all())]
+        2,
+        #[cfg(any())]
+        3,
+        4,
+        ..extra,
+        ..Some(5),
+    };
+
+    assert_eq!(s, HashSet::from([0, 2, 4, 5]));
+}
+
+#[test]
+fn hash_set_e_conditional() {
+    let s: HashSet<u16> = hash_set_e! { 0u8 if true, 1u8 if false, #[cfg(any())] 2u8 };
+
+    assert_eq!(s, HashSet::from([0]));
+}
+
+#[test]
+fn hash_set_range_elements() {
+    let end = 40;
+
+    let s = hash_set! { 1..=10, 20, 30..end, -2..0 };
+
+    assert_eq!(s.len(), 23);
+    assert!(s.contains(&-1));
+    assert!(!s.contains(&40));
+}
+
+#[test]
+fn hash_set_range_elements_conditional() {
+    let s = hash_set! { 0..2 if false, #[cfg(all())] 5..7, 10..x for x in 11..13 };
+
+    assert_eq!(s, HashSet::from([5, 6, 10, 11]));
+}
+
+#[test]
+fn hash_set_e_range_elements() {
+    let s: HashSet<u16> = hash_set_e! { 0u8, 1..3 };
+
+    assert_eq!(s, HashSet::from([0, 1, 2]));
+}
+
+/// Invokes `$m!` with 2^n distinct elements, one for each token in brackets.
+macro_rules! with_elements {
+    ($m: ident [] $($v: tt)*) => {
+        $m!($($v)*)
+    };
+    ($m: ident [$_n: tt $($n: tt)*] $($v: tt)*) => {
+        with_elements!($m [$($n)*] $((0, $v))* $((1, $v))*)
+    };
+}
+
+macro_rules! long_set {
+    ($($v: tt)*) => {
+        hash_set! { $($v),* }
+    };
+}
+
+macro_rules! long_set_spread {
+    ($($v: tt)*) => {
+        hash_set! { $($v,)* ..[$($v),*] }
+    };
+}
+
+#[test]
+#[allow(clippy::large_stack_arrays)]
+fn hash_set_long() {
+    let s = with_elements!(long_set [. . . . . . . . . . .] ());
+
+    assert_eq!(s.len(), 2048);
+}
+
+#[test]
+fn hash_set_long_spread() {
+    let s = with_elements!(long_set_spread [. . . . . . . .] ());
+
+    assert_eq!(s.len(), 256);
+}
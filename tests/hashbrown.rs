@@ -1,7 +1,18 @@
-use hashbrown::HashMap;
+use allocator_api2::alloc::{AllocError, Allocator, Global, Layout};
+use hashbrown::hash_map::DefaultHashBuilder;
+use hashbrown::{HashMap, HashSet};
+use std::cell::Cell;
+use std::collections::hash_map::DefaultHasher;
 use std::fmt::Debug;
+use std::hash::BuildHasherDefault;
+use std::ptr::NonNull;
 
-use map_macro::hashbrown::{hash_map, hash_map_e, hash_set};
+use map_macro::hashbrown::{
+    hash_map, hash_map_e, hash_multimap, hash_multimap_e, hash_set, hash_set_e, lazy_hash_map,
+    lazy_hash_set, nested_hash_map, nested_hash_map_e, try_hash_map, try_hash_map_e, try_hash_set,
+    try_hash_set_e,
+};
+use map_macro::{extend_map, extend_map_e, extend_set, extend_set_e};
 
 #[derive(Debug)]
 struct Dyn1;
@@ -9,6 +20,20 @@ struct Dyn1;
 #[derive(Debug)]
 struct Dyn2;
 
+#[derive(Default)]
+struct Counting(Cell<usize>);
+
+unsafe impl Allocator for &Counting {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.0.set(self.0.get() + 1);
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        Global.deallocate(ptr, layout);
+    }
+}
+
 #[test]
 fn hash_map1() {
     let m = hash_map! {
@@ -82,3 +107,427 @@ fn hash_set2() {
 
     assert!(!s.contains(&4));
 }
+
+#[test]
+fn hash_map_hasher() {
+    let m: HashMap<_, _, BuildHasherDefault<DefaultHasher>> = hash_map! {
+        hasher: BuildHasherDefault::default();
+        "en" => "Hello",
+        "de" => "Hallo",
+    };
+
+    assert_eq!(m["en"], "Hello");
+    assert_eq!(m["de"], "Hallo");
+}
+
+#[test]
+fn hash_map_e_hasher() {
+    drop::<HashMap<&str, &dyn Debug, BuildHasherDefault<DefaultHasher>>>(hash_map_e! {
+        hasher: BuildHasherDefault::default();
+        "1" => &Dyn1,
+        "2" => &Dyn2,
+    });
+}
+
+#[test]
+fn hash_set_hasher() {
+    let s: HashSet<_, BuildHasherDefault<DefaultHasher>> = hash_set! {
+        hasher: BuildHasherDefault::default();
+        0, 1, 2, 3, 0
+    };
+
+    assert_eq!(s.len(), 4);
+}
+
+#[test]
+fn hash_set_e_hasher() {
+    let s: HashSet<u8, BuildHasherDefault<DefaultHasher>> = hash_set_e! {
+        hasher: BuildHasherDefault::default();
+        0, 1, 2, 3, 0,
+    };
+
+    assert_eq!(s.len(), 4);
+}
+
+#[test]
+fn hash_map_capacity() {
+    let m = hash_map! {
+        capacity: 10;
+        "en" => "Hello",
+        "de" => "Hallo",
+    };
+
+    assert_eq!(m.len(), 2);
+    assert!(m.capacity() >= 10);
+}
+
+#[test]
+fn hash_map_capacity_and_hasher() {
+    let m: HashMap<_, _, BuildHasherDefault<DefaultHasher>> = hash_map! {
+        capacity: 10;
+        hasher: BuildHasherDefault::default();
+        "en" => "Hello",
+    };
+
+    assert!(m.capacity() >= 10);
+}
+
+#[test]
+fn hash_map_e_capacity() {
+    let m: HashMap<&str, &dyn Debug> = hash_map_e! {
+        capacity: 10;
+        "1" => &Dyn1,
+        "2" => &Dyn2,
+    };
+
+    assert!(m.capacity() >= 10);
+}
+
+#[test]
+fn hash_set_capacity() {
+    let s = hash_set! { capacity: 10; 0, 1, 2, 3, 0 };
+
+    assert_eq!(s.len(), 4);
+    assert!(s.capacity() >= 10);
+}
+
+#[test]
+fn hash_set_e_capacity() {
+    let s: HashSet<u8> = hash_set_e! {
+        capacity: 10;
+        hasher: hashbrown::hash_map::DefaultHashBuilder::default();
+        0, 1, 2, 3, 0
+    };
+
+    assert_eq!(s.len(), 4);
+    assert!(s.capacity() >= 10);
+}
+
+#[test]
+fn hash_map_in() {
+    let alloc = Counting::default();
+
+    let m = hash_map! { in: &alloc; "en" => "Hello", "de" => "Hallo" };
+
+    assert_eq!(m.len(), 2);
+    assert_eq!(m["de"], "Hallo");
+    assert!(alloc.0.get() > 0);
+}
+
+#[test]
+fn hash_map_in_capacity_and_hasher() {
+    let alloc = Counting::default();
+
+    let m: HashMap<_, _, BuildHasherDefault<DefaultHasher>, _> = hash_map! {
+        capacity: 10;
+        in: &alloc;
+        hasher: BuildHasherDefault::default();
+        "en" => "Hello",
+    };
+
+    assert!(m.capacity() >= 10);
+    assert_eq!(alloc.0.get(), 1);
+
+    let m = hash_map! { hasher: DefaultHashBuilder::default(); in: Global; ..[(0, 0)] };
+
+    assert_eq!(m.len(), 1);
+}
+
+#[test]
+fn hash_map_e_in() {
+    let m: HashMap<&str, &dyn Debug, DefaultHashBuilder, Global> = hash_map_e! {
+        in: Global;
+        capacity: 10;
+        "1" => &Dyn1,
+        "2" => &Dyn2,
+    };
+
+    assert!(m.capacity() >= 10);
+}
+
+#[test]
+fn hash_set_in() {
+    let alloc = Counting::default();
+
+    let s = hash_set! { in: &alloc; 0, 1..3, x for x in 3..5 };
+
+    assert_eq!(s.len(), 5);
+    assert!((0..5).all(|x| s.contains(&x)));
+    assert!(alloc.0.get() > 0);
+}
+
+#[test]
+fn hash_set_e_in() {
+    let s: HashSet<u16, DefaultHashBuilder, Global> = hash_set_e! {
+        capacity: 10;
+        hasher: DefaultHashBuilder::default();
+        in: Global;
+        0u8, 1u8
+    };
+
+    assert_eq!(s.len(), 2);
+    assert!(s.capacity() >= 10);
+}
+
+#[test]
+fn extend_map1() {
+    let mut m = hash_map! { "en" => "Hello" };
+
+    extend_map!(m, "de" => "Hallo", "fr" => "Bonjour");
+
+    assert_eq!(m.len(), 3);
+    assert_eq!(m["fr"], "Bonjour");
+}
+
+#[test]
+fn extend_map_e1() {
+    let mut m: HashMap<&str, &dyn Debug> = hash_map_e! { "en" => &Dyn1 };
+
+    extend_map_e!(m, "de" => &Dyn2, "fr" => &0);
+
+    assert_eq!(m.len(), 3);
+}
+
+#[test]
+fn extend_set1() {
+    let mut s = hash_set! { 0, 1 };
+
+    extend_set!(s, 1, 2, 3);
+
+    assert_eq!(s, HashSet::from([0, 1, 2, 3]));
+}
+
+#[test]
+fn extend_set_e1() {
+    let mut s: HashSet<u16> = hash_set! { 0 };
+
+    extend_set_e!(s, 1u8, 2u8);
+
+    assert_eq!(s, HashSet::from([0, 1, 2]));
+}
+
+lazy_hash_map! {
+    static LAZY_MAP: HashMap<&str, &(dyn Debug + Sync)> = {
+        "1" => &Dyn1,
+        "2" => &Dyn2,
+    };
+}
+
+lazy_hash_set! {
+    static LAZY_SET: HashSet<u16> = { 0u8, 1u8, 0u8 };
+}
+
+#[test]
+fn lazy_hash_map1() {
+    assert_eq!(LAZY_MAP.len(), 2);
+    assert_eq!(format!("{:?}", LAZY_MAP["1"]), "Dyn1");
+}
+
+#[test]
+fn lazy_hash_set1() {
+    assert_eq!(*LAZY_SET, HashSet::from([0, 1]));
+}
+
+#[test]
+fn hash_map_comprehension() {
+    let m = hash_map! { "zero" => 0, x => x.len() for x in ["a", "bb"] if !x.is_empty() };
+
+    assert_eq!(m, HashMap::from([("zero", 0), ("a", 1), ("bb", 2)]));
+}
+
+#[test]
+fn hash_map_e_comprehension() {
+    let m: HashMap<u8, &dyn Debug> = hash_map_e! {
+        capacity: 10;
+        i => &Dyn1 for i in 0..2,
+    };
+
+    assert_eq!(m.len(), 2);
+    assert!(m.capacity() >= 10);
+}
+
+#[test]
+fn hash_set_comprehension() {
+    let s = hash_set! { hasher: DefaultHashBuilder::default(); x * y for x in 0..3 for y in 0..3 };
+
+    assert_eq!(s, HashSet::from([0, 1, 2, 4]));
+}
+
+#[test]
+fn hash_set_e_comprehension() {
+    let s: HashSet<u16> = hash_set_e! { 0u8, x for x in 1u8..3 };
+
+    assert_eq!(s, HashSet::from([0, 1, 2]));
+}
+
+#[test]
+fn nested_hash_map1() {
+    let m = nested_hash_map! {
+        "a" => { "b" => [1, 2], "c" => [] },
+    };
+
+    assert_eq!(m["a"]["b"], vec![1, 2]);
+    assert!(m["a"]["c"].is_empty());
+}
+
+#[test]
+fn nested_hash_map_e1() {
+    let m: HashMap<&str, HashMap<u16, &dyn Debug>> = nested_hash_map_e! {
+        "a" => { 0u8 => &Dyn1, 1u8 => &Dyn2 },
+    };
+
+    assert_eq!(m["a"].len(), 2);
+}
+
+macro_rules! with_keys {
+    ($m: ident [] $($k: tt)*) => {
+        $m!($($k)*)
+    };
+    ($m: ident [$_n: tt $($n: tt)*] $($k: tt)*) => {
+        with_keys!($m [$($n)*] $((0, $k))* $((1, $k))*)
+    };
+}
+
+macro_rules! long_nested_hash_map {
+    ($($k: tt)*) => {
+        nested_hash_map! { $(format!("{:?}", $k) => { -$k.0 => [$k.0] }),* }
+    };
+}
+
+#[test]
+fn nested_hash_map_long() {
+    let m = with_keys!(long_nested_hash_map [. . . . . . . .] ());
+
+    assert_eq!(m.len(), 256);
+    assert!(m.values().all(|m| m.len() == 1));
+}
+
+#[test]
+fn try_hash_map1() {
+    assert_eq!(try_hash_map! { "a" => 0, "b" => 1 }.unwrap().len(), 2);
+
+    let err = try_hash_map! { "a" => 0, "b" => 1, "a" => 2 }.unwrap_err();
+
+    assert_eq!(err.first_index(), 0);
+    assert_eq!(err.second_index(), 2);
+    assert_eq!(err.key(), &"a");
+}
+
+#[test]
+fn try_hash_map_e1() {
+    let m: Result<HashMap<&str, &dyn Debug>, _> = try_hash_map_e! { "1" => &Dyn1, "2" => &Dyn2 };
+
+    assert_eq!(m.unwrap().len(), 2);
+}
+
+#[test]
+fn try_hash_set1() {
+    assert_eq!(try_hash_set! { 0, 1 }.unwrap(), HashSet::from([0, 1]));
+    assert_eq!(try_hash_set! { 0, 1, 1 }.unwrap_err().second_index(), 2);
+}
+
+#[test]
+fn try_hash_set_e1() {
+    let s: Result<HashSet<u16>, _> = try_hash_set_e! { 0u8, 0u8 };
+
+    assert_eq!(s.unwrap_err().key(), &0);
+}
+
+#[test]
+fn hash_multimap1() {
+    let m = hash_multimap! {
+        "a" => 1,
+        "b" => [2, 3],
+        "a" => [4],
+    };
+
+    assert_eq!(m["a"], vec![1, 4]);
+    assert_eq!(m["b"], vec![2, 3]);
+}
+
+#[test]
+fn hash_multimap_inner() {
+    let m = hash_multimap! {
+        inner: HashSet<_>;
+        "a" => [1, 1, 2],
+    };
+
+    assert_eq!(m["a"].len(), 2);
+}
+
+#[test]
+fn hash_multimap_e1() {
+    let m: HashMap<&str, Vec<&dyn Debug>> = hash_multimap_e! {
+        "a" => [&Dyn1, &Dyn2],
+        "a" => &"Hello",
+    };
+
+    assert_eq!(m["a"].len(), 3);
+}
+
+#[test]
+fn hash_map_spread() {
+    let defaults = hash_map! { "timeout" => 10, "retries" => 3 };
+
+    let m = hash_map! { ..defaults, "timeout" => 30 };
+
+    assert_eq!(m["timeout"], 30);
+    assert_eq!(m["retries"], 3);
+}
+
+#[test]
+fn hash_set_spread() {
+    let base = hash_set! { 1, 2 };
+
+    let s = hash_set! { 0, ..base, 3 };
+
+    assert_eq!(s.len(), 4);
+}
+
+#[test]
+fn hash_set_e_spread() {
+    let s: HashSet<u16> = hash_set_e! { 0u8, ..[1u16] };
+
+    assert_eq!(s.len(), 2);
+}
+
+#[test]
+fn hash_map_conditional() {
+    let m = hash_map! {
+        "a" => 1 if true,
+        "b" => 2 if false,
+        #[cfg(any())]
+        "c" => 3,
+        "d" =>? Some(4),
+        "e" =>? None,
+    };
+
+    assert_eq!(m, HashMap::from([("a", 1), ("d", 4)]));
+}
+
+#[test]
+fn hash_set_conditional() {
+    let s = hash_set! { 0, 1 if false, #[cfg(any())] 2 };
+
+    assert_eq!(s, HashSet::from([0]));
+}
+
+#[test]
+fn hash_map_shorthand() {
+    let width = 16;
+    let height = 9;
+
+    let m = hash_map! { width, height, "depth" => 1 };
+
+    assert_eq!(
+        m,
+        HashMap::from([("width", 16), ("height", 9), ("depth", 1)])
+    );
+}
+
+#[test]
+fn hash_set_range_elements() {
+    let s = hash_set! { 0, 1..=3, 10..12 };
+
+    assert_eq!(s, HashSet::from([0, 1, 2, 3, 10, 11]));
+}
@@ -0,0 +1,180 @@
+use im::{HashMap, HashSet, OrdMap, OrdSet, Vector};
+use std::fmt::Debug;
+
+use map_macro::im::{
+    hash_map, hash_map_e, hash_set, hash_set_e, ord_map, ord_map_e, ord_set, ord_set_e, vector,
+    vector_e,
+};
+
+#[derive(Debug)]
+struct Dyn1;
+
+#[derive(Debug)]
+struct Dyn2;
+
+#[test]
+fn hash_map1() {
+    let m = hash_map! {
+        "en" => "Hello",
+        "de" => "Hallo",
+        "fr" => "Bonjour",
+    };
+
+    assert_eq!(m.len(), 3);
+    assert_eq!(m["fr"], "Bonjour");
+
+    let updated = m.update("es", "Hola");
+
+    assert_eq!(m.len(), 3);
+    assert_eq!(updated.len(), 4);
+}
+
+#[test]
+fn hash_map_e1() {
+    let m: HashMap<&str, &dyn Debug> = hash_map_e! {
+        "1" => &Dyn1,
+        "2" => &Dyn2,
+    };
+
+    assert_eq!(m.len(), 2);
+}
+
+#[test]
+fn hash_map_duplicate_keys() {
+    let m = hash_map! { 0 => "a", 0 => "b" };
+
+    assert_eq!(m, HashMap::unit(0, "b"));
+}
+
+#[test]
+fn hash_map_entries() {
+    let other = hash_map! { 10 => 100 };
+    let v = 7;
+
+    let m = hash_map! {
+        x => x * x for x in 0..4 if x % 2 == 0,
+        ..other,
+        5 =>? Some(25),
+        6 =>? None,
+    };
+
+    assert_eq!(m, hash_map! { 0 => 0, 2 => 4, 10 => 100, 5 => 25 });
+
+    let m = hash_map! { v, #[cfg(any())] "w" => 0 };
+
+    assert_eq!(m, HashMap::unit("v", 7));
+}
+
+#[test]
+fn hash_map_e_entries() {
+    let m: HashMap<u64, &dyn Debug> = hash_map_e! { x => &Dyn1 for x in 0u8..3 };
+
+    assert_eq!(m.len(), 3);
+}
+
+#[test]
+fn hash_set1() {
+    let s = hash_set! { 1, 2, 3, 3 };
+
+    assert_eq!(s.len(), 3);
+    assert!(s.contains(&3));
+}
+
+#[test]
+fn hash_set_e1() {
+    let s: HashSet<u64> = hash_set_e! { 1u8, 2u16, 3u32 };
+
+    assert_eq!(s.len(), 3);
+}
+
+#[test]
+fn hash_set_entries() {
+    let other = vec![20, 21];
+
+    let s = hash_set! { 0, 1..=3, ..other, x * 10 for x in 4..6 };
+
+    assert_eq!(s, hash_set! { 0, 1, 2, 3, 20, 21, 40, 50 });
+}
+
+#[test]
+fn ord_map1() {
+    let m = ord_map! { 2 => "b", 0 => "z", 1 => "a" };
+
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), [0, 1, 2]);
+    assert_eq!(m.get_min(), Some(&(0, "z")));
+}
+
+#[test]
+fn ord_map_e1() {
+    let m: OrdMap<u64, &dyn Debug> = ord_map_e! { 1u8 => &Dyn1, 0u16 => &Dyn2 };
+
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), [0, 1]);
+}
+
+#[test]
+fn ord_map_entries() {
+    let m = ord_map! { x => x + 1 for x in 0..3, 10 => 0 };
+
+    assert_eq!(m, ord_map! { 0 => 1, 1 => 2, 2 => 3, 10 => 0 });
+}
+
+#[test]
+fn ord_set1() {
+    let s = ord_set! { 3, 1, 2 };
+
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn ord_set_e1() {
+    let s: OrdSet<u64> = ord_set_e! { 3u8, 1u16, 2u32 };
+
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn ord_set_entries() {
+    let s = ord_set! { 'z', 'a'..='c', c for c in ['x', 'y'] if c != 'y' };
+
+    assert_eq!(s.iter().copied().collect::<String>(), "abcxz");
+}
+
+#[test]
+fn vector1() {
+    let v = vector![1, 2, 3];
+
+    assert_eq!(v.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+    let mut updated = v.clone();
+    updated.push_front(0);
+
+    assert_eq!(v.len(), 3);
+    assert_eq!(updated.len(), 4);
+}
+
+#[test]
+fn vector_e1() {
+    let v: Vector<&dyn Debug> = vector_e![&Dyn1, &Dyn2];
+
+    assert_eq!(v.len(), 2);
+}
+
+#[test]
+fn vector_entries() {
+    let tail = vec![10, 11];
+
+    let v = vector![0, 1..3, ..tail, 12];
+
+    assert_eq!(v.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 10, 11, 12]);
+}
+
+#[test]
+fn empty() {
+    let m: HashMap<u8, u8> = hash_map! {};
+    let s: OrdSet<u8> = ord_set! {};
+    let v: Vector<u8> = vector![];
+
+    assert!(m.is_empty());
+    assert!(s.is_empty());
+    assert!(v.is_empty());
+}
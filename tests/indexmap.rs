@@ -0,0 +1,237 @@
+use indexmap::{IndexMap, IndexSet};
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
+use std::hash::BuildHasherDefault;
+
+use map_macro::indexmap::{index_map, index_map_e, index_set, index_set_e};
+
+#[derive(Debug)]
+struct Dyn1;
+
+#[derive(Debug)]
+struct Dyn2;
+
+#[test]
+fn index_map1() {
+    let m = index_map! {
+        "en" => "Hello",
+        "de" => "Hallo",
+        "fr" => "Bonjour",
+        "es" => "Hola",
+    };
+
+    assert_eq!(m["en"], "Hello");
+    assert_eq!(m["de"], "Hallo");
+    assert_eq!(m["fr"], "Bonjour");
+    assert_eq!(m["es"], "Hola");
+}
+
+#[test]
+fn index_map_order() {
+    let m = index_map! {
+        2 => "c",
+        0 => "a",
+        1 => "b",
+    };
+
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), vec![2, 0, 1]);
+}
+
+#[test]
+fn index_map_duplicate_keeps_first_position() {
+    let m = index_map! {
+        "en" => "Hello",
+        "de" => "Hallo",
+        "en" => "Hi",
+    };
+
+    assert_eq!(m.len(), 2);
+    assert_eq!(m.get_index(0), Some((&"en", &"Hi")));
+}
+
+#[test]
+fn index_map_empty() {
+    let m: IndexMap<u8, u8> = index_map! {};
+
+    assert!(m.is_empty());
+}
+
+#[test]
+fn index_map_e1() {
+    drop::<IndexMap<&str, &dyn Debug>>(index_map_e! {
+        "en" => &"Hello",
+        "de" => &"Hallo",
+        "fr" => &"Bonjour",
+        "es" => &"Hola",
+    });
+}
+
+#[test]
+fn index_map_e2() {
+    drop::<IndexMap<&str, &dyn Debug>>(index_map_e! {
+        "1" => &Dyn1,
+        "2" => &Dyn2,
+    });
+}
+
+#[test]
+fn index_map_hasher() {
+    let m: IndexMap<_, _, BuildHasherDefault<DefaultHasher>> = index_map! {
+        hasher: BuildHasherDefault::default();
+        "en" => "Hello",
+        "de" => "Hallo",
+    };
+
+    assert_eq!(m.get_index(1), Some((&"de", &"Hallo")));
+}
+
+#[test]
+fn index_map_capacity() {
+    let m = index_map! {
+        capacity: 10;
+        "en" => "Hello",
+        "de" => "Hallo",
+    };
+
+    assert_eq!(m.len(), 2);
+    assert!(m.capacity() >= 10);
+}
+
+#[test]
+fn index_map_capacity_and_hasher() {
+    let m = index_map! {
+        capacity: 10;
+        hasher: BuildHasherDefault::<DefaultHasher>::default();
+        "en" => "Hello",
+    };
+
+    assert_eq!(m.len(), 1);
+    assert!(m.capacity() >= 10);
+}
+
+#[test]
+fn index_map_comprehension() {
+    let m = index_map! {
+        "zero" => 0,
+        "big" => x for x in [100, 200],
+        "one" => 1,
+    };
+
+    assert_eq!(
+        m.keys().copied().collect::<Vec<_>>(),
+        vec!["zero", "big", "one"]
+    );
+    assert_eq!(m["big"], 200);
+}
+
+#[test]
+fn index_map_e_comprehension() {
+    let m: IndexMap<u64, &dyn Debug> = index_map_e! { x => &Dyn1 for x in 0..3u8 };
+
+    assert_eq!(m.len(), 3);
+}
+
+#[test]
+fn index_set1() {
+    let s = index_set! { "a", "b", "c", "d" };
+
+    assert_eq!(s.len(), 4);
+
+    assert!(s.contains("a"));
+    assert!(s.contains("b"));
+    assert!(s.contains("c"));
+    assert!(s.contains("d"));
+
+    assert!(!s.contains("e"));
+}
+
+#[test]
+fn index_set_order() {
+    let s = index_set! { 3, 1, 2, 3, 0 };
+
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), vec![3, 1, 2, 0]);
+}
+
+#[test]
+fn index_set_empty() {
+    let s: IndexSet<u8> = index_set! {};
+
+    assert!(s.is_empty());
+}
+
+#[test]
+fn index_set_e1() {
+    let s: IndexSet<u64> = index_set_e! { 0u8, 1u8, 2u8 };
+
+    assert_eq!(s.get_index(2), Some(&2));
+}
+
+#[test]
+fn index_set_hasher_and_capacity() {
+    let s: IndexSet<_, BuildHasherDefault<DefaultHasher>> = index_set! {
+        hasher: BuildHasherDefault::default();
+        capacity: 10;
+        "a", "b",
+    };
+
+    assert_eq!(s.len(), 2);
+    assert!(s.capacity() >= 10);
+}
+
+#[test]
+fn index_set_comprehension() {
+    let s = index_set! { 42, x % 3 for x in 0..10 };
+
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), vec![42, 0, 1, 2]);
+}
+
+#[test]
+fn index_set_e_comprehension() {
+    let s: IndexSet<u64> = index_set_e! { x for x in 0..3u8 if x != 1 };
+
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), vec![0, 2]);
+}
+
+#[test]
+fn index_map_conditional() {
+    let m = index_map! {
+        "a" => 1 if true,
+        "b" => 2 if false,
+        #[cfg(any())]
+        "c" => 3,
+        "d" =>? Some(4),
+        "e" =>? None,
+    };
+
+    assert_eq!(m.into_iter().collect::<Vec<_>>(), vec![("a", 1), ("d", 4)]);
+}
+
+#[test]
+fn index_set_conditional() {
+    let s = index_set! { 0, 1 if false, #[cfg(any())] 2, 3 };
+
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), vec![0, 3]);
+}
+
+#[test]
+fn index_map_shorthand() {
+    let width = 16;
+    let height = 9;
+
+    let m = index_map! { width, "depth" => 1, height };
+
+    assert_eq!(
+        m.into_iter().collect::<Vec<_>>(),
+        vec![("width", 16), ("depth", 1), ("height", 9)]
+    );
+}
+
+#[test]
+fn index_set_range_elements() {
+    let s = index_set! { 10, 0..=2, 5..7 };
+
+    assert_eq!(
+        s.iter().copied().collect::<Vec<_>>(),
+        vec![10, 0, 1, 2, 5, 6]
+    );
+}
@@ -0,0 +1,107 @@
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::fmt::Debug;
+
+use map_macro::{
+    lazy_binary_heap, lazy_btree_map, lazy_btree_set, lazy_hash_map, lazy_hash_set,
+    lazy_linked_list, lazy_vec_deque, lazy_vec_no_clone,
+};
+
+#[derive(Debug)]
+struct Dyn1;
+
+#[derive(Debug)]
+struct Dyn2;
+
+#[derive(Debug, PartialEq)]
+struct UnclonableWrapper(u8);
+
+lazy_hash_map! {
+    static HELLO: HashMap<&str, &str> = {
+        "en" => "Hello",
+        "de" => "Hallo",
+    };
+
+    /// Declarations can have doc comments and a visibility.
+    pub(crate) static DYN: HashMap<u8, &(dyn Debug + Sync)> = {
+        0 => &Dyn1,
+        1 => &Dyn2,
+    };
+
+    static CAPACITY: HashMap<u8, u8> = { capacity: 10; 0 => 0 };
+}
+
+lazy_btree_map! {
+    static BTREE_MAP: BTreeMap<u16, &str> = { 0u8 => "a", 1u8 => "b" };
+}
+
+lazy_hash_set! {
+    static HASH_SET: HashSet<&str> = { "en", "de", "en" };
+}
+
+lazy_btree_set! {
+    static BTREE_SET: BTreeSet<u8> = { 2, 1, 0 };
+}
+
+lazy_vec_deque! {
+    static VEC_DEQUE: VecDeque<&(dyn Debug + Sync)> = [&Dyn1, &Dyn2];
+}
+
+lazy_linked_list! {
+    static LINKED_LIST: LinkedList<u8> = [0, 1, 2];
+}
+
+lazy_binary_heap! {
+    static BINARY_HEAP: BinaryHeap<u8> = [0, 2, 1];
+}
+
+lazy_vec_no_clone! {
+    static VEC_NO_CLONE: Vec<UnclonableWrapper> = [UnclonableWrapper(0); 4];
+}
+
+#[test]
+fn lazy_hash_map1() {
+    assert_eq!(HELLO["en"], "Hello");
+    assert_eq!(HELLO["de"], "Hallo");
+
+    assert_eq!(DYN.len(), 2);
+    assert_eq!(format!("{:?}", DYN[&1]), "Dyn2");
+
+    assert_eq!(CAPACITY.len(), 1);
+    assert!(CAPACITY.capacity() >= 10);
+}
+
+#[test]
+fn lazy_btree_map1() {
+    assert_eq!(*BTREE_MAP, BTreeMap::from([(0, "a"), (1, "b")]));
+}
+
+#[test]
+fn lazy_hash_set1() {
+    assert_eq!(*HASH_SET, HashSet::from(["en", "de"]));
+}
+
+#[test]
+fn lazy_btree_set1() {
+    assert_eq!(*BTREE_SET, BTreeSet::from([0, 1, 2]));
+}
+
+#[test]
+fn lazy_vec_deque1() {
+    assert_eq!(VEC_DEQUE.len(), 2);
+}
+
+#[test]
+fn lazy_linked_list1() {
+    assert_eq!(*LINKED_LIST, LinkedList::from([0, 1, 2]));
+}
+
+#[test]
+fn lazy_binary_heap1() {
+    assert_eq!(BINARY_HEAP.peek(), Some(&2));
+}
+
+#[test]
+fn lazy_vec_no_clone1() {
+    assert_eq!(VEC_NO_CLONE.len(), 4);
+    assert_eq!(VEC_NO_CLONE[3], UnclonableWrapper(0));
+}
@@ -3,12 +3,6 @@ use std::fmt::Debug;
 
 use map_macro::{linked_list, linked_list_e};
 
-#[derive(Debug)]
-struct Dyn1;
-
-#[derive(Debug)]
-struct Dyn2;
-
 #[test]
 fn linked_list1() {
     let v: LinkedList<u8> = linked_list![0, 1, 2, 3];
@@ -49,3 +43,44 @@ fn linked_list_e1() {
 fn linked_list_e2() {
     drop::<LinkedList<&dyn Debug>>(linked_list_e![&0; 4]);
 }
+
+#[test]
+fn linked_list_indexed() {
+    let v: LinkedList<usize> = linked_list![|i| i * 2; 4];
+
+    assert!(v.into_iter().eq([0, 2, 4, 6]));
+}
+
+#[test]
+fn linked_list_indexed_count_evaluated_once() {
+    let mut calls = 0;
+
+    let v: LinkedList<usize> = linked_list![|i| i; {
+        calls += 1;
+        3
+    }];
+
+    assert!(v.into_iter().eq([0, 1, 2]));
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn linked_list_e_indexed() {
+    let v: LinkedList<u64> = linked_list_e![|i| i; 3];
+
+    assert!(v.into_iter().eq([0, 1, 2]));
+}
+
+#[test]
+fn linked_list_spread() {
+    let l = linked_list![..[0, 1], 2, ..vec![3]];
+
+    assert!(l.into_iter().eq(0..4));
+}
+
+#[test]
+fn linked_list_range_elements() {
+    let l = linked_list![0, 1..3, 3..=4];
+
+    assert!(l.into_iter().eq(0..5));
+}
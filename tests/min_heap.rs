@@ -0,0 +1,75 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use map_macro::{min_heap, min_heap_e};
+
+#[test]
+fn min_heap1() {
+    let mut h = min_heap![3, 0, 2, 1];
+
+    assert_eq!(h.len(), 4);
+
+    assert_eq!(h.pop(), Some(Reverse(0)));
+    assert_eq!(h.pop(), Some(Reverse(1)));
+    assert_eq!(h.pop(), Some(Reverse(2)));
+    assert_eq!(h.pop(), Some(Reverse(3)));
+}
+
+#[test]
+fn min_heap2() {
+    let h = min_heap![0; 4];
+
+    assert_eq!(h.len(), 4);
+    assert_eq!(h.peek(), Some(&Reverse(0)));
+}
+
+#[test]
+fn min_heap_empty() {
+    let h: BinaryHeap<Reverse<u8>> = min_heap![];
+
+    assert!(h.is_empty());
+}
+
+#[test]
+fn min_heap_capacity() {
+    let h = min_heap![capacity: 10; 1, 0];
+
+    assert_eq!(h.len(), 2);
+    assert!(h.capacity() >= 10);
+    assert_eq!(h.peek(), Some(&Reverse(0)));
+}
+
+#[test]
+fn min_heap_indexed() {
+    let h = min_heap![|i| 10 - i; 4];
+
+    assert_eq!(
+        h.into_sorted_vec(),
+        vec![Reverse(10), Reverse(9), Reverse(8), Reverse(7)],
+    );
+}
+
+#[test]
+fn min_heap_e1() {
+    let mut h: BinaryHeap<Reverse<u64>> = min_heap_e![3u8, 1u16, 2u32];
+
+    assert_eq!(h.pop(), Some(Reverse(1)));
+}
+
+#[test]
+fn min_heap_e_capacity() {
+    let h: BinaryHeap<Reverse<u64>> = min_heap_e![capacity: 10; 1u8];
+
+    assert!(h.capacity() >= 10);
+}
+
+#[test]
+fn min_heap_e_repeat() {
+    let h: BinaryHeap<Reverse<u64>> = min_heap_e![1u8; 3];
+
+    assert_eq!(h.len(), 3);
+
+    let h: BinaryHeap<Reverse<u64>> = min_heap_e![|i| i; 3];
+
+    assert_eq!(h.peek(), Some(&Reverse(0)));
+}
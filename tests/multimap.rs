@@ -0,0 +1,159 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::Debug;
+
+use map_macro::{btree_multimap, btree_multimap_e, hash_multimap, hash_multimap_e};
+
+#[derive(Debug)]
+struct Dyn1;
+
+#[derive(Debug)]
+struct Dyn2;
+
+#[test]
+fn hash_multimap1() {
+    let m = hash_multimap! {
+        "a" => 1,
+        "b" => 2,
+        "a" => 3,
+    };
+
+    assert_eq!(m.len(), 2);
+    assert_eq!(m["a"], vec![1, 3]);
+    assert_eq!(m["b"], vec![2]);
+}
+
+#[test]
+fn hash_multimap_list() {
+    let m = hash_multimap! {
+        "a" => [1, 2],
+        "b" => [3],
+        "a" => 4,
+        "a" => [5, 6,],
+    };
+
+    assert_eq!(m["a"], vec![1, 2, 4, 5, 6]);
+    assert_eq!(m["b"], vec![3]);
+}
+
+#[test]
+fn hash_multimap_empty_list() {
+    let m: HashMap<&str, Vec<u8>> = hash_multimap! { "a" => [] };
+
+    assert!(m["a"].is_empty());
+}
+
+#[test]
+fn hash_multimap_empty() {
+    let m: HashMap<&str, Vec<u8>> = hash_multimap! {};
+
+    assert!(m.is_empty());
+}
+
+#[test]
+fn hash_multimap_inner() {
+    let m = hash_multimap! {
+        inner: BTreeSet<_>;
+        "a" => [3, 1],
+        "a" => 1,
+        "b" => 2,
+    };
+
+    assert_eq!(m["a"], BTreeSet::from([1, 3]));
+    assert_eq!(m["b"], BTreeSet::from([2]));
+}
+
+#[test]
+fn hash_multimap_inner_inferred() {
+    let m: HashMap<&str, HashSet<u8>> = hash_multimap! {
+        inner: _;
+        "a" => 1,
+        "a" => 1,
+    };
+
+    assert_eq!(m["a"].len(), 1);
+}
+
+#[test]
+fn hash_multimap_e1() {
+    let m: HashMap<&str, Vec<&dyn Debug>> = hash_multimap_e! {
+        "a" => &Dyn1,
+        "a" => [&Dyn2, &"Hello"],
+        "b" => [],
+    };
+
+    assert_eq!(m["a"].len(), 3);
+    assert!(m["b"].is_empty());
+}
+
+#[test]
+fn hash_multimap_e_inner() {
+    let m: HashMap<u64, VecDeque<u64>> = hash_multimap_e! {
+        inner: VecDeque<_>;
+        0u8 => [1u8, 2u16],
+        0u32 => 3u32,
+    };
+
+    assert_eq!(m[&0], VecDeque::from([1, 2, 3]));
+}
+
+#[test]
+fn btree_multimap1() {
+    let m = btree_multimap! {
+        "b" => 2,
+        "a" => [1, 3],
+        "b" => 4,
+    };
+
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), vec!["a", "b"]);
+    assert_eq!(m["a"], vec![1, 3]);
+    assert_eq!(m["b"], vec![2, 4]);
+}
+
+#[test]
+fn btree_multimap_inner() {
+    let m: BTreeMap<&str, BTreeSet<u8>> = btree_multimap! {
+        inner: _;
+        "a" => [3, 1, 3],
+    };
+
+    assert_eq!(m["a"], BTreeSet::from([1, 3]));
+}
+
+#[test]
+fn btree_multimap_e1() {
+    let m: BTreeMap<&str, Vec<&dyn Debug>> = btree_multimap_e! {
+        "a" => [&Dyn1, &Dyn2],
+        "a" => &0,
+    };
+
+    assert_eq!(m["a"].len(), 3);
+}
+
+macro_rules! with_keys {
+    ($m: ident [] $($k: tt)*) => {
+        $m!($($k)*)
+    };
+    ($m: ident [$_n: tt $($n: tt)*] $($k: tt)*) => {
+        with_keys!($m [$($n)*] $((0, $k))* $((1, $k))*)
+    };
+}
+
+macro_rules! long_multimap {
+    ($($k: tt)*) => {
+        hash_multimap! {
+            $($k.0 => -1,)*
+            $($k.0 => [$k.0, -$k.0],)*
+            2 => [$(-$k.0),*],
+        }
+    };
+}
+
+#[test]
+#[allow(clippy::large_stack_arrays)]
+fn hash_multimap_long() {
+    let m: HashMap<i32, Vec<i32>> = with_keys!(long_multimap [. . . . . . . .] ());
+
+    assert_eq!(m[&0].len(), 128 * 3);
+    assert_eq!(m[&1].len(), 128 * 3);
+    assert_eq!(m[&2].len(), 256);
+}
@@ -0,0 +1,134 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+
+use map_macro::{nested_btree_map, nested_btree_map_e, nested_hash_map, nested_hash_map_e};
+
+#[derive(Debug)]
+struct Dyn1;
+
+#[derive(Debug)]
+struct Dyn2;
+
+#[test]
+fn nested_hash_map1() {
+    let m = nested_hash_map! {
+        "a" => {
+            "b" => {
+                "c" => 1,
+                "d" => 2,
+            },
+            "e" => {},
+        },
+        "f" => {
+            "g" => { "h" => 3 },
+        },
+    };
+
+    assert_eq!(m["a"]["b"]["c"], 1);
+    assert_eq!(m["a"]["b"]["d"], 2);
+    assert!(m["a"]["e"].is_empty());
+    assert_eq!(m["f"]["g"]["h"], 3);
+}
+
+#[test]
+fn nested_hash_map2() {
+    let m = nested_hash_map! {
+        "matrix" => [[1, 2], [3, 4], []],
+        "list" => [[5]],
+    };
+
+    assert_eq!(m["matrix"], vec![vec![1, 2], vec![3, 4], vec![]]);
+    assert_eq!(m["list"], vec![vec![5]]);
+}
+
+#[test]
+fn nested_hash_map3() {
+    let m = nested_hash_map! {
+        "users" => [
+            { "name" => "Alice".to_owned() },
+            { "name" => ["Bob", "Builder"].join(" ") },
+        ],
+    };
+
+    assert_eq!(m["users"][0]["name"], "Alice");
+    assert_eq!(m["users"][1]["name"], "Bob Builder");
+}
+
+#[test]
+fn nested_hash_map_leaves() {
+    let m = nested_hash_map! {
+        1 => ({ let x = 2; x * 2 }),
+        2 => [1, 2].len(),
+    };
+
+    assert_eq!(m, HashMap::from([(1, 4), (2, 2)]));
+}
+
+#[test]
+fn nested_hash_map_e1() {
+    let m: HashMap<u16, HashMap<&str, Vec<&dyn Debug>>> = nested_hash_map_e! {
+        0u8 => { "a" => [&Dyn1, &Dyn2] },
+        1u8 => { "b" => [] },
+    };
+
+    assert_eq!(m[&0]["a"].len(), 2);
+    assert!(m[&1]["b"].is_empty());
+}
+
+#[test]
+fn nested_btree_map1() {
+    let m = nested_btree_map! {
+        "b" => { "d" => [1, 2], "c" => [3] },
+        "a" => {},
+    };
+
+    assert_eq!(
+        m,
+        BTreeMap::from([
+            ("a", BTreeMap::new()),
+            ("b", BTreeMap::from([("c", vec![3]), ("d", vec![1, 2])])),
+        ])
+    );
+}
+
+#[test]
+fn nested_btree_map_e1() {
+    let m: BTreeMap<&str, BTreeMap<&str, &dyn Debug>> = nested_btree_map_e! {
+        "a" => { "b" => &Dyn1, "c" => &0 },
+    };
+
+    assert_eq!(m["a"].len(), 2);
+}
+
+macro_rules! with_keys {
+    ($m: ident [] $($k: tt)*) => {
+        $m!($($k)*)
+    };
+    ($m: ident [$_n: tt $($n: tt)*] $($k: tt)*) => {
+        with_keys!($m [$($n)*] $((0, $k))* $((1, $k))*)
+    };
+}
+
+macro_rules! long_nested_hash_map {
+    ($($k: tt)*) => {
+        nested_hash_map! {
+            $(format!("{:?}", $k) => { "a".to_owned() => [-1, $k.0] },)*
+            "b".to_owned() => { $(format!("{:?}", $k) => [$k.0],)* "c".to_owned() => [-1] },
+            "d".to_owned() => { "e".to_owned() => [$(-$k.0),*] },
+        }
+    };
+}
+
+#[test]
+#[allow(clippy::large_stack_arrays)]
+fn nested_hash_map_long() {
+    let m = with_keys!(long_nested_hash_map [. . . . . . . .] ());
+
+    assert_eq!(m.len(), 258);
+    assert_eq!(
+        m["(1, (0, (0, (0, (0, (0, (0, (0, ()))))))))"]["a"],
+        [-1, 1]
+    );
+    assert_eq!(m["b"].len(), 257);
+    assert_eq!(m["d"]["e"].len(), 256);
+}
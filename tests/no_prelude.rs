@@ -0,0 +1,79 @@
+#![no_implicit_prelude]
+
+use ::std::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+use ::std::vec::Vec;
+
+#[test]
+fn btree_map() {
+    let m: BTreeMap<u8, u8> = ::map_macro::btree_map! { 0 => 1, 1 => 2 };
+    ::std::assert_eq!(m.len(), 2);
+
+    let m: BTreeMap<u8, u8> = ::map_macro::btree_map_e! { x => x for x in 0..3 };
+    ::std::assert_eq!(m.len(), 3);
+
+    let m: BTreeMap<u8, u8> = ::map_macro::btree_map! { ..m, 3 => 3 };
+    ::std::assert_eq!(m.len(), 4);
+}
+
+#[test]
+fn btree_set() {
+    let s: BTreeSet<u8> = ::map_macro::btree_set! { 0, 1 };
+    ::std::assert_eq!(s.len(), 2);
+
+    let s: BTreeSet<u8> = ::map_macro::btree_set_e! { x for x in 0..3 };
+    ::std::assert_eq!(s.len(), 3);
+}
+
+#[test]
+fn vec_deque() {
+    let v: VecDeque<u8> = ::map_macro::vec_deque![capacity: 4; 0, 1];
+    ::std::assert_eq!(v.len(), 2);
+
+    let v: VecDeque<usize> = ::map_macro::vec_deque_e![|i| i; 3];
+    ::std::assert_eq!(v.len(), 3);
+
+    let v: VecDeque<u8> = ::map_macro::vec_deque![0, 1, 2];
+    ::std::assert_eq!(v.len(), 3);
+
+    let v: VecDeque<u8> = ::map_macro::vec_deque_e![..v, 3];
+    ::std::assert_eq!(v.len(), 4);
+}
+
+#[test]
+fn linked_list() {
+    let l: LinkedList<u8> = ::map_macro::linked_list![0; 2];
+    ::std::assert_eq!(l.len(), 2);
+
+    let l: LinkedList<u8> = ::map_macro::linked_list_e![0, 1, 2];
+    ::std::assert_eq!(l.len(), 3);
+
+    let l: LinkedList<u8> = ::map_macro::linked_list![..l, 3];
+    ::std::assert_eq!(l.len(), 4);
+}
+
+#[test]
+fn binary_heap() {
+    let h: BinaryHeap<u8> = ::map_macro::binary_heap![capacity: 4; 0, 1];
+    ::std::assert_eq!(h.len(), 2);
+
+    let h: BinaryHeap<usize> = ::map_macro::binary_heap_e![|i| i; 3];
+    ::std::assert_eq!(h.len(), 3);
+
+    let h: BinaryHeap<u8> = ::map_macro::binary_heap![0, 1, 2];
+    ::std::assert_eq!(h.len(), 3);
+}
+
+#[test]
+fn vec_no_clone() {
+    let v: Vec<u8> = ::map_macro::vec_no_clone![0, 1];
+    ::std::assert_eq!(v.len(), 2);
+
+    let v: Vec<u8> = ::map_macro::vec_no_clone![capacity: 4; 0, 1];
+    ::std::assert_eq!(v.len(), 2);
+
+    let v: Vec<u8> = ::map_macro::vec_no_clone![0; 3];
+    ::std::assert_eq!(v.len(), 3);
+
+    let v: Vec<usize> = ::map_macro::vec_no_clone_e![|i| i; 3];
+    ::std::assert_eq!(v.len(), 3);
+}
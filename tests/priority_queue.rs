@@ -0,0 +1,102 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::Debug;
+
+use map_macro::{priority_queue, priority_queue_e, Prioritized};
+
+#[derive(Debug, PartialEq)]
+struct Unordered(&'static str);
+
+#[test]
+fn priority_queue1() {
+    let mut q = priority_queue! {
+        Unordered("low") => 1,
+        Unordered("high") => 10,
+        Unordered("mid") => 5,
+    };
+
+    assert_eq!(q.len(), 3);
+
+    assert_eq!(q.pop().unwrap().item, Unordered("high"));
+    assert_eq!(q.pop().unwrap().item, Unordered("mid"));
+    assert_eq!(q.pop().unwrap().into_item(), Unordered("low"));
+}
+
+#[test]
+fn priority_queue_reverse() {
+    let mut q = priority_queue! {
+        'a' => Reverse(7),
+        'b' => Reverse(2),
+        'c' => Reverse(4),
+    };
+
+    assert_eq!(q.pop().unwrap().item, 'b');
+    assert_eq!(q.pop().unwrap().item, 'c');
+    assert_eq!(q.pop().unwrap().item, 'a');
+}
+
+#[test]
+fn priority_queue_empty() {
+    let q: BinaryHeap<Prioritized<Unordered, u8>> = priority_queue! {};
+
+    assert!(q.is_empty());
+}
+
+#[test]
+fn priority_queue_capacity() {
+    let q = priority_queue! { capacity: 10; "a" => 1, "b" => 2 };
+
+    assert_eq!(q.len(), 2);
+    assert!(q.capacity() >= 10);
+    assert_eq!(q.peek().unwrap().item, "b");
+}
+
+#[test]
+fn priority_queue_repeat() {
+    let q = priority_queue![Unordered("worker") => 0; 3];
+
+    assert_eq!(q.len(), 3);
+}
+
+#[test]
+fn priority_queue_indexed() {
+    let mut q = priority_queue![|i| format!("job {}", i) => i; 4];
+
+    assert_eq!(q.pop().unwrap().item, "job 3");
+    assert_eq!(q.pop().unwrap().priority, 2);
+}
+
+#[test]
+fn priority_queue_e1() {
+    let mut q: BinaryHeap<Prioritized<&dyn Debug, u64>> = priority_queue_e! {
+        &"Hello" => 1u8,
+        &Unordered("World") => 2u32,
+    };
+
+    assert_eq!(q.pop().unwrap().priority, 2);
+    assert_eq!(format!("{:?}", q.pop().unwrap().item), "\"Hello\"");
+}
+
+#[test]
+fn priority_queue_e_capacity() {
+    let q: BinaryHeap<Prioritized<&str, u64>> = priority_queue_e! { capacity: 10; "a" => 1u8 };
+
+    assert!(q.capacity() >= 10);
+}
+
+#[test]
+fn priority_queue_e_repeat() {
+    let q: BinaryHeap<Prioritized<u64, u64>> = priority_queue_e![0u8 => 1u8; 2];
+
+    assert_eq!(q.len(), 2);
+
+    let q: BinaryHeap<Prioritized<u64, u64>> = priority_queue_e![|i| i => i; 3];
+
+    assert_eq!(q.peek().unwrap().item, 2);
+}
+
+#[test]
+fn prioritized_ignores_item() {
+    assert_eq!(Prioritized::new(1, 0), Prioritized::new(2, 0));
+    assert!(Prioritized::new(1, 0) < Prioritized::new(0, 1));
+}
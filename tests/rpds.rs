@@ -0,0 +1,208 @@
+use rpds::{HashTrieMap, HashTrieSet, List, RedBlackTreeMap, RedBlackTreeSet, Vector};
+use std::fmt::Debug;
+
+use map_macro::rpds::{
+    ht_map, ht_map_e, ht_set, ht_set_e, list, list_e, rbt_map, rbt_map_e, rbt_set, rbt_set_e,
+    vector, vector_e,
+};
+
+#[derive(Debug)]
+struct Dyn1;
+
+#[derive(Debug)]
+struct Dyn2;
+
+#[test]
+fn ht_map1() {
+    let m = ht_map! {
+        "en" => "Hello",
+        "de" => "Hallo",
+        "fr" => "Bonjour",
+    };
+
+    assert_eq!(m.size(), 3);
+    assert_eq!(m["fr"], "Bonjour");
+
+    let updated = m.insert("es", "Hola");
+
+    assert_eq!(m.size(), 3);
+    assert_eq!(updated.size(), 4);
+}
+
+#[test]
+fn ht_map_e1() {
+    let m: HashTrieMap<&str, &dyn Debug> = ht_map_e! {
+        "1" => &Dyn1,
+        "2" => &Dyn2,
+    };
+
+    assert_eq!(m.size(), 2);
+}
+
+#[test]
+fn ht_map_duplicate_keys() {
+    let m = ht_map! { 0 => "a", 0 => "b" };
+
+    assert_eq!(m.size(), 1);
+    assert_eq!(m[&0], "b");
+}
+
+#[test]
+fn ht_map_entries() {
+    let other = vec![(10, 100)];
+    let v = 7;
+
+    let m = ht_map! {
+        x => x * x for x in 0..4 if x % 2 == 0,
+        ..other,
+        5 =>? Some(25),
+        6 =>? None,
+    };
+
+    assert_eq!(m, ht_map! { 0 => 0, 2 => 4, 10 => 100, 5 => 25 });
+
+    let m = ht_map! { v, #[cfg(any())] "w" => 0 };
+
+    assert_eq!(m, ht_map! { "v" => 7 });
+}
+
+#[test]
+fn ht_map_e_entries() {
+    let m: HashTrieMap<u64, &dyn Debug> = ht_map_e! { x => &Dyn1 for x in 0u8..3 };
+
+    assert_eq!(m.size(), 3);
+}
+
+#[test]
+fn ht_set1() {
+    let s = ht_set! { 1, 2, 3, 3 };
+
+    assert_eq!(s.size(), 3);
+    assert!(s.contains(&3));
+}
+
+#[test]
+fn ht_set_e1() {
+    let s: HashTrieSet<u64> = ht_set_e! { 1u8, 2u16, 3u32 };
+
+    assert_eq!(s.size(), 3);
+}
+
+#[test]
+fn ht_set_entries() {
+    let other = vec![20, 21];
+
+    let s = ht_set! { 0, 1..=3, ..other, x * 10 for x in 4..6 };
+
+    assert_eq!(s, ht_set! { 0, 1, 2, 3, 20, 21, 40, 50 });
+}
+
+#[test]
+fn rbt_map1() {
+    let m = rbt_map! { 2 => "b", 0 => "z", 1 => "a" };
+
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), [0, 1, 2]);
+    assert_eq!(m.first(), Some((&0, &"z")));
+}
+
+#[test]
+fn rbt_map_e1() {
+    let m: RedBlackTreeMap<u64, &dyn Debug> = rbt_map_e! { 1u8 => &Dyn1, 0u16 => &Dyn2 };
+
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), [0, 1]);
+}
+
+#[test]
+fn rbt_map_entries() {
+    let m = rbt_map! { x => x + 1 for x in 0..3, 10 => 0 };
+
+    assert_eq!(m, rbt_map! { 0 => 1, 1 => 2, 2 => 3, 10 => 0 });
+}
+
+#[test]
+fn rbt_set1() {
+    let s = rbt_set! { 3, 1, 2 };
+
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn rbt_set_e1() {
+    let s: RedBlackTreeSet<u64> = rbt_set_e! { 3u8, 1u16, 2u32 };
+
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn rbt_set_entries() {
+    let s = rbt_set! { 'z', 'a'..='c', c for c in ['x', 'y'] if c != 'y' };
+
+    assert_eq!(s.iter().copied().collect::<String>(), "abcxz");
+}
+
+#[test]
+fn vector1() {
+    let v = vector![1, 2, 3];
+
+    assert_eq!(v.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+    let updated = v.push_back(4);
+
+    assert_eq!(v.len(), 3);
+    assert_eq!(updated.len(), 4);
+}
+
+#[test]
+fn vector_e1() {
+    let v: Vector<&dyn Debug> = vector_e![&Dyn1, &Dyn2];
+
+    assert_eq!(v.len(), 2);
+}
+
+#[test]
+fn vector_entries() {
+    let tail = vec![10, 11];
+
+    let v = vector![0, 1..3, ..tail, 12];
+
+    assert_eq!(v.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 10, 11, 12]);
+}
+
+#[test]
+fn list1() {
+    let l = list![1, 2, 3];
+
+    assert_eq!(l.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+    let updated = l.push_front(0);
+
+    assert_eq!(l.len(), 3);
+    assert_eq!(updated.first(), Some(&0));
+}
+
+#[test]
+fn list_e1() {
+    let l: List<&dyn Debug> = list_e![&Dyn1, &Dyn2];
+
+    assert_eq!(l.len(), 2);
+}
+
+#[test]
+fn list_entries() {
+    let tail = vec![10, 11];
+
+    let l = list![0, 1..3, ..tail, 12];
+
+    assert_eq!(l.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 10, 11, 12]);
+}
+
+#[test]
+fn empty() {
+    let m: HashTrieMap<u8, u8> = ht_map! {};
+    let s: RedBlackTreeSet<u8> = rbt_set! {};
+    let l: List<u8> = list![];
+
+    assert!(m.is_empty());
+    assert!(s.is_empty());
+    assert!(l.is_empty());
+}
@@ -0,0 +1,241 @@
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashMap};
+
+use map_macro::serde_json::{json_map, try_json_map};
+
+#[derive(Serialize)]
+struct User {
+    name: &'static str,
+    admin: bool,
+}
+
+#[test]
+fn json_map1() {
+    let m = json_map! {
+        "a" => 1,
+        "b" => [1, 2],
+        "c" => "c",
+    };
+
+    assert_eq!(Value::Object(m), json!({ "a": 1, "b": [1, 2], "c": "c" }));
+}
+
+#[test]
+fn json_map_keys() {
+    let m = json_map! {
+        "a" => 0,
+        String::from("b") => 1,
+        'c' => 2,
+    };
+
+    assert_eq!(
+        m.keys().map(String::as_str).collect::<Vec<_>>(),
+        ["a", "b", "c"]
+    );
+}
+
+#[test]
+fn json_map_values() {
+    let m = json_map! {
+        "null" => (),
+        "none" => None::<u8>,
+        "some" => Some(1.5),
+        "vec" => vec!["x", "y"],
+        "user" => User { name: "Alice", admin: true },
+        "value" => json!({ "x": 0 }),
+    };
+
+    assert_eq!(
+        Value::Object(m),
+        json!({
+            "null": null,
+            "none": null,
+            "some": 1.5,
+            "vec": ["x", "y"],
+            "user": { "name": "Alice", "admin": true },
+            "value": { "x": 0 },
+        }),
+    );
+}
+
+#[test]
+fn json_map_nested() {
+    let m = json_map! {
+        "a" => json_map! {
+            "b" => json_map! { "c" => 1 },
+        },
+    };
+
+    assert_eq!(m["a"]["b"]["c"], 1);
+}
+
+#[test]
+fn json_map_nested_braces() {
+    let m = json_map! {
+        "a" => { "b" => { "c" => 1 } },
+        "d" => [{ "e" => true }, { "e" => false }],
+        "f" => [[1, 2], [3, 4]],
+        "g" => -1,
+    };
+
+    assert_eq!(
+        Value::Object(m),
+        json!({
+            "a": { "b": { "c": 1 } },
+            "d": [{ "e": true }, { "e": false }],
+            "f": [[1, 2], [3, 4]],
+            "g": -1,
+        }),
+    );
+}
+
+#[test]
+fn json_map_nested_entries() {
+    let name = "Alice";
+
+    let m = json_map! {
+        name,
+        #[cfg(all())]
+        "user" => { name, "admin" => false if name == "Bob" },
+        #[cfg(any())]
+        "skipped" => { "a" => 0 },
+        "flag" => { "on" => true } if name == "Alice",
+        format!("x{}", i) => { "i" => i } for i in 0..2,
+        "list" => [{ "i" => 0 }].len(),
+        "block" => ({ 1 }),
+    };
+
+    assert_eq!(
+        Value::Object(m),
+        json!({
+            "name": "Alice",
+            "user": { "name": "Alice" },
+            "flag": { "on": true },
+            "x0": { "i": 0 },
+            "x1": { "i": 1 },
+            "list": 1,
+            "block": 1,
+        }),
+    );
+}
+
+#[test]
+fn json_map_empty() {
+    let m = json_map! {};
+
+    assert_eq!(m, Map::new());
+}
+
+#[test]
+fn json_map_spread() {
+    let defaults = json_map! { "a" => 0, "b" => 0 };
+    let other: HashMap<&str, i32> = HashMap::from([("c", 3)]);
+
+    let m = json_map! {
+        ..defaults,
+        "b" => 2,
+        ..other,
+    };
+
+    assert_eq!(Value::Object(m), json!({ "a": 0, "b": 2, "c": 3 }));
+}
+
+#[test]
+fn json_map_spread_borrowed() {
+    let defaults = json_map! { "a" => [0] };
+
+    let m = json_map! { ..&defaults, "b" => 1 };
+
+    assert_eq!(m["a"], defaults["a"]);
+    assert_eq!(m.len(), 2);
+}
+
+#[test]
+fn json_map_entries() {
+    let name = "Alice";
+    let nickname: Option<&str> = None;
+
+    let m = json_map! {
+        name,
+        "nickname" =>? nickname,
+        "admin" => true if name == "Bob",
+        format!("x{}", i) => i for i in 0..3 if i != 1,
+    };
+
+    assert_eq!(
+        Value::Object(m),
+        json!({ "name": "Alice", "x0": 0, "x2": 2 })
+    );
+}
+
+#[test]
+#[should_panic(expected = "failed to convert value of `json_map!` to JSON")]
+fn json_map_invalid_value() {
+    let invalid: BTreeMap<(u8, u8), u8> = BTreeMap::from([((0, 0), 0)]);
+
+    let _ = json_map! { "a" => invalid };
+}
+
+#[test]
+fn try_json_map1() {
+    let m = try_json_map! {
+        "a" => 1,
+        "b" => { "c" => [1, 2] },
+    };
+
+    assert_eq!(
+        Value::Object(m.unwrap()),
+        json!({ "a": 1, "b": { "c": [1, 2] } })
+    );
+}
+
+#[test]
+fn try_json_map_invalid_value() {
+    let invalid: BTreeMap<(u8, u8), u8> = BTreeMap::from([((0, 0), 0)]);
+
+    assert!(try_json_map! { "a" => 0, "b" => &invalid }.is_err());
+    assert!(try_json_map! { "a" => { "b" => { "c" => &invalid } } }.is_err());
+    assert!(try_json_map! { "a" => [{ "b" => 0 }, { "b" => &invalid }] }.is_err());
+}
+
+macro_rules! with_keys {
+    ($m: ident [] $($k: tt)*) => {
+        $m!($($k)*)
+    };
+    ($m: ident [$_n: tt $($n: tt)*] $($k: tt)*) => {
+        with_keys!($m [$($n)*] $((0, $k))* $((1, $k))*)
+    };
+}
+
+macro_rules! long_object {
+    ($($k: tt)*) => {
+        json_map! { $(format!("{:?}", $k) => -1),* }
+    };
+}
+
+macro_rules! long_nested_object {
+    ($($k: tt)*) => {
+        json_map! { $(format!("{:?}", $k) => { "a" => [{ "b" => 1 }] },)* "c" => -1 }
+    };
+}
+
+#[test]
+fn json_map_long() {
+    let m = with_keys!(long_object [. . . . . . . .] ());
+
+    assert_eq!(m.len(), 256);
+    assert!(m.values().all(|v| *v == -1));
+}
+
+#[test]
+fn json_map_long_nested() {
+    let m = with_keys!(long_nested_object [. . . . . . . .] ());
+
+    assert_eq!(m.len(), 257);
+    assert_eq!(
+        m["(0, (1, (0, (1, (0, (1, (0, (1, ()))))))))"]["a"][0]["b"],
+        1
+    );
+    assert_eq!(m["c"], -1);
+}
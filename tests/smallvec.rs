@@ -0,0 +1,103 @@
+use smallvec::SmallVec;
+use std::cell::RefCell;
+use std::fmt::Debug;
+
+use map_macro::smallvec::{small_vec, small_vec_e};
+
+#[derive(PartialEq, Debug)]
+struct UnclonableWrapper(usize);
+
+#[derive(Debug)]
+struct Dyn1;
+
+#[derive(Debug)]
+struct Dyn2;
+
+#[test]
+fn small_vec1() {
+    let v: SmallVec<[u8; 4]> = small_vec![0, 1, 2, 3];
+
+    assert_eq!(v.as_slice(), &[0, 1, 2, 3]);
+    assert!(!v.spilled());
+}
+
+#[test]
+fn small_vec_spilled() {
+    let v: SmallVec<[u8; 2]> = small_vec![0, 1, 2, 3];
+
+    assert_eq!(v.as_slice(), &[0, 1, 2, 3]);
+    assert!(v.spilled());
+}
+
+#[test]
+fn small_vec_empty() {
+    let v: SmallVec<[u8; 2]> = small_vec![];
+
+    assert!(v.is_empty());
+}
+
+#[test]
+fn small_vec_repeat() {
+    let v: SmallVec<[RefCell<Vec<u8>>; 2]> = small_vec![RefCell::new(Vec::new()); 2];
+
+    v[0].borrow_mut().push(1);
+
+    assert_eq!(v[0].borrow().len(), 1);
+    assert_eq!(v[1].borrow().len(), 0);
+}
+
+#[test]
+fn small_vec_repeat_unclonable() {
+    let v: SmallVec<[UnclonableWrapper; 4]> = small_vec![UnclonableWrapper(0); 3];
+
+    assert_eq!(v.len(), 3);
+    assert_eq!(v[2], UnclonableWrapper(0));
+}
+
+#[test]
+fn small_vec_indexed() {
+    let v: SmallVec<[UnclonableWrapper; 4]> = small_vec![|i| UnclonableWrapper(i); 3];
+
+    assert_eq!(
+        v.as_slice(),
+        &[
+            UnclonableWrapper(0),
+            UnclonableWrapper(1),
+            UnclonableWrapper(2),
+        ],
+    );
+}
+
+#[test]
+fn small_vec_count_evaluated_once() {
+    let mut calls = 0;
+
+    let v: SmallVec<[usize; 4]> = small_vec![|i| i; {
+        calls += 1;
+        3
+    }];
+
+    assert_eq!(v.as_slice(), &[0, 1, 2]);
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn small_vec_e1() {
+    let v: SmallVec<[&dyn Debug; 2]> = small_vec_e![&Dyn1, &Dyn2, &"Hello"];
+
+    assert_eq!(v.len(), 3);
+}
+
+#[test]
+fn small_vec_e2() {
+    let v: SmallVec<[&dyn Debug; 2]> = small_vec_e![&Dyn1; 2];
+
+    assert_eq!(v.len(), 2);
+}
+
+#[test]
+fn small_vec_e_indexed() {
+    let v: SmallVec<[u64; 4]> = small_vec_e![|i| i; 3];
+
+    assert_eq!(v.as_slice(), &[0, 1, 2]);
+}
@@ -0,0 +1,129 @@
+use map_macro::static_map::{StaticHashMap, StaticHashSet};
+use map_macro::{static_hash_map, static_hash_set};
+
+static MIME_TYPES: StaticHashMap<&str, &str> = static_hash_map! {
+    "html" => "text/html",
+    "css" => "text/css",
+    "js" => "text/javascript",
+    "png" => "image/png",
+    "jpg" => "image/jpeg",
+    "gif" => "image/gif",
+    "svg" => "image/svg+xml",
+    "json" => "application/json",
+    "pdf" => "application/pdf",
+    "wasm" => "application/wasm",
+    "txt" => "text/plain",
+};
+
+const EMPTY: StaticHashMap<u8, u8> = static_hash_map! {};
+
+#[test]
+fn static_hash_map1() {
+    assert_eq!(MIME_TYPES.len(), 11);
+
+    assert_eq!(MIME_TYPES.get("html"), Some(&"text/html"));
+    assert_eq!(MIME_TYPES.get(&"wasm"), Some(&"application/wasm"));
+    assert_eq!(
+        MIME_TYPES.get_key_value("txt"),
+        Some((&"txt", &"text/plain"))
+    );
+
+    assert!(MIME_TYPES.contains_key("svg"));
+    assert!(!MIME_TYPES.contains_key("exe"));
+    assert!(!MIME_TYPES.contains_key(""));
+}
+
+#[test]
+fn static_hash_map2() {
+    let m: StaticHashMap<i16, char> = static_hash_map! {
+        -1 => 'a',
+        0 => 'b',
+        0x10 => 'c',
+        -300 => 'd',
+        32767 => 'e',
+    };
+
+    assert_eq!(m.get(&-1), Some(&'a'));
+    assert_eq!(m.get(&0), Some(&'b'));
+    assert_eq!(m.get(&16), Some(&'c'));
+    assert_eq!(m.get(&-300), Some(&'d'));
+    assert_eq!(m.get(&1), None);
+}
+
+#[test]
+fn static_hash_map_iter() {
+    let mut entries: Vec<(&str, &str)> = MIME_TYPES.iter().map(|(k, v)| (*k, *v)).collect();
+    entries.sort_unstable();
+
+    assert_eq!(entries.len(), MIME_TYPES.len());
+    assert_eq!(entries[0], ("css", "text/css"));
+
+    assert_eq!((&MIME_TYPES).into_iter().count(), 11);
+}
+
+#[test]
+fn static_hash_map_large() {
+    static WORDS: StaticHashMap<u32, u32> = static_hash_map! {
+        0 => 0, 1 => 1, 2 => 4, 3 => 9, 4 => 16, 5 => 25, 6 => 36, 7 => 49,
+        8 => 64, 9 => 81, 10 => 100, 11 => 121, 12 => 144, 13 => 169,
+        14 => 196, 15 => 225, 16 => 256, 17 => 289, 18 => 324, 19 => 361,
+        20 => 400, 21 => 441, 22 => 484, 23 => 529, 24 => 576, 25 => 625,
+        26 => 676, 27 => 729, 28 => 784, 29 => 841, 30 => 900, 31 => 961,
+    };
+
+    for i in 0..32 {
+        assert_eq!(WORDS.get(&i), Some(&(i * i)));
+    }
+
+    assert_eq!(WORDS.get(&32), None);
+}
+
+#[test]
+fn static_hash_map_empty() {
+    assert!(EMPTY.is_empty());
+    assert_eq!(EMPTY.get(&0), None);
+    assert_eq!(EMPTY.iter().count(), 0);
+}
+
+#[test]
+fn static_hash_set1() {
+    static LANGUAGES: StaticHashSet<&str> = static_hash_set! { "en", "de", "fr", "es", "cat" };
+
+    assert_eq!(LANGUAGES.len(), 5);
+
+    assert!(LANGUAGES.contains("de"));
+    assert!(!LANGUAGES.contains("it"));
+    assert_eq!(LANGUAGES.get("cat"), Some(&"cat"));
+}
+
+#[test]
+fn static_hash_set2() {
+    let s: StaticHashSet<char> = static_hash_set! { 'a', 'e', 'i', 'o', 'u' };
+
+    assert!(s.contains(&'o'));
+    assert!(!s.contains(&'y'));
+
+    let b: StaticHashSet<bool> = static_hash_set! { true };
+
+    assert!(b.contains(&true));
+    assert!(!b.contains(&false));
+
+    let bytes: StaticHashSet<&[u8]> = static_hash_set! { b"GET", b"POST" };
+
+    assert!(bytes.contains(&b"GET"[..]));
+    assert!(!bytes.contains(&b"PUT"[..]));
+}
+
+#[test]
+fn static_hash_set_iter() {
+    let s: StaticHashSet<u8> = static_hash_set! { 1, 2, 3, b'a' };
+
+    let mut elements: Vec<u8> = s.iter().copied().collect();
+    elements.sort_unstable();
+
+    assert_eq!(elements, [1, 2, 3, 97]);
+
+    let single: StaticHashSet<u8> = static_hash_set! { 7 };
+
+    assert_eq!(format!("{single:?}"), "{7}");
+}
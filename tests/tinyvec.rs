@@ -0,0 +1,96 @@
+use std::cell::RefCell;
+use tinyvec::TinyVec;
+
+use map_macro::tinyvec::{tiny_vec, tiny_vec_e};
+
+#[derive(PartialEq, Debug, Default)]
+struct UnclonableWrapper(usize);
+
+#[test]
+fn tiny_vec1() {
+    let v: TinyVec<[u8; 4]> = tiny_vec![0, 1, 2, 3];
+
+    assert_eq!(v.as_slice(), &[0, 1, 2, 3]);
+    assert!(v.is_inline());
+}
+
+#[test]
+fn tiny_vec_spilled() {
+    let v: TinyVec<[u8; 2]> = tiny_vec![0, 1, 2, 3];
+
+    assert_eq!(v.as_slice(), &[0, 1, 2, 3]);
+    assert!(v.is_heap());
+}
+
+#[test]
+fn tiny_vec_empty() {
+    let v: TinyVec<[u8; 2]> = tiny_vec![];
+
+    assert!(v.is_empty());
+}
+
+#[test]
+fn tiny_vec_repeat() {
+    let v: TinyVec<[RefCell<Vec<u8>>; 2]> = tiny_vec![RefCell::new(Vec::new()); 2];
+
+    v[0].borrow_mut().push(1);
+
+    assert_eq!(v[0].borrow().len(), 1);
+    assert_eq!(v[1].borrow().len(), 0);
+}
+
+#[test]
+fn tiny_vec_repeat_unclonable() {
+    let v: TinyVec<[UnclonableWrapper; 4]> = tiny_vec![UnclonableWrapper(0); 3];
+
+    assert_eq!(v.len(), 3);
+    assert_eq!(v[2], UnclonableWrapper(0));
+}
+
+#[test]
+fn tiny_vec_indexed() {
+    let v: TinyVec<[UnclonableWrapper; 4]> = tiny_vec![|i| UnclonableWrapper(i); 3];
+
+    assert_eq!(
+        v.as_slice(),
+        &[
+            UnclonableWrapper(0),
+            UnclonableWrapper(1),
+            UnclonableWrapper(2),
+        ],
+    );
+}
+
+#[test]
+fn tiny_vec_count_evaluated_once() {
+    let mut calls = 0;
+
+    let v: TinyVec<[usize; 4]> = tiny_vec![|i| i; {
+        calls += 1;
+        3
+    }];
+
+    assert_eq!(v.as_slice(), &[0, 1, 2]);
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn tiny_vec_e1() {
+    let v: TinyVec<[u64; 2]> = tiny_vec_e![0u8, 1u16, 2u32];
+
+    assert_eq!(v.as_slice(), &[0, 1, 2]);
+}
+
+#[test]
+fn tiny_vec_e2() {
+    let v: TinyVec<[u64; 2]> = tiny_vec_e![1u8; 2];
+
+    assert_eq!(v.as_slice(), &[1, 1]);
+}
+
+#[test]
+fn tiny_vec_e_indexed() {
+    let v: TinyVec<[u64; 4]> = tiny_vec_e![|i| i; 3];
+
+    assert_eq!(v.as_slice(), &[0, 1, 2]);
+}
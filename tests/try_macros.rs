@@ -0,0 +1,146 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::error::Error;
+use std::fmt::Debug;
+
+use map_macro::{
+    try_btree_map, try_btree_map_e, try_btree_set, try_btree_set_e, try_hash_map, try_hash_map_e,
+    try_hash_set, try_hash_set_e, DuplicateKeyError,
+};
+
+#[derive(Debug)]
+struct Dyn1;
+
+#[derive(Debug)]
+struct Dyn2;
+
+#[test]
+fn try_hash_map1() {
+    let m = try_hash_map! {
+        "en" => "Hello",
+        "de" => "Hallo",
+        "fr" => "Bonjour",
+    };
+
+    assert_eq!(
+        m,
+        Ok(HashMap::from([
+            ("en", "Hello"),
+            ("de", "Hallo"),
+            ("fr", "Bonjour")
+        ]))
+    );
+}
+
+#[test]
+fn try_hash_map_duplicate() {
+    let keys = ["a", "b", "c", "b"];
+
+    let m = try_hash_map! {
+        keys[0] => 0,
+        keys[1] => 1,
+        keys[2] => 2,
+        keys[3] => 3,
+        keys[0] => 4,
+    };
+
+    assert_eq!(m, Err(DuplicateKeyError::__new(1, 3, "b")));
+}
+
+#[test]
+fn try_hash_map_error() {
+    let err = try_hash_map! { 1 => (), 1 => () }.unwrap_err();
+
+    assert_eq!(err.first_index(), 0);
+    assert_eq!(err.second_index(), 1);
+    assert_eq!(err.key(), &1);
+    assert_eq!(
+        err.to_string(),
+        "duplicate key at index 1, first defined at index 0"
+    );
+    assert_eq!(
+        format!("{err:?}"),
+        "DuplicateKeyError { first: 0, second: 1, key: 1 }"
+    );
+    assert!(err.source().is_none());
+    assert_eq!(err.into_key(), 1);
+}
+
+#[test]
+fn try_hash_map_question_mark() {
+    fn build(b: &str) -> Result<HashMap<String, u8>, Box<dyn Error>> {
+        Ok(try_hash_map! { "a".to_owned() => 0, b.to_owned() => 1 }?)
+    }
+
+    assert_eq!(build("b").unwrap().len(), 2);
+    assert!(build("a").is_err());
+}
+
+#[test]
+fn try_hash_map_empty() {
+    let m: Result<HashMap<u8, u8>, DuplicateKeyError<u8>> = try_hash_map! {};
+
+    assert_eq!(m, Ok(HashMap::new()));
+}
+
+#[test]
+fn try_hash_map_e1() {
+    let m: Result<HashMap<&str, &dyn Debug>, _> = try_hash_map_e! {
+        "1" => &Dyn1,
+        "2" => &Dyn2,
+    };
+
+    assert_eq!(m.unwrap().len(), 2);
+}
+
+#[test]
+fn try_btree_map1() {
+    assert_eq!(
+        try_btree_map! { 1 => 'a', 0 => 'b' },
+        Ok(BTreeMap::from([(0, 'b'), (1, 'a')]))
+    );
+    assert_eq!(
+        try_btree_map! { 1 => 'a', 0 => 'b', 1 => 'c' }
+            .unwrap_err()
+            .first_index(),
+        0
+    );
+}
+
+#[test]
+fn try_btree_map_e1() {
+    let m: Result<BTreeMap<u16, &dyn Debug>, DuplicateKeyError<u16>> = try_btree_map_e! {
+        0u8 => &Dyn1,
+        0u8 => &Dyn2,
+    };
+
+    assert_eq!(m.unwrap_err().second_index(), 1);
+}
+
+#[test]
+fn try_hash_set1() {
+    assert_eq!(try_hash_set! { 0, 1, 2 }, Ok(HashSet::from([0, 1, 2])));
+    assert_eq!(
+        try_hash_set! { 0, 1, 2, 1 },
+        Err(DuplicateKeyError::__new(1, 3, 1))
+    );
+}
+
+#[test]
+fn try_hash_set_e1() {
+    let s: Result<HashSet<u16>, _> = try_hash_set_e! { 0u8, 1u8 };
+
+    assert_eq!(s, Ok(HashSet::from([0, 1])));
+}
+
+#[test]
+fn try_btree_set1() {
+    assert_eq!(try_btree_set! { "b", "a" }, Ok(BTreeSet::from(["a", "b"])));
+    assert_eq!(try_btree_set! { "b", "b" }.unwrap_err().key(), &"b");
+}
+
+#[test]
+fn try_btree_set_e1() {
+    let s: Result<BTreeSet<u16>, _> = try_btree_set_e! { 1u8, 2u8, 1u8 };
+
+    assert_eq!(s.unwrap_err().into_key(), 1);
+}
@@ -45,3 +45,84 @@ fn vec_deque_e1() {
 fn vec_deque_e2() {
     drop::<VecDeque<&dyn Debug>>(vec_deque_e![&0; 4]);
 }
+
+#[test]
+fn vec_deque_capacity() {
+    let v: VecDeque<u8> = vec_deque![capacity: 10; 0, 1, 2, 3];
+
+    assert_eq!(v, [0, 1, 2, 3]);
+    assert!(v.capacity() >= 10);
+}
+
+#[test]
+fn vec_deque_e_capacity() {
+    let v: VecDeque<&dyn Debug> = vec_deque_e![capacity: 10; &Dyn1, &Dyn2];
+
+    assert_eq!(v.len(), 2);
+    assert!(v.capacity() >= 10);
+}
+
+#[test]
+fn vec_deque_indexed() {
+    let v: VecDeque<usize> = vec_deque![|i| i * 2; 4];
+
+    assert_eq!(v, [0, 2, 4, 6]);
+}
+
+#[test]
+fn vec_deque_indexed_count_evaluated_once() {
+    let mut calls = 0;
+
+    let v: VecDeque<usize> = vec_deque![|i| i; {
+        calls += 1;
+        3
+    }];
+
+    assert_eq!(v, [0, 1, 2]);
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn vec_deque_e_indexed() {
+    let v: VecDeque<u64> = vec_deque_e![|i| i; 3];
+
+    assert_eq!(v, [0, 1, 2]);
+}
+
+#[test]
+fn vec_deque_spread() {
+    let v = vec_deque![0, ..vec![1, 2], 3, ..4..6];
+
+    assert_eq!(v, [0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn vec_deque_spread_capacity() {
+    let v = vec_deque![..0..100];
+
+    assert_eq!(v.capacity(), 100);
+
+    let v = vec_deque![capacity: 200; ..0..100, 100];
+
+    assert!(v.capacity() >= 200);
+}
+
+#[test]
+fn vec_deque_e_spread() {
+    let v: VecDeque<&dyn Debug> = vec_deque_e![&0, ..vec![&"a" as &dyn Debug]];
+
+    assert_eq!(v.len(), 2);
+}
+
+#[test]
+fn vec_deque_range_elements() {
+    let n = 3;
+
+    let v = vec_deque![0, 1..=n, 10..12];
+
+    assert_eq!(v, [0, 1, 2, 3, 10, 11]);
+
+    let v = vec_deque![1..=100];
+
+    assert_eq!(v.capacity(), 100);
+}
@@ -1,4 +1,6 @@
-use map_macro::vec_no_clone;
+use std::convert::TryFrom;
+
+use map_macro::{vec_no_clone, vec_no_clone_e};
 
 #[derive(PartialEq, Debug)]
 struct UnclonableWrapper(i8);
@@ -22,3 +24,150 @@ fn vec_no_clone_empty() {
 
     assert_eq!(v.len(), 0);
 }
+
+#[test]
+fn vec_no_clone_capacity() {
+    let v = vec_no_clone![capacity: 10; UnclonableWrapper(0), UnclonableWrapper(1)];
+
+    assert_eq!(v, [UnclonableWrapper(0), UnclonableWrapper(1)]);
+    assert!(v.capacity() >= 10);
+}
+
+#[test]
+fn vec_no_clone_e_capacity() {
+    let v: Vec<u16> = vec_no_clone_e![capacity: 10; 0u8, 1u8];
+
+    assert_eq!(v, [0, 1]);
+    assert!(v.capacity() >= 10);
+}
+
+#[test]
+fn vec_no_clone_indexed() {
+    let v = vec_no_clone![|i| UnclonableWrapper(i8::try_from(i).unwrap()); 3];
+
+    assert_eq!(
+        v,
+        vec![
+            UnclonableWrapper(0),
+            UnclonableWrapper(1),
+            UnclonableWrapper(2),
+        ],
+    );
+}
+
+#[test]
+fn vec_no_clone_indexed_wildcard() {
+    let v = vec_no_clone![|_| UnclonableWrapper(0); 2];
+
+    assert_eq!(v, vec![UnclonableWrapper(0), UnclonableWrapper(0)]);
+}
+
+#[test]
+fn vec_no_clone_count_evaluated_once() {
+    let mut calls = 0;
+
+    let v = vec_no_clone![UnclonableWrapper(0); {
+        calls += 1;
+        2
+    }];
+
+    assert_eq!(v.len(), 2);
+    assert_eq!(calls, 1);
+
+    let v = vec_no_clone![|i| i; {
+        calls += 1;
+        2
+    }];
+
+    assert_eq!(v, vec![0, 1]);
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn vec_no_clone_e_indexed() {
+    let v: Vec<u64> = vec_no_clone_e![|i| i; 3];
+
+    assert_eq!(v, vec![0, 1, 2]);
+}
+
+#[test]
+fn vec_no_clone_e_closure() {
+    let v: Vec<fn(usize) -> usize> = vec_no_clone_e![(|x: usize| x + 1); 3];
+
+    assert_eq!(v.len(), 3);
+    assert_eq!(v[0](1), 2);
+}
+
+#[test]
+fn vec_no_clone_spread() {
+    let v = vec_no_clone![0, ..vec![1, 2], 3];
+
+    assert_eq!(v, vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn vec_no_clone_spread_capacity() {
+    let v = vec_no_clone![..0..3];
+
+    assert_eq!(v.capacity(), 3);
+}
+
+#[test]
+fn vec_no_clone_range_to() {
+    let v = vec_no_clone![(..3), (..4)];
+
+    assert_eq!(v, vec![..3, ..4]);
+}
+
+#[test]
+fn vec_no_clone_e_spread() {
+    let v: Vec<u16> = vec_no_clone_e![0u8, ..1u16..3];
+
+    assert_eq!(v, vec![0, 1, 2]);
+}
+
+#[test]
+fn vec_no_clone_range_elements() {
+    let v = vec_no_clone![1..=100];
+
+    assert_eq!(v.len(), 100);
+    assert_eq!(v.capacity(), 100);
+
+    let v: Vec<u16> = vec_no_clone_e![0u8, 1..3];
+
+    assert_eq!(v, vec![0, 1, 2]);
+}
+
+#[test]
+fn vec_no_clone_negative_elements() {
+    let x = 1;
+    let v = vec_no_clone![-x, 1, -3..-1];
+
+    assert_eq!(v, vec![-1, 1, -3, -2]);
+
+    let v = vec_no_clone![-x..0, (0..x)];
+
+    assert_eq!(v, vec![-1..0, 0..1]);
+}
+
+macro_rules! with_elements {
+    ($m: ident [] $($e: tt)*) => {
+        $m!($($e)*)
+    };
+    ($m: ident [$_n: tt $($n: tt)*] $($e: tt)*) => {
+        with_elements!($m [$($n)*] $((0, $e))* $((1, $e))*)
+    };
+}
+
+macro_rules! long_vec_calls {
+    ($($e: tt)*) => {
+        vec_no_clone![$(Box::new($e)),*]
+    };
+}
+
+#[test]
+fn vec_no_clone_long_calls() {
+    let v = with_elements!(long_vec_calls [. . . . . . . .] ());
+
+    assert_eq!(v.len(), 256);
+}